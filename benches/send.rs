@@ -0,0 +1,84 @@
+//! Compares the number of sends and the time per frame of a [Server] sending one packet per frame
+//! against sending one packet per profile, as the server did before frames were batched.
+//!
+//! Run with `cargo bench --bench send`
+
+use std::{net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket}, sync::{Arc, atomic::{AtomicUsize, Ordering}}, time::{Duration, Instant}};
+
+use rosc::{OscPacket, OscBundle};
+use tuio_rs::{Server, Cursor, Object, Blob, cursor::Position, errors::TuioError, osc_encode_decode::{EncodeOsc, OscEncoder}, server::{SendOsc, UdpSender}};
+
+const FRAMES: usize = 10_000;
+
+/// A [UdpSender] counting its `send_to` calls
+struct CountingSender {
+    sender: UdpSender,
+    sends: Arc<AtomicUsize>,
+}
+
+impl SendOsc<OscPacket, TuioError> for CountingSender {
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), TuioError> {
+        self.sends.fetch_add(1, Ordering::Relaxed);
+        self.sender.send_osc_packet(packet)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.sender.is_connected()
+    }
+
+    fn is_local(&self) -> bool {
+        self.sender.is_local()
+    }
+}
+
+fn counting_sender(target: SocketAddr) -> (CountingSender, Arc<AtomicUsize>) {
+    let sends = Arc::new(AtomicUsize::new(0));
+    (CountingSender { sender: UdpSender::new(target).unwrap(), sends: Arc::clone(&sends) }, sends)
+}
+
+fn report(name: &str, sends: usize, elapsed: Duration) {
+    println!(
+        "{:<12} {:>6} sends, {:.2} sends per frame, {:>8.2} µs per frame",
+        name,
+        sends,
+        sends as f64 / FRAMES as f64,
+        elapsed.as_secs_f64() * 1e6 / FRAMES as f64
+    );
+}
+
+fn main() {
+    let receiver = UdpSocket::bind(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+    let target = receiver.local_addr().unwrap();
+
+    let cursors: Vec<Cursor> = (0..10).map(|session_id| Cursor::new(session_id, Position { x: 0.5, y: 0.5 })).collect();
+    let objects: Vec<Object> = (10..15).map(|session_id| Object::new(session_id, 1, Position { x: 0.5, y: 0.5 }, 0.)).collect();
+    let blobs: Vec<Blob> = (15..20).map(|session_id| Blob::new(session_id, Position { x: 0.5, y: 0.5 }, 0., 0.1, 0.1, 0.01)).collect();
+
+    let (sender, sends) = counting_sender(target);
+    let start = Instant::now();
+
+    for frame_id in 0..FRAMES as i32 {
+        let bundles: [OscBundle; 3] = [
+            OscEncoder::encode_cursor_bundle(&cursors, "bench@local".into(), frame_id),
+            OscEncoder::encode_object_bundle(&objects, "bench@local".into(), frame_id),
+            OscEncoder::encode_blob_bundle(&blobs, "bench@local".into(), frame_id),
+        ];
+
+        for bundle in bundles {
+            sender.send_osc_packet(&OscPacket::Bundle(bundle)).unwrap();
+        }
+    }
+
+    report("per profile", sends.load(Ordering::Relaxed), start.elapsed());
+
+    let (sender, sends) = counting_sender(target);
+    let mut server = Server::from_osc_sender(sender);
+    server.set_source_name("bench");
+    let start = Instant::now();
+
+    for _ in 0..FRAMES {
+        server.send_frame(&cursors, &objects, &blobs).unwrap();
+    }
+
+    report("batched", sends.load(Ordering::Relaxed), start.elapsed());
+}
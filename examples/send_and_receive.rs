@@ -38,7 +38,7 @@ fn main() {
     let cursor_id = server.create_cursor(0., 0.);
     let object_id = server.create_object(1, 0., 0., 0.);
     let blob_id = server.create_blob(0., 0., 0., 0.1, 0.1, 0.01);
-    server.commit_frame();
+    server.commit_frame().expect("Sending frame");
     std::thread::sleep(Duration::from_secs(1));
 
     if let Ok(Some(events)) = client.refresh() {
@@ -49,7 +49,7 @@ fn main() {
     server.update_cursor(cursor_id, 1., 1.);
     server.update_object(object_id, 1., 1., 90f32.to_radians());
     server.update_blob(blob_id, 1., 1., 90f32.to_radians(), 0.2, 0.2, 0.04);
-    server.commit_frame();
+    server.commit_frame().expect("Sending frame");
 
     std::thread::sleep(Duration::from_secs(1));
 
@@ -61,7 +61,7 @@ fn main() {
     server.remove_cursor(cursor_id);
     server.remove_object(object_id);
     server.remove_blob(blob_id);
-    server.commit_frame();
+    server.commit_frame().expect("Sending frame");
 
     std::thread::sleep(Duration::from_secs(1));
 
@@ -0,0 +1,117 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use ringbuffer::RingBufferWrite;
+use tokio::net::UdpSocket;
+
+use crate::{client::{Client, TuioEvents}, errors::TuioError, loopback::PacketBuffer, osc_receiver::MAX_DATAGRAM_SIZE};
+
+/// A [Client] receiving TUIO over UDP on a [tokio] socket instead of a receive thread
+///
+/// The packets are only received while awaiting [AsyncClient::recv], so dropping its future or the [AsyncClient] itself stops receiving
+/// without any thread to join. The wrapped [Client] keeps its configuration, [crate::client::Listener]s and [crate::client::ClientStats]
+pub struct AsyncClient {
+    socket: UdpSocket,
+    packet_buffer: PacketBuffer,
+    client: Client,
+}
+
+impl AsyncClient {
+    /// Creates an [AsyncClient] receiving TUIO on the port 3333 of the loopback interface
+    pub async fn new() -> Result<Self, TuioError> {
+        Self::from_port(3333).await
+    }
+
+    /// Creates an [AsyncClient] receiving TUIO on a port of the loopback interface
+    ///
+    /// # Arguments
+    /// * `port` - the port the TUIO source sends to
+    pub async fn from_port(port: u16) -> Result<Self, TuioError> {
+        Self::from_address(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port)).await
+    }
+
+    /// Creates an [AsyncClient] receiving TUIO on the provided socket address
+    ///
+    /// # Arguments
+    /// * `address` - the local socket address to bind, either IPv4 or IPv6
+    pub async fn from_address(address: SocketAddr) -> Result<Self, TuioError> {
+        let socket = UdpSocket::bind(address).await.map_err(TuioError::Socket)?;
+        let packet_buffer = PacketBuffer::default();
+        let client = Client::from_packet_buffer(packet_buffer.clone(), address.ip().is_loopback());
+
+        Ok(Self { socket, packet_buffer, client })
+    }
+
+    /// Returns the local socket address of the [AsyncClient], such as the port chosen by the system when binding the port 0
+    pub fn local_addr(&self) -> Result<SocketAddr, TuioError> {
+        self.socket.local_addr().map_err(TuioError::Socket)
+    }
+
+    /// Returns a reference to the wrapped [Client]
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Returns a mutable reference to the wrapped [Client], to configure it or add [crate::client::Listener]s
+    pub fn client_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+
+    /// Waits for the next packets producing any event and returns these [TuioEvents], after notifying the registered [crate::client::Listener]s
+    ///
+    /// Undecodable packets are skipped and counted in [Client::stats].
+    /// The future is cancel safe: a packet received before it is dropped is processed by the next call.
+    /// The event queue of the [Client] must stay disabled, otherwise the events are queued for [Client::drain_events] and never returned
+    pub async fn recv(&mut self) -> Result<TuioEvents, TuioError> {
+        let mut buf = vec![0u8; MAX_DATAGRAM_SIZE];
+
+        loop {
+            if let Some(events) = self.client.refresh()? {
+                return Ok(events);
+            }
+
+            let size = self.socket.recv(&mut buf).await.map_err(TuioError::Socket)?;
+
+            match rosc::decoder::decode_udp(&buf[..size]) {
+                Ok((_, packet)) => self.packet_buffer.lock().unwrap().push(packet),
+                Err(err) => self.client.skip_packet(TuioError::Decode(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{client::CursorEvent, cursor::Position, Cursor, Server};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn recv_events() {
+        let mut client = AsyncClient::from_port(0).await.unwrap();
+        let address = client.local_addr().unwrap();
+        let server = Server::from_socket_addr(address).unwrap();
+
+        std::net::UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap().send_to(b"not an OSC packet", address).unwrap();
+        server.send_frame(&[Cursor::new(2, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+
+        let events = tokio::time::timeout(Duration::from_secs(1), client.recv()).await.unwrap().unwrap();
+
+        assert!(matches!(&events.cursor_events[..], [CursorEvent::New(data)] if data.cursor.session_id == 2));
+        assert_eq!(client.client().stats().messages_dropped, 1);
+    }
+
+    #[tokio::test]
+    async fn cancelled_recv() {
+        let mut client = AsyncClient::from_port(0).await.unwrap();
+        let server = Server::from_socket_addr(client.local_addr().unwrap()).unwrap();
+
+        assert!(tokio::time::timeout(Duration::from_millis(10), client.recv()).await.is_err());
+
+        server.send_frame(&[Cursor::new(2, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        let events = tokio::time::timeout(Duration::from_secs(1), client.recv()).await.unwrap().unwrap();
+
+        assert_eq!(events.cursor_events.len(), 1);
+    }
+}
@@ -0,0 +1,207 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use bevy::{app::{App, Plugin, PreUpdate}, ecs::{message::{Message, MessageWriter}, resource::Resource, system::{NonSend, ResMut}}};
+
+use crate::{client::{BlobData, BlobEvent, CursorData, CursorEvent, ObjectData, ObjectEvent}, loopback::LoopbackTransport, Blob, Client, Cursor, Object};
+
+/// A [Message] written when a [Cursor] is added
+#[derive(Message)]
+pub struct CursorAdded(pub CursorData);
+
+/// A [Message] written when a [Cursor] is updated
+#[derive(Message)]
+pub struct CursorUpdated(pub CursorData);
+
+/// A [Message] written when a [Cursor] is removed
+#[derive(Message)]
+pub struct CursorRemoved(pub CursorData);
+
+/// A [Message] written when an [Object] is added
+#[derive(Message)]
+pub struct ObjectAdded(pub ObjectData);
+
+/// A [Message] written when an [Object] is updated
+#[derive(Message)]
+pub struct ObjectUpdated(pub ObjectData);
+
+/// A [Message] written when an [Object] is removed
+#[derive(Message)]
+pub struct ObjectRemoved(pub ObjectData);
+
+/// A [Message] written when a [Blob] is added
+#[derive(Message)]
+pub struct BlobAdded(pub BlobData);
+
+/// A [Message] written when a [Blob] is updated
+#[derive(Message)]
+pub struct BlobUpdated(pub BlobData);
+
+/// A [Message] written when a [Blob] is removed
+#[derive(Message)]
+pub struct BlobRemoved(pub BlobData);
+
+/// A [Resource] holding the alive TUIO inputs, updated before every frame
+#[derive(Resource, Default)]
+pub struct TuioState {
+    pub cursors: Vec<Cursor>,
+    pub objects: Vec<Object>,
+    pub blobs: Vec<Blob>,
+}
+
+#[derive(Clone)]
+enum Source {
+    Address(SocketAddr),
+    Loopback(LoopbackTransport),
+}
+
+/// A bevy [Plugin] receiving TUIO in the background and writing its events as bevy [Message]s
+///
+/// The [Client]'s receive threads are connected when the plugin is built. Before every frame, the received packets are processed,
+/// the [Message]s of the added, updated and removed inputs are written and the [TuioState] is updated
+#[derive(Clone)]
+pub struct TuioPlugin {
+    source: Source,
+}
+
+impl TuioPlugin {
+    /// Creates a [TuioPlugin] receiving TUIO on the port 3333 of the loopback interface
+    pub fn new() -> Self {
+        Self::from_port(3333)
+    }
+
+    /// Creates a [TuioPlugin] receiving TUIO on a port of the loopback interface
+    ///
+    /// # Arguments
+    /// * `port` - the port the TUIO source sends to
+    pub fn from_port(port: u16) -> Self {
+        Self::from_address(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
+    }
+
+    /// Creates a [TuioPlugin] receiving TUIO on the provided socket address
+    ///
+    /// # Arguments
+    /// * `address` - the local socket address to bind, either IPv4 or IPv6
+    pub fn from_address(address: SocketAddr) -> Self {
+        Self { source: Source::Address(address) }
+    }
+
+    /// Creates a [TuioPlugin] processing the packets sent through a [LoopbackTransport]
+    ///
+    /// # Arguments
+    /// * `transport` - the [LoopbackTransport] shared with a [crate::Server]
+    pub fn from_loopback(transport: &LoopbackTransport) -> Self {
+        Self { source: Source::Loopback(transport.clone()) }
+    }
+}
+
+impl Default for TuioPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for TuioPlugin {
+    /// Connects the [Client] and registers the [Message]s, the [TuioState] and the receive system
+    ///
+    /// # Panics
+    /// Panics if the socket address cannot be bound
+    fn build(&self, app: &mut App) {
+        let client = match &self.source {
+            Source::Address(address) => Client::from_address(*address).expect("could not bind the TUIO client"),
+            Source::Loopback(transport) => Client::from_loopback(transport),
+        };
+
+        client.connect().expect("could not connect the TUIO client");
+
+        app.insert_non_send(client)
+            .init_resource::<TuioState>()
+            .add_message::<CursorAdded>()
+            .add_message::<CursorUpdated>()
+            .add_message::<CursorRemoved>()
+            .add_message::<ObjectAdded>()
+            .add_message::<ObjectUpdated>()
+            .add_message::<ObjectRemoved>()
+            .add_message::<BlobAdded>()
+            .add_message::<BlobUpdated>()
+            .add_message::<BlobRemoved>()
+            .add_systems(PreUpdate, receive_tuio);
+    }
+}
+
+/// Processes the received packets, writes the [Message]s of their events and updates the [TuioState]
+#[allow(clippy::too_many_arguments)]
+fn receive_tuio(
+    client: NonSend<Client>,
+    mut state: ResMut<TuioState>,
+    mut cursor_added: MessageWriter<CursorAdded>,
+    mut cursor_updated: MessageWriter<CursorUpdated>,
+    mut cursor_removed: MessageWriter<CursorRemoved>,
+    mut object_added: MessageWriter<ObjectAdded>,
+    mut object_updated: MessageWriter<ObjectUpdated>,
+    mut object_removed: MessageWriter<ObjectRemoved>,
+    mut blob_added: MessageWriter<BlobAdded>,
+    mut blob_updated: MessageWriter<BlobUpdated>,
+    mut blob_removed: MessageWriter<BlobRemoved>,
+) {
+    let Ok(Some(events)) = client.refresh() else {
+        return;
+    };
+
+    for event in events.cursor_events {
+        match event {
+            CursorEvent::New(data) => { cursor_added.write(CursorAdded(data)); },
+            CursorEvent::Update(data) => { cursor_updated.write(CursorUpdated(data)); },
+            CursorEvent::Remove(data) => { cursor_removed.write(CursorRemoved(data)); },
+        }
+    }
+
+    for event in events.object_events {
+        match event {
+            ObjectEvent::New(data) => { object_added.write(ObjectAdded(data)); },
+            ObjectEvent::Update(data) => { object_updated.write(ObjectUpdated(data)); },
+            ObjectEvent::Remove(data) => { object_removed.write(ObjectRemoved(data)); },
+        }
+    }
+
+    for event in events.blob_events {
+        match event {
+            BlobEvent::New(data) => { blob_added.write(BlobAdded(data)); },
+            BlobEvent::Update(data) => { blob_updated.write(BlobUpdated(data)); },
+            BlobEvent::Remove(data) => { blob_removed.write(BlobRemoved(data)); },
+        }
+    }
+
+    state.cursors = client.cursors();
+    state.objects = client.objects();
+    state.blobs = client.blobs();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::message::Messages;
+
+    use crate::{cursor::Position, Server};
+
+    use super::*;
+
+    #[test]
+    fn cursor_messages() {
+        let transport = LoopbackTransport::new();
+        let server = Server::from_osc_sender(transport.clone());
+        let mut app = App::new();
+        app.add_plugins(TuioPlugin::from_loopback(&transport));
+
+        server.send_frame(&[Cursor::new(2, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        app.update();
+
+        let added = app.world().resource::<Messages<CursorAdded>>();
+        assert!(matches!(added.iter_current_update_messages().collect::<Vec<_>>()[..], [CursorAdded(CursorData { cursor: Cursor { session_id: 2, .. }, .. })]));
+        assert_eq!(app.world().resource::<TuioState>().cursors.len(), 1);
+
+        server.send_frame(&[], &[], &[]).unwrap();
+        app.update();
+
+        assert_eq!(app.world().resource::<Messages<CursorRemoved>>().iter_current_update_messages().count(), 1);
+        assert!(app.world().resource::<TuioState>().cursors.is_empty());
+    }
+}
@@ -1,8 +1,9 @@
-use std::{f32::consts::PI, time::Duration};
+use std::{fmt, time::Duration};
 
-use crate::cursor::{Position, Velocity};
+use crate::cursor::{Lifetime, PixelPosition, Position, Velocity, linear_motion, normalize_angle, predict_position, smooth, rotation_motion, MotionOrigin, DEFAULT_MIN_DELTA_TIME};
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Blob {
     pub(crate) session_id: i32,
     pub(crate) position: Position,
@@ -14,6 +15,13 @@ pub struct Blob {
     pub(crate) width: f32,
     pub(crate) height: f32,
     pub(crate) area: f32,
+    pub(crate) area_speed: f32,
+    pub(crate) extra_args: Vec<f32>,
+    pub(crate) source: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) lifetime: Lifetime,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<(Position, f32, f32)>,
 }
 
 impl Blob {
@@ -21,7 +29,7 @@ impl Blob {
     /// # Arguments
     /// * `session_id` - a unique session ID
     /// * `position` - a normalized [Position]
-    /// * `angle` - an angle in radians
+    /// * `angle` - an angle in radians, stored normalized between 0 and 2π
     /// * `width` - a normalized width
     /// * `height` - a normalized height
     /// * `area` - a normalized area
@@ -38,12 +46,17 @@ impl Blob {
             position,
             velocity: Velocity::default(),
             acceleration: 0f32,
-            angle,
+            angle: normalize_angle(angle),
             rotation_speed: 0f32,
             rotation_acceleration: 0f32,
             width,
             height,
             area,
+            area_speed: 0f32,
+            extra_args: Vec::new(),
+            source: None,
+            lifetime: Lifetime::default(),
+            motion_origin: MotionOrigin::default(),
         }
     }
 
@@ -52,7 +65,7 @@ impl Blob {
     /// * `velocity` - a normalized [Velocity]
     /// * `rotation_speed` - a rotation speed in turns per second
     /// * `acceleration` - a normalized acceleration
-    /// * `rotation_acceleration` - a roation acceleration in radians turn per second squared
+    /// * `rotation_acceleration` - a rotation acceleration in turns per second squared
     pub fn with_motion(
         mut self,
         velocity: Velocity,
@@ -67,11 +80,11 @@ impl Blob {
         self
     }
 
-    /// Updates the [Blob], computing its velocity, acceleration, rotation speed and rotation acceleration
+    /// Updates the [Blob], computing its velocity, acceleration, rotation speed, rotation acceleration and area speed once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
     /// # Arguments
     /// * `delta_time` - the [Duration] since last update
     /// * `position` - the new [Position]
-    /// * `angle` - the new angle
+    /// * `angle` - the new angle in radians, stored normalized between 0 and 2π
     /// * `width` - the new width
     /// * `height` - the new height
     /// * `area` - the new area
@@ -84,29 +97,41 @@ impl Blob {
         height: f32,
         area: f32,
     ) {
-        let delta_time = delta_time.as_secs_f32();
-
-        let distance = position.distance_from(&self.position);
-        let delta_x = position.x - self.position.x;
-        let delta_y = position.y - self.position.y;
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position, angle, width, height, area);
+    }
 
-        let last_speed = self.velocity.get_speed();
-        let speed = distance / delta_time;
+    /// Updates the [Blob], computing its velocity, acceleration, rotation speed, rotation acceleration and area speed once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// Closer updates keep the previous motion instead of producing a spike, while the other values are still updated
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle in radians, stored normalized between 0 and 2π
+    /// * `width` - the new width
+    /// * `height` - the new height
+    /// * `area` - the new area
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_with_min_delta_time(
+        &mut self,
+        delta_time: Duration,
+        min_delta_time: Duration,
+        position: Position,
+        angle: f32,
+        width: f32,
+        height: f32,
+        area: f32,
+    ) {
+        self.lifetime = self.lifetime.updated();
 
-        self.velocity = Velocity {
-            x: delta_x / delta_time,
-            y: delta_y / delta_time,
-        };
+        if let Some(((previous_position, previous_angle, previous_area), delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &(self.position, self.angle, self.area)) {
+            (self.velocity, self.acceleration) = linear_motion(&position, &previous_position, &self.velocity, delta_time);
+            (self.rotation_speed, self.rotation_acceleration) = rotation_motion(angle, previous_angle, self.rotation_speed, delta_time);
+            self.area_speed = (area - previous_area) / delta_time;
+        }
 
-        self.acceleration = (speed - last_speed) / delta_time;
         self.position = position;
-
-        let delta_turn = (angle - self.angle) / (2. * PI);
-        let rotation_speed = delta_turn / delta_time;
-
-        self.rotation_acceleration = (rotation_speed - self.rotation_speed) / delta_time;
-        self.rotation_speed = rotation_speed;
-
+        self.angle = normalize_angle(angle);
         self.width = width;
         self.height = height;
         self.area = area;
@@ -144,6 +169,62 @@ impl Blob {
         self.acceleration
     }
 
+    /// Returns the name of the source which sent this [Blob], or [None] if it was not received or its bundle had no `source` message
+    pub fn get_source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the float arguments following the standard ones in the received `set` message, such as vendor-specific values of extended trackers
+    pub fn get_extra_args(&self) -> &[f32] {
+        &self.extra_args
+    }
+
+    /// Returns the time elapsed between the creation or first reception of this [Blob] and the last frame it was part of,
+    /// measured by the [crate::clock::Clock] of the [crate::Client] or [crate::Server] tracking it
+    pub fn age(&self) -> Duration {
+        self.lifetime.age()
+    }
+
+    /// Returns the number of updates of this [Blob] since it was created or first received
+    pub fn update_count(&self) -> u32 {
+        self.lifetime.update_count()
+    }
+
+    /// Returns true if this [Blob] first appeared in the last frame received from its source, which lets a polling application detect new inputs without the add events
+    pub fn is_new_this_frame(&self) -> bool {
+        self.lifetime.is_new_this_frame()
+    }
+
+    /// Returns the position extrapolated from the velocity, to render smooth motion between two frames
+    /// # Arguments
+    /// * `ahead` - the [Duration] since the last update
+    pub fn predicted_position(&self, ahead: Duration) -> Position {
+        predict_position(&self.position, &self.velocity, None, ahead)
+    }
+
+    /// Returns the position extrapolated from the velocity and the acceleration along the direction of motion
+    /// # Arguments
+    /// * `ahead` - the [Duration] since the last update
+    pub fn predicted_position_with_acceleration(&self, ahead: Duration) -> Position {
+        predict_position(&self.position, &self.velocity, Some(self.acceleration), ahead)
+    }
+
+    /// Returns the position in screen space
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn get_pixel_position(&self, screen_width: u16, screen_height: u16) -> PixelPosition {
+        self.position.to_pixel(screen_width, screen_height)
+    }
+
+    /// Returns the speed in pixels per second in screen space
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn get_pixel_speed(&self, screen_width: u16, screen_height: u16) -> f32 {
+        self.velocity.to_pixels(screen_width, screen_height).get_speed()
+    }
+
     /// Returns the angle in radians
     pub fn get_angle(&self) -> f32 {
         self.angle
@@ -176,20 +257,66 @@ impl Blob {
 
     /// Returns the height in screen space
     pub fn get_pixel_height(&self, screen_height: u16) -> u16 {
-        (self.width * screen_height as f32) as u16
+        (self.height * screen_height as f32) as u16
+    }
+
+    /// Returns the normalized semi-axis lengths of the [Blob]'s ellipse, half the width and half the height
+    pub fn get_semi_axes(&self) -> (f32, f32) {
+        (self.width / 2., self.height / 2.)
+    }
+
+    /// Returns the eccentricity of the [Blob]'s ellipse, 0 for a circle and approaching 1 as it flattens
+    pub fn get_eccentricity(&self) -> f32 {
+        let (semi_width, semi_height) = self.get_semi_axes();
+        let (semi_minor, semi_major) = (semi_width.min(semi_height), semi_width.max(semi_height));
+
+        if semi_major <= 0. {
+            return 0.;
+        }
+
+        (1. - (semi_minor / semi_major).powi(2)).sqrt()
     }
 
     /// Returns the normalized area
     pub fn get_area(&self) -> f32 {
         self.area
     }
+
+    /// Returns the area change rate per second, positive while the [Blob] grows
+    pub fn get_area_speed(&self) -> f32 {
+        self.area_speed
+    }
+
+    /// Keeps the position, velocity and acceleration of the previous state of this [Blob] if it moved less than a dead-band
+    /// # Arguments
+    /// * `previous` - the previous state of this [Blob]
+    /// * `deadband` - the minimum normalized distance of a position change, 0 keeps every change
+    pub(crate) fn hold_position(&mut self, previous: &Blob, deadband: f32) {
+        if self.position.distance_from(&previous.position) < deadband {
+            self.position = previous.position;
+            self.velocity = previous.velocity;
+            self.acceleration = previous.acceleration;
+        }
+    }
+
+    /// Low-pass filters the velocity, rotation speed, area speed and accelerations of this [Blob] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Blob]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Blob, alpha: f32) {
+        self.velocity = self.velocity.smooth(&previous.velocity, alpha);
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+        self.rotation_speed = smooth(self.rotation_speed, previous.rotation_speed, alpha);
+        self.rotation_acceleration = smooth(self.rotation_acceleration, previous.rotation_acceleration, alpha);
+        self.area_speed = smooth(self.area_speed, previous.area_speed, alpha);
+    }
 }
 
 impl PartialEq for Blob {
     fn eq(&self, other: &Self) -> bool {
         self.session_id == other.session_id
             && self.get_x_position() == other.get_x_position()
-            && self.get_x_position() == other.get_y_position()
+            && self.get_y_position() == other.get_y_position()
             && self.angle == other.angle
             && self.velocity == other.velocity
             && self.rotation_speed == other.rotation_speed
@@ -198,12 +325,23 @@ impl PartialEq for Blob {
             && self.width == other.width
             && self.height == other.height
             && self.area == other.area
+            && self.area_speed == other.area_speed
+    }
+}
+
+impl fmt::Display for Blob {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "blob {} at ({:.3}, {:.3}) angle {:.3} size {:.3}x{:.3} moving ({:.3}, {:.3})",
+            self.session_id, self.position.x, self.position.y, self.angle, self.width, self.height, self.velocity.x, self.velocity.y
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{f32::consts::SQRT_2, time::Duration};
+    use std::{f32::consts::{PI, SQRT_2}, time::Duration};
 
     use crate::{blob::Blob, cursor::Position};
 
@@ -231,4 +369,73 @@ mod tests {
         assert_eq!(blob.get_height(), 0.5);
         assert_eq!(blob.get_area(), 0.25);
     }
+
+    #[test]
+    fn blob_successive_updates() {
+        let mut blob = Blob::new(0, Position { x: 0., y: 0. }, 0., 0., 0., 0.);
+
+        blob.update(Duration::from_secs(1), Position { x: 0., y: 0. }, 90f32.to_radians(), 0., 0., 0.);
+        blob.update(Duration::from_secs(1), Position { x: 0., y: 0. }, 225f32.to_radians(), 0., 0., 0.);
+
+        assert_eq!(blob.get_angle(), 225f32.to_radians());
+        assert_eq!(blob.get_rotation_speed(), 0.375);
+        assert_eq!(blob.get_rotation_acceleration(), 0.125);
+    }
+
+    #[test]
+    fn blob_area_speed() {
+        let mut blob = Blob::new(0, Position { x: 0.5, y: 0.5 }, 0., 0.1, 0.1, 0.01);
+
+        blob.update(Duration::from_millis(500), Position { x: 0.5, y: 0.5 }, 0., 0.2, 0.2, 0.04);
+        assert_eq!(blob.get_area_speed(), 0.06);
+
+        blob.update(Duration::from_secs(2), Position { x: 0.5, y: 0.5 }, 0., 0.1, 0.1, 0.02);
+        assert_eq!(blob.get_area_speed(), -0.01);
+    }
+
+    #[test]
+    fn blob_pixel_size() {
+        let blob = Blob::new(0, Position { x: 0.5, y: 0.5 }, 0., 0.5, 0.25, 0.125);
+
+        assert_eq!(blob.get_pixel_width(1920), 960);
+        assert_eq!(blob.get_pixel_height(1080), 270);
+    }
+
+    #[test]
+    fn blob_ellipse() {
+        let circle = Blob::new(0, Position { x: 0.5, y: 0.5 }, 0., 0.2, 0.2, 0.0314);
+
+        assert_eq!(circle.get_semi_axes(), (0.1, 0.1));
+        assert!(circle.get_eccentricity().abs() < 1e-6);
+
+        let ellipse = Blob::new(0, Position { x: 0.5, y: 0.5 }, 0., 0.25, 0.5, 0.098);
+
+        assert_eq!(ellipse.get_semi_axes(), (0.125, 0.25));
+        assert!((ellipse.get_eccentricity() - 3f32.sqrt() / 2.).abs() < 1e-6);
+        assert_eq!(Blob::new(0, Position { x: 0.5, y: 0.5 }, 0., 0., 0., 0.).get_eccentricity(), 0.);
+    }
+
+    #[test]
+    fn blob_equality() {
+        let blob = Blob::new(0, Position { x: 0.5, y: 0.5 }, 0., 0.5, 0.5, 0.25);
+        let other = Blob::new(0, Position { x: 0.5, y: 0.2 }, 0., 0.5, 0.5, 0.25);
+
+        assert_eq!(blob, blob.clone());
+        assert_ne!(blob, other);
+    }
+
+    #[test]
+    fn angle_normalization() {
+        let blob = Blob::new(0, Position { x: 0.5, y: 0.5 }, 3. * PI, 0.1, 0.1, 0.01);
+
+        assert_eq!(blob, Blob::new(0, Position { x: 0.5, y: 0.5 }, PI, 0.1, 0.1, 0.01));
+        assert!((Blob::new(0, Position { x: 0.5, y: 0.5 }, -PI, 0.1, 0.1, 0.01).get_angle() - PI).abs() < 1e-6);
+        assert_eq!(Blob::new(0, Position { x: 0.5, y: 0.5 }, f32::NAN, 0.1, 0.1, 0.01).get_angle(), 0.);
+
+        let mut updated = blob.clone();
+        updated.update(Duration::from_secs(1), Position { x: 0.5, y: 0.5 }, -PI / 2., 0.1, 0.1, 0.01);
+
+        assert!((updated.get_angle() - 1.5 * PI).abs() < 1e-6);
+        assert!((updated.get_rotation_speed() - 0.25).abs() < 1e-5);
+    }
 }
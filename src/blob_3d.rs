@@ -0,0 +1,335 @@
+use std::time::Duration;
+
+use crate::{cursor::{smooth, rotation_speed, MotionOrigin, DEFAULT_MIN_DELTA_TIME}, cursor_3d::{Position3D, Velocity3D}};
+
+/// Euler angles around the three axes.
+/// Depending on the context, values are either angles in radians or rotation speeds in turns per second
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotation3D {
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+}
+
+impl Rotation3D {
+    pub fn get_speed(&self) -> f32 {
+        (self.roll * self.roll + self.pitch * self.pitch + self.yaw * self.yaw).sqrt()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blob3D {
+    pub(crate) session_id: i32,
+    pub(crate) position: Position3D,
+    pub(crate) velocity: Velocity3D,
+    pub(crate) acceleration: f32,
+    pub(crate) angle: Rotation3D,
+    pub(crate) rotation_speed: Rotation3D,
+    pub(crate) rotation_acceleration: f32,
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) depth: f32,
+    pub(crate) volume: f32,
+    pub(crate) extra_args: Vec<f32>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<(Position3D, Rotation3D)>,
+}
+
+impl Blob3D {
+    /// Creates a new [Blob3D]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `position` - a normalized [Position3D]
+    /// * `angle` - a [Rotation3D] of angles in radians
+    /// * `width` - a normalized width
+    /// * `height` - a normalized height
+    /// * `depth` - a normalized depth
+    /// * `volume` - a normalized volume
+    pub fn new(
+        session_id: i32,
+        position: Position3D,
+        angle: Rotation3D,
+        width: f32,
+        height: f32,
+        depth: f32,
+        volume: f32,
+    ) -> Self {
+        Self {
+            session_id,
+            position,
+            velocity: Velocity3D::default(),
+            acceleration: 0f32,
+            angle,
+            rotation_speed: Rotation3D::default(),
+            rotation_acceleration: 0f32,
+            width,
+            height,
+            depth,
+            volume,
+            extra_args: Vec::new(),
+            motion_origin: MotionOrigin::default(),
+        }
+    }
+
+    /// Returns this [Blob3D] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity3D]
+    /// * `rotation_speed` - a [Rotation3D] of rotation speeds in turns per second
+    /// * `acceleration` - a normalized acceleration
+    /// * `rotation_acceleration` - a rotation acceleration in turns per second squared
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity3D,
+        rotation_speed: Rotation3D,
+        acceleration: f32,
+        rotation_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.rotation_speed = rotation_speed;
+        self.acceleration = acceleration;
+        self.rotation_acceleration = rotation_acceleration;
+        self
+    }
+
+    /// Updates the [Blob3D], computing its velocity, acceleration, rotation speed and rotation acceleration once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position3D]
+    /// * `angle` - the new [Rotation3D] angles
+    /// * `width` - the new width
+    /// * `height` - the new height
+    /// * `depth` - the new depth
+    /// * `volume` - the new volume
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        delta_time: Duration,
+        position: Position3D,
+        angle: Rotation3D,
+        width: f32,
+        height: f32,
+        depth: f32,
+        volume: f32,
+    ) {
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position, angle, width, height, depth, volume);
+    }
+
+    /// Updates the [Blob3D], computing its velocity, acceleration, rotation speed and rotation acceleration once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// Closer updates keep the previous motion instead of producing a spike, while the other values are still updated
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position3D]
+    /// * `angle` - the new [Rotation3D] angles
+    /// * `width` - the new width
+    /// * `height` - the new height
+    /// * `depth` - the new depth
+    /// * `volume` - the new volume
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_with_min_delta_time(
+        &mut self,
+        delta_time: Duration,
+        min_delta_time: Duration,
+        position: Position3D,
+        angle: Rotation3D,
+        width: f32,
+        height: f32,
+        depth: f32,
+        volume: f32,
+    ) {
+        let current = (self.position.clone(), self.angle);
+
+        if let Some(((previous_position, previous_angle), delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &current) {
+            let distance = position.distance_from(&previous_position);
+            let delta_x = position.x - previous_position.x;
+            let delta_y = position.y - previous_position.y;
+            let delta_z = position.z - previous_position.z;
+
+            let last_speed = self.velocity.get_speed();
+            let speed = distance / delta_time;
+
+            self.velocity = Velocity3D {
+                x: delta_x / delta_time,
+                y: delta_y / delta_time,
+                z: delta_z / delta_time,
+            };
+
+            self.acceleration = (speed - last_speed) / delta_time;
+
+            let last_rotation_speed = self.rotation_speed.get_speed();
+
+            self.rotation_speed = Rotation3D {
+                roll: rotation_speed(angle.roll, previous_angle.roll, delta_time),
+                pitch: rotation_speed(angle.pitch, previous_angle.pitch, delta_time),
+                yaw: rotation_speed(angle.yaw, previous_angle.yaw, delta_time),
+            };
+
+            self.rotation_acceleration = (self.rotation_speed.get_speed() - last_rotation_speed) / delta_time;
+        }
+
+        self.position = position;
+        self.angle = angle;
+        self.width = width;
+        self.height = height;
+        self.depth = depth;
+        self.volume = volume;
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    /// Returns the float arguments following the standard ones in the received `set` message, such as vendor-specific values of extended trackers
+    pub fn get_extra_args(&self) -> &[f32] {
+        &self.extra_args
+    }
+
+    pub fn get_position(&self) -> &Position3D {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_z_position(&self) -> f32 {
+        self.position.z
+    }
+
+    pub fn get_velocity(&self) -> &Velocity3D {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_z_velocity(&self) -> f32 {
+        self.velocity.z
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns the roll, pitch and yaw angles in radians
+    pub fn get_angle(&self) -> &Rotation3D {
+        &self.angle
+    }
+
+    /// Returns the roll, pitch and yaw rotation speeds in turn per seconds
+    pub fn get_rotation_speed(&self) -> &Rotation3D {
+        &self.rotation_speed
+    }
+
+    /// Returns the combined rotation acceleration in turn per seconds squared
+    pub fn get_rotation_acceleration(&self) -> f32 {
+        self.rotation_acceleration
+    }
+
+    /// Returns the normalized width
+    pub fn get_width(&self) -> f32 {
+        self.width
+    }
+
+    /// Returns the normalized height
+    pub fn get_height(&self) -> f32 {
+        self.height
+    }
+
+    /// Returns the normalized depth
+    pub fn get_depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// Returns the normalized volume
+    pub fn get_volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// Low-pass filters the velocity, rotation speeds and accelerations of this [Blob3D] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Blob3D]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Blob3D, alpha: f32) {
+        self.velocity = Velocity3D {
+            x: smooth(self.velocity.x, previous.velocity.x, alpha),
+            y: smooth(self.velocity.y, previous.velocity.y, alpha),
+            z: smooth(self.velocity.z, previous.velocity.z, alpha),
+        };
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+        self.rotation_speed = Rotation3D {
+            roll: smooth(self.rotation_speed.roll, previous.rotation_speed.roll, alpha),
+            pitch: smooth(self.rotation_speed.pitch, previous.rotation_speed.pitch, alpha),
+            yaw: smooth(self.rotation_speed.yaw, previous.rotation_speed.yaw, alpha),
+        };
+        self.rotation_acceleration = smooth(self.rotation_acceleration, previous.rotation_acceleration, alpha);
+    }
+}
+
+impl PartialEq for Blob3D {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.get_z_position() == other.get_z_position()
+            && self.angle == other.angle
+            && self.velocity == other.velocity
+            && self.rotation_speed == other.rotation_speed
+            && self.acceleration == other.acceleration
+            && self.rotation_acceleration == other.rotation_acceleration
+            && self.width == other.width
+            && self.height == other.height
+            && self.depth == other.depth
+            && self.volume == other.volume
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{blob_3d::{Blob3D, Rotation3D}, cursor_3d::Position3D};
+
+    #[test]
+    fn blob_3d_update() {
+        let mut blob = Blob3D::new(0, Position3D { x: 0., y: 0., z: 0. }, Rotation3D::default(), 0., 0., 0., 0.);
+
+        blob.update(
+            Duration::from_secs(1),
+            Position3D { x: 1., y: 1., z: 1. },
+            Rotation3D { roll: 90f32.to_radians(), pitch: 180f32.to_radians(), yaw: 0. },
+            0.5,
+            0.5,
+            0.5,
+            0.125,
+        );
+
+        assert_eq!(blob.get_x_position(), 1.);
+        assert_eq!(blob.get_y_position(), 1.);
+        assert_eq!(blob.get_z_position(), 1.);
+        assert_eq!(blob.get_x_velocity(), 1.);
+        assert_eq!(blob.get_y_velocity(), 1.);
+        assert_eq!(blob.get_z_velocity(), 1.);
+        assert_eq!(blob.get_acceleration(), 3f32.sqrt());
+        assert_eq!(blob.get_rotation_speed().roll, 0.25);
+        assert_eq!(blob.get_rotation_speed().pitch, 0.5);
+        assert_eq!(blob.get_rotation_speed().yaw, 0.);
+        assert_eq!(blob.get_rotation_acceleration(), (0.25f32 * 0.25 + 0.5 * 0.5).sqrt());
+        assert_eq!(blob.get_width(), 0.5);
+        assert_eq!(blob.get_height(), 0.5);
+        assert_eq!(blob.get_depth(), 0.5);
+        assert_eq!(blob.get_volume(), 0.125);
+    }
+}
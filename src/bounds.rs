@@ -0,0 +1,242 @@
+use std::time::Duration;
+
+use crate::cursor::{Position, Velocity, linear_motion, smooth, rotation_motion, MotionOrigin, DEFAULT_MIN_DELTA_TIME};
+
+/// The oriented bounding box of a contact, as sent by the TUIO 2.0 bounds profile
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounds {
+    pub(crate) session_id: i32,
+    pub(crate) position: Position,
+    pub(crate) angle: f32,
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) area: f32,
+    pub(crate) velocity: Velocity,
+    pub(crate) rotation_speed: f32,
+    pub(crate) acceleration: f32,
+    pub(crate) rotation_acceleration: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<(Position, f32)>,
+}
+
+impl Bounds {
+    /// Creates a new [Bounds]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `position` - a normalized [Position] of the center
+    /// * `angle` - an angle in radians
+    /// * `width` - a normalized width
+    /// * `height` - a normalized height
+    /// * `area` - a normalized area
+    pub fn new(
+        session_id: i32,
+        position: Position,
+        angle: f32,
+        width: f32,
+        height: f32,
+        area: f32,
+    ) -> Self {
+        Self {
+            session_id,
+            position,
+            angle,
+            width,
+            height,
+            area,
+            velocity: Velocity::default(),
+            rotation_speed: 0f32,
+            acceleration: 0f32,
+            rotation_acceleration: 0f32,
+            motion_origin: MotionOrigin::default(),
+        }
+    }
+
+    /// Returns this [Bounds] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity]
+    /// * `rotation_speed` - a rotation speed in turns per second
+    /// * `acceleration` - a normalized acceleration
+    /// * `rotation_acceleration` - a rotation acceleration in turns per second squared
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity,
+        rotation_speed: f32,
+        acceleration: f32,
+        rotation_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.rotation_speed = rotation_speed;
+        self.acceleration = acceleration;
+        self.rotation_acceleration = rotation_acceleration;
+        self
+    }
+
+    /// Updates the [Bounds], computing its velocity, acceleration, rotation speed and rotation acceleration once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    /// * `width` - the new width
+    /// * `height` - the new height
+    /// * `area` - the new area
+    pub fn update(
+        &mut self,
+        delta_time: Duration,
+        position: Position,
+        angle: f32,
+        width: f32,
+        height: f32,
+        area: f32,
+    ) {
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position, angle, width, height, area);
+    }
+
+    /// Updates the [Bounds], computing its velocity, acceleration, rotation speed and rotation acceleration once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// Closer updates keep the previous motion instead of producing a spike, while the other values are still updated
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    /// * `width` - the new width
+    /// * `height` - the new height
+    /// * `area` - the new area
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_with_min_delta_time(
+        &mut self,
+        delta_time: Duration,
+        min_delta_time: Duration,
+        position: Position,
+        angle: f32,
+        width: f32,
+        height: f32,
+        area: f32,
+    ) {
+        self.width = width;
+        self.height = height;
+        self.area = area;
+
+        if let Some(((previous_position, previous_angle), delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &(self.position, self.angle)) {
+            (self.velocity, self.acceleration) = linear_motion(&position, &previous_position, &self.velocity, delta_time);
+            (self.rotation_speed, self.rotation_acceleration) = rotation_motion(angle, previous_angle, self.rotation_speed, delta_time);
+        }
+
+        self.position = position;
+        self.angle = angle;
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_velocity(&self) -> &Velocity {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns the angle in radians
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Returns the rotation speed in turn per seconds
+    pub fn get_rotation_speed(&self) -> f32 {
+        self.rotation_speed
+    }
+
+    /// Returns the rotation acceleration in turn per seconds squared
+    pub fn get_rotation_acceleration(&self) -> f32 {
+        self.rotation_acceleration
+    }
+
+    /// Returns the normalized width
+    pub fn get_width(&self) -> f32 {
+        self.width
+    }
+
+    /// Returns the normalized height
+    pub fn get_height(&self) -> f32 {
+        self.height
+    }
+
+    /// Returns the normalized area
+    pub fn get_area(&self) -> f32 {
+        self.area
+    }
+
+    /// Low-pass filters the velocity, rotation speed and accelerations of this [Bounds] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Bounds]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Bounds, alpha: f32) {
+        self.velocity = self.velocity.smooth(&previous.velocity, alpha);
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+        self.rotation_speed = smooth(self.rotation_speed, previous.rotation_speed, alpha);
+        self.rotation_acceleration = smooth(self.rotation_acceleration, previous.rotation_acceleration, alpha);
+    }
+}
+
+impl PartialEq for Bounds {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.angle == other.angle
+            && self.width == other.width
+            && self.height == other.height
+            && self.area == other.area
+            && self.velocity == other.velocity
+            && self.rotation_speed == other.rotation_speed
+            && self.acceleration == other.acceleration
+            && self.rotation_acceleration == other.rotation_acceleration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{blob::Blob, bounds::Bounds, cursor::Position};
+
+    #[test]
+    fn bounds_update_matches_blob() {
+        let mut bounds = Bounds::new(0, Position { x: 0., y: 0. }, 0., 0.1, 0.1, 0.01);
+        let mut blob = Blob::new(0, Position { x: 0., y: 0. }, 0., 0.1, 0.1, 0.01);
+
+        for (delta_time, x, angle) in [(100, 0.2, 30f32), (50, 0.1, 340.)] {
+            bounds.update(Duration::from_millis(delta_time), Position { x, y: 0.5 }, angle.to_radians(), 0.2, 0.1, 0.02);
+            blob.update(Duration::from_millis(delta_time), Position { x, y: 0.5 }, angle.to_radians(), 0.2, 0.1, 0.02);
+
+            assert_eq!(bounds.get_velocity(), blob.get_velocity());
+            assert_eq!(bounds.get_acceleration(), blob.get_acceleration());
+            assert_eq!(bounds.get_rotation_speed(), blob.get_rotation_speed());
+            assert_eq!(bounds.get_rotation_acceleration(), blob.get_rotation_acceleration());
+            assert_eq!(bounds.get_width(), 0.2);
+        }
+    }
+}
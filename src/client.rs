@@ -1,33 +1,419 @@
-use std::{time::{Instant, Duration}, sync::{RwLock, Arc, Mutex}, collections::HashSet, cell::{RefCell, Cell}, thread};
+use std::{fmt::Write, net::{IpAddr, Ipv4Addr, SocketAddr}, panic::{self, AssertUnwindSafe}, time::{Duration, Instant, SystemTime}, cell::Cell, path::Path, io::ErrorKind, sync::{RwLock, Arc, atomic::{AtomicU64, Ordering}}, collections::{HashSet, HashMap, VecDeque}, cell::RefCell, thread::{self, JoinHandle}};
 
 use indexmap::IndexMap;
-use ringbuffer::{ConstGenericRingBuffer, RingBufferWrite, RingBufferRead};
+use ringbuffer::{RingBufferWrite, RingBufferRead};
 use rosc::{OscPacket};
 
-use crate::{osc_receiver::{UdpReceiver, RoscReceiver}, cursor::{Cursor}, object::Object, blob::Blob, errors::{TuioError, OscReceiverError}, osc_encode_decode::{OscDecoder, DecodeOsc, self, Set}};
+use crate::{osc_receiver::{UdpReceiver, TcpReceiver, RawTap, RoscReceiver}, recording::{Player, Recorder}, loopback::{LoopbackTransport, PacketBuffer}, cursor::{Cursor, Lifetime, PixelRect, Position}, cursor_3d::Cursor3D, cursor_25d::Cursor25D, object::Object, blob::Blob, blob_3d::Blob3D, token::Token, pointer::Pointer, symbol::Symbol, bounds::Bounds, geometry::Geometry, control::Control, touch_point::TouchPoint, transform::Transform, remap::SessionIdRemapper, coalesce::UpdateCoalescer, persist::ObjectPersistence, clock::{Clock, SystemClock}, errors::{TuioError, OscReceiverError}, osc_encode_decode::{OscDecoder, self, Set, TuioBundle, Strictness, BoundsPolicy, TuioBundleType, TuioVersion}};
 
 #[derive(Default)]
 pub struct TuioEvents {
     pub cursor_events: Vec<CursorEvent>,
     pub object_events: Vec<ObjectEvent>,
     pub blob_events: Vec<BlobEvent>,
+    pub cursor_3d_events: Vec<Cursor3DEvent>,
+    pub cursor_25d_events: Vec<Cursor25DEvent>,
+    pub blob_3d_events: Vec<Blob3DEvent>,
+    pub token_events: Vec<TokenEvent>,
+    pub pointer_events: Vec<PointerEvent>,
+    pub symbol_events: Vec<SymbolEvent>,
+    pub bounds_events: Vec<BoundsEvent>,
+    pub geometry_events: Vec<GeometryEvent>,
+    pub control_events: Vec<ControlEvent>,
+}
+
+/// Base trait to implement a receiver of TUIO events
+///
+/// Every method has an empty default implementation, so only the events of interest need to be implemented
+#[allow(unused_variables)]
+pub trait Listener {
+    /// Called for a new [Cursor]
+    fn cursor_added(&self, source_name: &str, cursor: &Cursor) {}
+
+    /// Called for an updated [Cursor]
+    fn cursor_updated(&self, source_name: &str, cursor: &Cursor) {}
+
+    /// Called for a removed [Cursor]
+    fn cursor_removed(&self, source_name: &str, cursor: &Cursor) {}
+
+    /// Called for a new [Object]
+    fn object_added(&self, source_name: &str, object: &Object) {}
+
+    /// Called for an updated [Object]
+    fn object_updated(&self, source_name: &str, object: &Object) {}
+
+    /// Called for a removed [Object]
+    fn object_removed(&self, source_name: &str, object: &Object) {}
+
+    /// Called for a new [Blob]
+    fn blob_added(&self, source_name: &str, blob: &Blob) {}
+
+    /// Called for an updated [Blob]
+    fn blob_updated(&self, source_name: &str, blob: &Blob) {}
+
+    /// Called for a removed [Blob]
+    fn blob_removed(&self, source_name: &str, blob: &Blob) {}
+
+    /// Called for a new [Cursor3D]
+    fn cursor_3d_added(&self, source_name: &str, cursor: &Cursor3D) {}
+
+    /// Called for an updated [Cursor3D]
+    fn cursor_3d_updated(&self, source_name: &str, cursor: &Cursor3D) {}
+
+    /// Called for a removed [Cursor3D]
+    fn cursor_3d_removed(&self, source_name: &str, cursor: &Cursor3D) {}
+
+    /// Called for a new [Cursor25D]
+    fn cursor_25d_added(&self, source_name: &str, cursor: &Cursor25D) {}
+
+    /// Called for an updated [Cursor25D]
+    fn cursor_25d_updated(&self, source_name: &str, cursor: &Cursor25D) {}
+
+    /// Called for a removed [Cursor25D]
+    fn cursor_25d_removed(&self, source_name: &str, cursor: &Cursor25D) {}
+
+    /// Called for a new [Blob3D]
+    fn blob_3d_added(&self, source_name: &str, blob: &Blob3D) {}
+
+    /// Called for an updated [Blob3D]
+    fn blob_3d_updated(&self, source_name: &str, blob: &Blob3D) {}
+
+    /// Called for a removed [Blob3D]
+    fn blob_3d_removed(&self, source_name: &str, blob: &Blob3D) {}
+
+    /// Called for a new [Token]
+    fn token_added(&self, source_name: &str, token: &Token) {}
+
+    /// Called for an updated [Token]
+    fn token_updated(&self, source_name: &str, token: &Token) {}
+
+    /// Called for a removed [Token]
+    fn token_removed(&self, source_name: &str, token: &Token) {}
+
+    /// Called for a new [Pointer]
+    fn pointer_added(&self, source_name: &str, pointer: &Pointer) {}
+
+    /// Called for an updated [Pointer]
+    fn pointer_updated(&self, source_name: &str, pointer: &Pointer) {}
+
+    /// Called for a removed [Pointer]
+    fn pointer_removed(&self, source_name: &str, pointer: &Pointer) {}
+
+    /// Called for a new [Symbol]
+    fn symbol_added(&self, source_name: &str, symbol: &Symbol) {}
+
+    /// Called for an updated [Symbol]
+    fn symbol_updated(&self, source_name: &str, symbol: &Symbol) {}
+
+    /// Called for a removed [Symbol]
+    fn symbol_removed(&self, source_name: &str, symbol: &Symbol) {}
+
+    /// Called for a new [Bounds]
+    fn bounds_added(&self, source_name: &str, bounds: &Bounds) {}
+
+    /// Called for an updated [Bounds]
+    fn bounds_updated(&self, source_name: &str, bounds: &Bounds) {}
+
+    /// Called for a removed [Bounds]
+    fn bounds_removed(&self, source_name: &str, bounds: &Bounds) {}
+
+    /// Called for a new [Geometry]
+    fn geometry_added(&self, source_name: &str, geometry: &Geometry) {}
+
+    /// Called for an updated [Geometry]
+    fn geometry_updated(&self, source_name: &str, geometry: &Geometry) {}
+
+    /// Called for a removed [Geometry]
+    fn geometry_removed(&self, source_name: &str, geometry: &Geometry) {}
+
+    /// Called for a new [Control]
+    fn control_added(&self, source_name: &str, control: &Control) {}
+
+    /// Called for an updated [Control]
+    fn control_updated(&self, source_name: &str, control: &Control) {}
+
+    /// Called for a removed [Control]
+    fn control_removed(&self, source_name: &str, control: &Control) {}
+}
+
+/// A [Listener] shared between a [Client] and the rest of the application
+pub type SharedListener = Arc<dyn Listener + Send + Sync>;
+
+impl TuioEvents {
+    /// Returns true if there is no event of any profile
+    pub fn is_empty(&self) -> bool {
+        self.cursor_events.is_empty()
+            && self.object_events.is_empty()
+            && self.blob_events.is_empty()
+            && self.cursor_3d_events.is_empty()
+            && self.cursor_25d_events.is_empty()
+            && self.blob_3d_events.is_empty()
+            && self.token_events.is_empty()
+            && self.pointer_events.is_empty()
+            && self.symbol_events.is_empty()
+            && self.bounds_events.is_empty()
+            && self.geometry_events.is_empty()
+            && self.control_events.is_empty()
+    }
+
+    /// Returns the number of events of every profile
+    pub fn len(&self) -> usize {
+        self.cursor_events.len()
+            + self.object_events.len()
+            + self.blob_events.len()
+            + self.cursor_3d_events.len()
+            + self.cursor_25d_events.len()
+            + self.blob_3d_events.len()
+            + self.token_events.len()
+            + self.pointer_events.len()
+            + self.symbol_events.len()
+            + self.bounds_events.len()
+            + self.geometry_events.len()
+            + self.control_events.len()
+    }
+
+    /// Appends an event to the events of its profile
+    pub(crate) fn push(&mut self, event: TuioEvent) {
+        match event {
+            TuioEvent::Cursor(event) => self.cursor_events.push(event),
+            TuioEvent::Object(event) => self.object_events.push(event),
+            TuioEvent::Blob(event) => self.blob_events.push(event),
+            TuioEvent::Cursor3D(event) => self.cursor_3d_events.push(event),
+            TuioEvent::Cursor25D(event) => self.cursor_25d_events.push(event),
+            TuioEvent::Blob3D(event) => self.blob_3d_events.push(event),
+            TuioEvent::Token(event) => self.token_events.push(event),
+            TuioEvent::Pointer(event) => self.pointer_events.push(event),
+            TuioEvent::Symbol(event) => self.symbol_events.push(event),
+            TuioEvent::Bounds(event) => self.bounds_events.push(event),
+            TuioEvent::Geometry(event) => self.geometry_events.push(event),
+            TuioEvent::Control(event) => self.control_events.push(event),
+        }
+    }
+
+    /// Calls the [Listener] methods matching each event
+    ///
+    /// # Arguments
+    /// * `listener` - the [Listener] to notify
+    pub fn dispatch(&self, listener: &dyn Listener) {
+        self.visit(|call| call(listener));
+    }
+
+    /// Calls the methods matching each event on several [Listener]s
+    ///
+    /// Every listener is notified of an event, in the order of the slice, before the next event is dispatched.
+    /// A panicking listener does not prevent the other listeners, nor itself, from being notified of the following events
+    /// # Arguments
+    /// * `listeners` - the [Listener]s to notify
+    pub fn dispatch_to(&self, listeners: &[SharedListener]) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("dispatch", events = self.len(), listeners = listeners.len()).entered();
+
+        self.visit(|call| {
+            #[cfg(feature = "tracing")]
+            call(&crate::trace::TracingListener);
+
+            for listener in listeners {
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| call(listener.as_ref())));
+            }
+        });
+    }
+
+    /// Calls `notify` once per event, in the order of [TuioEvents::dispatch], with the call of the matching [Listener] method
+    fn visit(&self, mut notify: impl FnMut(&dyn Fn(&dyn Listener))) {
+        for event in &self.cursor_events {
+            match event {
+                CursorEvent::New(data) => notify(&|listener| listener.cursor_added(&data.source_name, &data.cursor)),
+                CursorEvent::Update(data) => notify(&|listener| listener.cursor_updated(&data.source_name, &data.cursor)),
+                CursorEvent::Remove(data) => notify(&|listener| listener.cursor_removed(&data.source_name, &data.cursor)),
+            }
+        }
+
+        for event in &self.object_events {
+            match event {
+                ObjectEvent::New(data) => notify(&|listener| listener.object_added(&data.source_name, &data.object)),
+                ObjectEvent::Update(data) => notify(&|listener| listener.object_updated(&data.source_name, &data.object)),
+                ObjectEvent::Remove(data) => notify(&|listener| listener.object_removed(&data.source_name, &data.object)),
+            }
+        }
+
+        for event in &self.blob_events {
+            match event {
+                BlobEvent::New(data) => notify(&|listener| listener.blob_added(&data.source_name, &data.blob)),
+                BlobEvent::Update(data) => notify(&|listener| listener.blob_updated(&data.source_name, &data.blob)),
+                BlobEvent::Remove(data) => notify(&|listener| listener.blob_removed(&data.source_name, &data.blob)),
+            }
+        }
+
+        for event in &self.cursor_3d_events {
+            match event {
+                Cursor3DEvent::New(data) => notify(&|listener| listener.cursor_3d_added(&data.source_name, &data.cursor)),
+                Cursor3DEvent::Update(data) => notify(&|listener| listener.cursor_3d_updated(&data.source_name, &data.cursor)),
+                Cursor3DEvent::Remove(data) => notify(&|listener| listener.cursor_3d_removed(&data.source_name, &data.cursor)),
+            }
+        }
+
+        for event in &self.cursor_25d_events {
+            match event {
+                Cursor25DEvent::New(data) => notify(&|listener| listener.cursor_25d_added(&data.source_name, &data.cursor)),
+                Cursor25DEvent::Update(data) => notify(&|listener| listener.cursor_25d_updated(&data.source_name, &data.cursor)),
+                Cursor25DEvent::Remove(data) => notify(&|listener| listener.cursor_25d_removed(&data.source_name, &data.cursor)),
+            }
+        }
+
+        for event in &self.blob_3d_events {
+            match event {
+                Blob3DEvent::New(data) => notify(&|listener| listener.blob_3d_added(&data.source_name, &data.blob)),
+                Blob3DEvent::Update(data) => notify(&|listener| listener.blob_3d_updated(&data.source_name, &data.blob)),
+                Blob3DEvent::Remove(data) => notify(&|listener| listener.blob_3d_removed(&data.source_name, &data.blob)),
+            }
+        }
+
+        for event in &self.token_events {
+            match event {
+                TokenEvent::New(data) => notify(&|listener| listener.token_added(&data.source_name, &data.token)),
+                TokenEvent::Update(data) => notify(&|listener| listener.token_updated(&data.source_name, &data.token)),
+                TokenEvent::Remove(data) => notify(&|listener| listener.token_removed(&data.source_name, &data.token)),
+            }
+        }
+
+        for event in &self.pointer_events {
+            match event {
+                PointerEvent::New(data) => notify(&|listener| listener.pointer_added(&data.source_name, &data.pointer)),
+                PointerEvent::Update(data) => notify(&|listener| listener.pointer_updated(&data.source_name, &data.pointer)),
+                PointerEvent::Remove(data) => notify(&|listener| listener.pointer_removed(&data.source_name, &data.pointer)),
+            }
+        }
+
+        for event in &self.symbol_events {
+            match event {
+                SymbolEvent::New(data) => notify(&|listener| listener.symbol_added(&data.source_name, &data.symbol)),
+                SymbolEvent::Update(data) => notify(&|listener| listener.symbol_updated(&data.source_name, &data.symbol)),
+                SymbolEvent::Remove(data) => notify(&|listener| listener.symbol_removed(&data.source_name, &data.symbol)),
+            }
+        }
+
+        for event in &self.bounds_events {
+            match event {
+                BoundsEvent::New(data) => notify(&|listener| listener.bounds_added(&data.source_name, &data.bounds)),
+                BoundsEvent::Update(data) => notify(&|listener| listener.bounds_updated(&data.source_name, &data.bounds)),
+                BoundsEvent::Remove(data) => notify(&|listener| listener.bounds_removed(&data.source_name, &data.bounds)),
+            }
+        }
+
+        for event in &self.geometry_events {
+            match event {
+                GeometryEvent::New(data) => notify(&|listener| listener.geometry_added(&data.source_name, &data.geometry)),
+                GeometryEvent::Update(data) => notify(&|listener| listener.geometry_updated(&data.source_name, &data.geometry)),
+                GeometryEvent::Remove(data) => notify(&|listener| listener.geometry_removed(&data.source_name, &data.geometry)),
+            }
+        }
+
+        for event in &self.control_events {
+            match event {
+                ControlEvent::New(data) => notify(&|listener| listener.control_added(&data.source_name, &data.control)),
+                ControlEvent::Update(data) => notify(&|listener| listener.control_updated(&data.source_name, &data.control)),
+                ControlEvent::Remove(data) => notify(&|listener| listener.control_removed(&data.source_name, &data.control)),
+            }
+        }
+    }
 }
 
 pub struct CursorData {
     pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
     pub cursor: Cursor
 }
 
 pub struct ObjectData {
     pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
     pub object: Object
 }
 
 pub struct BlobData {
     pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
     pub blob: Blob
 }
 
+pub struct Cursor3DData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub cursor: Cursor3D
+}
+
+pub struct Cursor25DData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub cursor: Cursor25D
+}
+
+pub struct Blob3DData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub blob: Blob3D
+}
+
+pub struct TokenData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub token: Token
+}
+
+pub struct PointerData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub pointer: Pointer
+}
+
+pub struct SymbolData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub symbol: Symbol
+}
+
+pub struct BoundsData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub bounds: Bounds
+}
+
+pub struct GeometryData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub geometry: Geometry
+}
+
+pub struct ControlData {
+    pub source_name: String,
+    pub time: SystemTime,
+    /// The frame sequence number of the bundle, -1 for a redundant frame or an input removed after a timeout
+    pub fseq: i32,
+    pub control: Control
+}
+
 pub enum CursorEvent {
     New(CursorData),
     Update(CursorData),
@@ -46,22 +432,368 @@ pub enum BlobEvent {
     Remove(BlobData),
 }
 
+pub enum Cursor3DEvent {
+    New(Cursor3DData),
+    Update(Cursor3DData),
+    Remove(Cursor3DData),
+}
+
+pub enum Cursor25DEvent {
+    New(Cursor25DData),
+    Update(Cursor25DData),
+    Remove(Cursor25DData),
+}
+
+pub enum Blob3DEvent {
+    New(Blob3DData),
+    Update(Blob3DData),
+    Remove(Blob3DData),
+}
+
+pub enum TokenEvent {
+    New(TokenData),
+    Update(TokenData),
+    Remove(TokenData),
+}
+
+pub enum PointerEvent {
+    New(PointerData),
+    Update(PointerData),
+    Remove(PointerData),
+}
+
+pub enum SymbolEvent {
+    New(SymbolData),
+    Update(SymbolData),
+    Remove(SymbolData),
+}
+
+pub enum BoundsEvent {
+    New(BoundsData),
+    Update(BoundsData),
+    Remove(BoundsData),
+}
+
+pub enum GeometryEvent {
+    New(GeometryData),
+    Update(GeometryData),
+    Remove(GeometryData),
+}
+
+pub enum ControlEvent {
+    New(ControlData),
+    Update(ControlData),
+    Remove(ControlData),
+}
+
+/// A single event of any TUIO profile, as queued by [Client::drain_events]
+pub enum TuioEvent {
+    Cursor(CursorEvent),
+    Object(ObjectEvent),
+    Blob(BlobEvent),
+    Cursor3D(Cursor3DEvent),
+    Cursor25D(Cursor25DEvent),
+    Blob3D(Blob3DEvent),
+    Token(TokenEvent),
+    Pointer(PointerEvent),
+    Symbol(SymbolEvent),
+    Bounds(BoundsEvent),
+    Geometry(GeometryEvent),
+    Control(ControlEvent),
+}
+
+impl IntoIterator for TuioEvents {
+    type Item = TuioEvent;
+    type IntoIter = std::vec::IntoIter<TuioEvent>;
+
+    /// Flattens the events of every profile, in the order [TuioEvents::dispatch] notifies them
+    fn into_iter(self) -> Self::IntoIter {
+        let mut events = Vec::new();
+
+        events.extend(self.cursor_events.into_iter().map(TuioEvent::Cursor));
+        events.extend(self.object_events.into_iter().map(TuioEvent::Object));
+        events.extend(self.blob_events.into_iter().map(TuioEvent::Blob));
+        events.extend(self.cursor_3d_events.into_iter().map(TuioEvent::Cursor3D));
+        events.extend(self.cursor_25d_events.into_iter().map(TuioEvent::Cursor25D));
+        events.extend(self.blob_3d_events.into_iter().map(TuioEvent::Blob3D));
+        events.extend(self.token_events.into_iter().map(TuioEvent::Token));
+        events.extend(self.pointer_events.into_iter().map(TuioEvent::Pointer));
+        events.extend(self.symbol_events.into_iter().map(TuioEvent::Symbol));
+        events.extend(self.bounds_events.into_iter().map(TuioEvent::Bounds));
+        events.extend(self.geometry_events.into_iter().map(TuioEvent::Geometry));
+        events.extend(self.control_events.into_iter().map(TuioEvent::Control));
+
+        events.into_iter()
+    }
+}
+
 #[derive(Default)]
 pub struct SourceCollection {
+    last_frames: HashMap<TuioBundleType, i32>,
+    frame_session_ids: HashMap<TuioBundleType, HashSet<i32>>,
+    observed_profiles: HashSet<TuioBundleType>,
+    last_update: Option<Instant>,
     pub object_map: IndexMap<i32, Object>,
     pub blob_map: IndexMap<i32, Blob>,
-    pub cursor_map: IndexMap<i32, Cursor>
+    pub cursor_map: IndexMap<i32, Cursor>,
+    pub cursor_3d_map: IndexMap<i32, Cursor3D>,
+    pub cursor_25d_map: IndexMap<i32, Cursor25D>,
+    pub blob_3d_map: IndexMap<i32, Blob3D>,
+    pub token_map: IndexMap<i32, Token>,
+    pub pointer_map: IndexMap<i32, Pointer>,
+    pub symbol_map: IndexMap<i32, Symbol>,
+    pub bounds_map: IndexMap<i32, Bounds>,
+    pub geometry_map: IndexMap<i32, Geometry>,
+    pub control_map: IndexMap<i32, Control>
+}
+
+impl SourceCollection {
+    /// Creates an empty [SourceCollection] with [Cursor], [Object] and [Blob] tables sized for a number of contacts
+    /// # Arguments
+    /// * `capacity` - the number of contacts of each profile stored without reallocating
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            object_map: IndexMap::with_capacity(capacity),
+            blob_map: IndexMap::with_capacity(capacity),
+            cursor_map: IndexMap::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if a frame sequence number is the last processed frame of a profile,
+    /// so its bundle is another part of a frame split across several bundles
+    /// # Arguments
+    /// * `tuio_type` - the profile of the frame
+    /// * `frame` - the frame sequence number
+    fn is_current_frame(&self, tuio_type: TuioBundleType, frame: i32) -> bool {
+        frame != -1 && self.last_frames.get(&tuio_type) == Some(&frame)
+    }
+
+    /// Returns true if a frame sequence number skips at least one frame after the last processed frame of a profile
+    /// # Arguments
+    /// * `tuio_type` - the profile of the frame
+    /// * `frame` - the frame sequence number
+    fn is_frame_gap(&self, tuio_type: TuioBundleType, frame: i32) -> bool {
+        frame != -1 && self.last_frames.get(&tuio_type).is_some_and(|last_frame| (2..=i32::MAX).contains(&frame.wrapping_sub(*last_frame)))
+    }
+
+    /// Returns the session IDs of the alive inputs of a profile
+    fn session_ids(&self, tuio_type: TuioBundleType) -> HashSet<i32> {
+        match tuio_type {
+            TuioBundleType::Cursor => self.cursor_map.keys().copied().collect(),
+            TuioBundleType::Object => self.object_map.keys().copied().collect(),
+            TuioBundleType::Blob => self.blob_map.keys().copied().collect(),
+            TuioBundleType::Cursor3D => self.cursor_3d_map.keys().copied().collect(),
+            TuioBundleType::Cursor25D => self.cursor_25d_map.keys().copied().collect(),
+            TuioBundleType::Blob3D => self.blob_3d_map.keys().copied().collect(),
+            TuioBundleType::Token => self.token_map.keys().copied().collect(),
+            TuioBundleType::Pointer => self.pointer_map.keys().copied().collect(),
+            TuioBundleType::Symbol => self.symbol_map.keys().copied().collect(),
+            TuioBundleType::Bounds => self.bounds_map.keys().copied().collect(),
+            TuioBundleType::Geometry => self.geometry_map.keys().copied().collect(),
+            TuioBundleType::Control => self.control_map.keys().copied().collect(),
+            TuioBundleType::Unknown => HashSet::new(),
+        }
+    }
+
+    /// Updates the last frame sequence number of a profile
+    ///
+    /// Returns true if the frame must be processed, false if it is a duplicate or a late frame.
+    /// The redundant frame number -1 is always processed and a frame more than 100 frames behind is treated as a restart of the source
+    /// # Arguments
+    /// * `tuio_type` - the profile of the frame
+    /// * `frame` - the frame sequence number
+    fn update_frame(&mut self, tuio_type: TuioBundleType, frame: i32) -> bool {
+        if frame == -1 {
+            return true;
+        }
+
+        match self.last_frames.get(&tuio_type) {
+            Some(last_frame) => {
+                let delta = frame.wrapping_sub(*last_frame);
+
+                if !(-100..=0).contains(&delta) {
+                    self.last_frames.insert(tuio_type, frame);
+                    true
+                }
+                else {
+                    false
+                }
+            },
+            None => {
+                self.last_frames.insert(tuio_type, frame);
+                true
+            }
+        }
+    }
+}
+
+/// A builder of a [Client] receiving TUIO over UDP
+pub struct ClientBuilder {
+    address: SocketAddr,
+    recv_buffer_size: Option<usize>,
+    event_queue_limit: Option<(usize, QueuePolicy)>,
+    expected_contacts: usize,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            address: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3333),
+            recv_buffer_size: None,
+            event_queue_limit: None,
+            expected_contacts: 0,
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Creates a [ClientBuilder] listening on 127.0.0.1:3333
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the local socket address to bind
+    ///
+    /// # Arguments
+    /// * `address` - a socket address [SocketAddr], either IPv4 or IPv6
+    pub fn address(mut self, address: SocketAddr) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Sets the size of the socket's receive buffer, `SO_RCVBUF`, for sources sending many large bundles. Defaults to the OS default
+    ///
+    /// # Arguments
+    /// * `bytes` - the buffer size in bytes
+    pub fn recv_buffer_size(mut self, bytes: usize) -> Self {
+        self.recv_buffer_size = Some(bytes);
+        self
+    }
+
+    /// Enables the event queue with a maximum length, see [Client::set_event_queue] and [Client::set_event_queue_limit]
+    ///
+    /// # Arguments
+    /// * `max_len` - the maximum number of queued events
+    /// * `policy` - the [QueuePolicy] applied once the queue is full
+    pub fn event_queue(mut self, max_len: usize, policy: QueuePolicy) -> Self {
+        self.event_queue_limit = Some((max_len, policy));
+        self
+    }
+
+    /// Pre-sizes the tables of each source for a number of contacts, see [Client::set_expected_contacts]
+    ///
+    /// # Arguments
+    /// * `n` - the number of contacts routinely tracked per profile
+    pub fn expected_contacts(mut self, n: usize) -> Self {
+        self.expected_contacts = n;
+        self
+    }
+
+    /// Creates the configured [Client]
+    pub fn build(self) -> Result<Client, TuioError> {
+        let receiver = UdpReceiver::from_address(self.address)?;
+
+        if let Some(size) = self.recv_buffer_size {
+            receiver.set_recv_buffer_size(size)?;
+        }
+
+        let mut client = Client::from_osc_receiver(Arc::new(receiver), self.address.ip().is_loopback());
+
+        if let Some((max_len, policy)) = self.event_queue_limit {
+            client.set_event_queue(true);
+            client.set_event_queue_limit(max_len, policy);
+        }
+
+        client.set_expected_contacts(self.expected_contacts);
+
+        Ok(client)
+    }
+}
+
+/// What a [Client] does with new events once its event queue holds the maximum number of events, see [Client::set_event_queue_limit]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QueuePolicy {
+    /// Discards the oldest queued events to make room for the new ones.
+    /// The queue always holds the latest state, but a consumer may miss the new event of an input and only see its updates
+    #[default]
+    DropOldest,
+    /// Discards the new events which do not fit.
+    /// The queued history stays consistent, but the latest updates and removals are lost until the queue is drained
+    DropNewest,
+    /// Stops processing received packets until the queue is drained, leaving them in the receive buffer.
+    /// No event is discarded by the queue, but the receive buffer keeps only the latest 128 packets, and the queue may exceed its maximum length by the events of one packet
+    Block,
+}
+
+/// A snapshot of the decoding counters of a [Client], see [Client::stats]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientStats {
+    /// The number of TUIO bundles received, not counting the bundles nesting the bundles of several profiles
+    pub bundles_received: u64,
+    /// The number of messages of the received bundles which were decoded
+    pub messages_decoded: u64,
+    /// The number of malformed messages which were skipped, including every message of a malformed bundle,
+    /// the messages sent outside of a bundle and the packets which could not be decoded
+    pub messages_dropped: u64,
+    /// The number of jumps of more than one frame in the frame sequence of a profile.
+    /// A server which only sends the profiles that changed also skips frame sequence numbers
+    pub fseq_gaps: u64,
+    /// The number of bytes of the OSC packets received
+    pub bytes_received: u64,
+}
+
+/// The decoding counters of a [Client], incremented while packets are processed
+#[derive(Default)]
+struct StatCounters {
+    bundles_received: AtomicU64,
+    messages_decoded: AtomicU64,
+    messages_dropped: AtomicU64,
+    fseq_gaps: AtomicU64,
+    bytes_received: AtomicU64,
+}
+
+impl StatCounters {
+    fn snapshot(&self) -> ClientStats {
+        ClientStats {
+            bundles_received: self.bundles_received.load(Ordering::Relaxed),
+            messages_decoded: self.messages_decoded.load(Ordering::Relaxed),
+            messages_dropped: self.messages_dropped.load(Ordering::Relaxed),
+            fseq_gaps: self.fseq_gaps.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+        }
+    }
 }
 
 pub struct Client {
-    current_frame: Cell<i32>,
-    instant: Instant,
-    current_time: Cell<Duration>,
     pub source_list: RefCell<IndexMap<String, SourceCollection>>,
     osc_receivers: Vec<Arc<RoscReceiver>>,
-    packet_buffer: Arc<Mutex<ConstGenericRingBuffer<OscPacket, 128>>>,
+    packet_buffer: PacketBuffer,
     local_receiver: bool,
-    listen: Arc<RwLock<bool>>
+    listen: Arc<RwLock<bool>>,
+    receive_threads: RefCell<Vec<JoinHandle<()>>>,
+    smoothing: f32,
+    position_deadband: f32,
+    transform: Option<Transform>,
+    pixel_rect: Option<PixelRect>,
+    last_bundle_time: Cell<Option<SystemTime>>,
+    last_tuio2_frame: RefCell<Option<(String, (u16, u16))>>,
+    strictness: Strictness,
+    tuio_version: TuioVersion,
+    tuio2_frames: bool,
+    bounds_policy: BoundsPolicy,
+    namespace: String,
+    entity_timeout: Option<Duration>,
+    event_queue: Option<RefCell<VecDeque<TuioEvent>>>,
+    event_queue_limit: Option<(usize, QueuePolicy)>,
+    expected_contacts: usize,
+    listeners: Vec<SharedListener>,
+    session_id_remapper: Option<RefCell<SessionIdRemapper>>,
+    update_coalescer: Option<RefCell<UpdateCoalescer>>,
+    object_persistence: Option<RefCell<ObjectPersistence>>,
+    clock: Box<dyn Clock>,
+    disabled_profiles: HashSet<TuioBundleType>,
+    stats: Arc<StatCounters>
 }
 
 /// Keeps the entries whose keys are contained in a [HashSet]
@@ -89,205 +821,2226 @@ fn retain_by_ids<T>(index_map: &mut IndexMap<i32, T>, to_keep: HashSet<i32>) ->
 }
 
 impl Client {
-    pub fn new() -> Result<Self, std::io::Error> {
+    pub fn new() -> Result<Self, TuioError> {
         Self::from_port(3333)
     }
 
-    pub fn from_port(port: u16) -> Result<Self, std::io::Error> {
-        Ok(Self {
-            instant: Instant::now(),
-            osc_receivers: vec![Arc::new(UdpReceiver::from_port(port)?)],
-            current_frame: (-1).into(),
-            current_time: Cell::new(Duration::default()),
+    /// Returns a [ClientBuilder] to configure a [Client] receiving TUIO over UDP
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    pub fn from_port(port: u16) -> Result<Self, TuioError> {
+        Ok(Self::from_osc_receiver(Arc::new(UdpReceiver::from_port(port)?), true))
+    }
+
+    /// Creates a [Client] receiving TUIO over UDP on the provided socket address
+    ///
+    /// # Arguments
+    /// * `address` - the local socket address to bind, either IPv4 or IPv6
+    pub fn from_address(address: SocketAddr) -> Result<Self, TuioError> {
+        Ok(Self::from_osc_receiver(Arc::new(UdpReceiver::from_address(address)?), address.ip().is_loopback()))
+    }
+
+    /// Creates a [Client] receiving TUIO sent to a UDP multicast group on the port 3333
+    ///
+    /// # Arguments
+    /// * `group` - the IPv4 multicast group address
+    /// * `iface` - the address of the local interface joining the group, or [Ipv4Addr::UNSPECIFIED] to let the system choose
+    pub fn new_multicast(group: Ipv4Addr, iface: Ipv4Addr) -> Result<Self, TuioError> {
+        Ok(Self::from_osc_receiver(Arc::new(UdpReceiver::from_multicast(group, iface, 3333)?), false))
+    }
+
+    /// Creates a [Client] receiving TUIO over TCP from a source listening on the provided socket address
+    ///
+    /// TCP is another [crate::osc_receiver::OscReceiver], the transport abstraction shared with UDP, multicast and WebSocket,
+    /// rather than a separate `Transport` trait. Packets are framed with the big-endian int32 size prefix specified by OSC 1.0 for stream transports
+    /// and sent by the TUIO 1.1 reference implementation. SLIP framing belongs to OSC 1.1 and is not what these sources send.
+    /// Call [Client::connect] again to retry if the source is not listening yet
+    ///
+    /// # Arguments
+    /// * `address` - the socket address of the TUIO source
+    pub fn new_tcp(address: SocketAddr) -> Self {
+        Self::from_osc_receiver(Arc::new(TcpReceiver::new(address)), address.ip().is_loopback())
+    }
+
+    /// Creates a [Client] receiving TUIO bundles framed as binary WebSocket messages
+    ///
+    /// Text messages, such as the TUIO-as-JSON variant of some web trackers, are ignored
+    ///
+    /// # Arguments
+    /// * `url` - a `ws://host:port/path` URL
+    #[cfg(feature = "websocket")]
+    pub fn new_websocket(url: &str) -> Result<Self, TuioError> {
+        let receiver = crate::websocket::WebSocketReceiver::new(url)?;
+        Ok(Self::from_osc_receiver(Arc::new(receiver), false))
+    }
+
+    /// Creates a [Client] replaying a recording
+    ///
+    /// # Arguments
+    /// * `player` - a [Player] loaded with the recording
+    pub fn from_player(player: Player) -> Self {
+        Self::from_osc_receiver(Arc::new(player), true)
+    }
+
+    /// Creates a [Client] processing the packets sent through a [LoopbackTransport], without any socket
+    ///
+    /// The packets are processed by [Client::refresh] whether or not the [Client] is connected
+    /// # Arguments
+    /// * `transport` - the [LoopbackTransport] shared with a [crate::Server]
+    pub fn from_loopback(transport: &LoopbackTransport) -> Self {
+        Self::from_packet_buffer(transport.packet_buffer(), true)
+    }
+
+    /// Creates a [Client] without any receiver, processing the packets pushed to a [PacketBuffer] by its owner
+    pub(crate) fn from_packet_buffer(packet_buffer: PacketBuffer, local_receiver: bool) -> Self {
+        let mut client = Self::from_osc_receivers(Vec::new(), local_receiver);
+        client.packet_buffer = packet_buffer;
+        client
+    }
+
+    fn from_osc_receiver(osc_receiver: Arc<RoscReceiver>, local_receiver: bool) -> Self {
+        Self::from_osc_receivers(vec![osc_receiver], local_receiver)
+    }
+
+    fn from_osc_receivers(osc_receivers: Vec<Arc<RoscReceiver>>, local_receiver: bool) -> Self {
+        Self {
+            osc_receivers,
             source_list: RefCell::new(IndexMap::new()),
-            local_receiver: true,
+            local_receiver,
             listen: Arc::new(RwLock::new(false)),
-            packet_buffer: Default::default()
-        })
+            receive_threads: RefCell::new(Vec::new()),
+            packet_buffer: Default::default(),
+            smoothing: 1.,
+            position_deadband: 0.,
+            transform: None,
+            pixel_rect: None,
+            last_bundle_time: Cell::new(None),
+            last_tuio2_frame: RefCell::new(None),
+            strictness: Strictness::default(),
+            tuio_version: TuioVersion::default(),
+            tuio2_frames: false,
+            bounds_policy: BoundsPolicy::default(),
+            namespace: String::new(),
+            entity_timeout: None,
+            event_queue: None,
+            event_queue_limit: None,
+            expected_contacts: 0,
+            listeners: Vec::new(),
+            session_id_remapper: None,
+            update_coalescer: None,
+            object_persistence: None,
+            clock: Box::new(SystemClock),
+            disabled_profiles: HashSet::new(),
+            stats: Arc::default()
+        }
     }
 
-    pub fn connect(&self) -> Result<(), OscReceiverError> {
+    /// Connects the receivers and starts receiving on a thread per receiver
+    ///
+    /// If a receiver fails to connect, such as a TCP source which is not listening yet, the others are disconnected and the [Client] can connect again
+    pub fn connect(&self) -> Result<(), TuioError> {
         if *self.listen.read().unwrap() {
-            return Err(OscReceiverError::AlreadyConnected());
+            return Err(TuioError::AlreadyConnected);
+        }
+
+        for (index, receiver) in self.osc_receivers.iter().enumerate() {
+            if let Err(err) = receiver.connect() {
+                for receiver in &self.osc_receivers[..index] {
+                    receiver.disconnect();
+                }
+
+                return Err(err.into());
+            }
         }
 
         *self.listen.write().unwrap() = true;
 
         for receiver in &self.osc_receivers {
-            receiver.connect().map_err(OscReceiverError::Connect)?;
-
             let listen = Arc::clone(&self.listen);
             let receiver = Arc::clone(receiver);
             let buffer = Arc::clone(&self.packet_buffer);
+            let stats = Arc::clone(&self.stats);
 
-            thread::spawn(move || loop {
-                if !*listen.read().unwrap() {
-                    break;
-                }
-                
+            let handle = thread::spawn(move || while *listen.read().unwrap() {
                 match receiver.recv() {
+                    _ if !*listen.read().unwrap() => break,
                     Ok(packet) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::trace!(bytes = osc_encode_decode::encoded_size(&packet), "packet received");
+
                         buffer.lock().unwrap().push(packet);
                     }
-                    Err(err) => {
-                        match err {
-                            OscReceiverError::Receive(err) => if err.raw_os_error().unwrap() != 10004 {
-                                println!("Error receiving from socket: {}", err);
-                            },
-                            _ => println!("Error receiving from socket: {}", err)
+                    Err(OscReceiverError::Receive(err)) if err.kind() == ErrorKind::Interrupted => (),
+                    // A malformed packet is skipped, the socket itself is still usable
+                    Err(OscReceiverError::Decode(_err)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(err = %_err, "skipping undecodable OSC packet");
+
+                        stats.messages_dropped.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(_err) => {
+                        #[cfg(feature = "tracing")]
+                        if !matches!(&_err, OscReceiverError::Receive(err) if err.raw_os_error() == Some(10004) || err.kind() == ErrorKind::UnexpectedEof) {
+                            tracing::warn!(err = %_err, "error receiving from socket");
                         }
-                        
+
                         break;
                     }
                 }
             });
+
+            self.receive_threads.borrow_mut().push(handle);
         }
 
         Ok(())
     }
 
+    /// Stops receiving and waits for the receive threads to exit
     pub fn disconnect(&self) {
+        *self.listen.write().unwrap() = false;
+
         for receiver in &self.osc_receivers {
             receiver.disconnect();
         }
-    }
 
-    /// Refreshes the client's state
-    /// 
-    /// On success, returns an [Option] containing the evnts of all new, updated and removed TUIO inputs
-    pub fn refresh(&self) -> Result<Option<TuioEvents>, TuioError> {
-        let mut updated = false;
-        let mut events = TuioEvents::default();
+        for handle in self.receive_threads.borrow_mut().drain(..) {
+            let _ = handle.join();
+        }
+    }
 
-        for packet in self.packet_buffer.lock().unwrap().drain() {
-            if self.process_osc_packet(packet, &mut events)? {
-                updated = true;
-            }
-        };
+    /// Records every packet received from now on into a new file, which can later be replayed through [Client::from_player]
+    ///
+    /// Must be called before [Client::connect]
+    /// # Arguments
+    /// * `path` - the path of the file to create
+    pub fn record(&mut self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        let mut receivers = self.osc_receivers.drain(..);
 
-        if updated {
-            Ok(Some(events))
-        }
-        else {
-            Ok(None)
+        if let Some(first) = receivers.next() {
+            let recorder = Recorder::create(first, path)?;
+            let mut recorders: Vec<Arc<RoscReceiver>> = receivers.map(|receiver| Arc::new(recorder.share(receiver)) as Arc<RoscReceiver>).collect();
+            recorders.insert(0, Arc::new(recorder));
+            self.osc_receivers = recorders;
         }
+
+        Ok(())
     }
 
-    /// Update frame parameters based on a frame number
-    /// 
-    /// Returns true if the frame is a new frame
-    /// # Argument
-    /// * `frame` - the new frame number
-    fn update_frame(&self, frame: i32) -> bool {
-        if frame >= 0 {
-            let current_frame = self.current_frame.get();
-            
-            if frame > current_frame {
-                self.current_time.set(self.instant.elapsed());
-            }
-            
-            if frame >= current_frame || current_frame - frame > 100 {
-                self.current_frame.set(frame);
-                return true;
-            }
-            else if self.instant.elapsed() - self.current_time.get() > Duration::from_millis(100){
-                self.current_time.set(self.instant.elapsed());
-                return false;
+    /// Sets the exponential smoothing factor applied to the velocities and accelerations of updated inputs
+    ///
+    /// Each motion value is filtered with `alpha * new + (1 - alpha) * previous`. The default of 1 disables the filter
+    /// # Arguments
+    /// * `alpha` - a smoothing factor, clamped between 0 and 1
+    pub fn set_smoothing(&mut self, alpha: f32) {
+        self.smoothing = alpha.clamp(0., 1.);
+    }
+
+    /// Sets the dead-band filtering the positional noise of stationary [Cursor]s, [Object]s and [Blob]s
+    ///
+    /// An update moving an input less than `eps` away from its stored position keeps the stored position, velocity and acceleration,
+    /// while its other attributes are still updated. The default of 0 disables the filter
+    /// # Arguments
+    /// * `eps` - the minimum normalized distance of a position change, clamped to be positive
+    pub fn set_position_deadband(&mut self, eps: f32) {
+        self.position_deadband = eps.max(0.);
+    }
+
+    /// Sets a [Transform] applied to the coordinates of every received input before they are stored and reported
+    ///
+    /// # Arguments
+    /// * `transform` - a [Transform], such as [Transform::flip_y] for renderers with a bottom-left origin
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = Some(transform);
+    }
+
+    /// Sets the [PixelRect] of the canvas covered by the normalized space of the sources, used by [Client::to_pixel]
+    ///
+    /// # Arguments
+    /// * `rect` - a [PixelRect], such as the region of a display wall in front of the tracker
+    pub fn set_pixel_rect(&mut self, rect: PixelRect) {
+        self.pixel_rect = Some(rect);
+    }
+
+    /// Returns the canvas coordinates in pixels of a normalized [Position] mapped into the [PixelRect], or the normalized coordinates if no [PixelRect] is set
+    ///
+    /// # Arguments
+    /// * `position` - a normalized [Position], such as the position of a received input after the [Transform]
+    pub fn to_pixel(&self, position: &Position) -> (f32, f32) {
+        match &self.pixel_rect {
+            Some(rect) => rect.map(position),
+            None => (position.x, position.y),
+        }
+    }
+
+    /// Sets how strictly the TUIO 1.1 message ordering of received bundles is enforced. Defaults to [Strictness::Lenient]
+    ///
+    /// # Arguments
+    /// * `strictness` - a [Strictness]
+    pub fn set_strictness(&mut self, strictness: Strictness) {
+        self.strictness = strictness;
+    }
+
+    /// Sets the version of the TUIO 1.x protocol of the received bundles. Defaults to [TuioVersion::V11]
+    ///
+    /// With [TuioVersion::V10], the bundles of legacy trackers without `source` message are decoded, their inputs belonging to a source with an empty name
+    /// # Arguments
+    /// * `version` - the [TuioVersion] of the sources
+    pub fn set_tuio_version(&mut self, version: TuioVersion) {
+        self.tuio_version = version;
+    }
+
+    /// Enables or disables the TUIO 2.0 frame model, where a bundle with a `/tuio2/frm` message is a single frame of every TUIO 2.0 profile. Disabled by default
+    ///
+    /// While enabled, the components of several profiles may share a frame and its `/tuio2/alv` message lists the session IDs of all of them,
+    /// so the [Token]s, [Pointer]s, [Symbol]s, [Bounds], [Geometry]s and [Control]s of the source missing from it are removed.
+    /// While disabled, each bundle holds the components of a single profile with its own alive message, like the bundles of [crate::osc_encode_decode::OscEncoder].
+    /// A frame without any component message, such as the bundle of the last [Token] leaving, is still reconciled with every TUIO 2.0 profile
+    /// # Arguments
+    /// * `enabled` - true to decode unified TUIO 2.0 frames
+    pub fn set_tuio2_frames(&mut self, enabled: bool) {
+        self.tuio2_frames = enabled;
+    }
+
+    /// Sets how the coordinates of received inputs outside of the 0 to 1 range are handled, after the [Transform] if any. Defaults to [BoundsPolicy::Passthrough]
+    ///
+    /// # Arguments
+    /// * `bounds_policy` - a [BoundsPolicy]
+    pub fn set_bounds_policy(&mut self, bounds_policy: BoundsPolicy) {
+        self.bounds_policy = bounds_policy;
+    }
+
+    /// Sets the OSC address namespace of the received TUIO messages, such as `/mytable` for `/mytable/tuio/2Dcur`.
+    /// Defaults to the standard empty namespace
+    ///
+    /// Messages outside of the namespace are ignored, so several TUIO streams can share a single port
+    /// # Arguments
+    /// * `namespace` - the address prefix
+    pub fn set_namespace(&mut self, namespace: &str) {
+        self.namespace = osc_encode_decode::normalize_namespace(namespace);
+    }
+
+    /// Sets the duration after which the inputs of a silent source are removed, even though no alive message dropped them.
+    /// Disabled by default, which keeps the inputs until the source removes them
+    ///
+    /// A TUIO source sends at least its alive messages every frame, so a source silent for longer than the timeout is considered dead.
+    /// Its inputs are removed by the next [Client::refresh], which then reports their removal events
+    /// # Arguments
+    /// * `timeout` - the maximum [Duration] between two bundles of a source
+    pub fn set_entity_timeout(&mut self, timeout: Duration) {
+        self.entity_timeout = Some(timeout);
+    }
+
+    /// Sets a callback receiving the undecoded bytes of every packet and the socket address of its sender, before they are decoded and dispatched
+    ///
+    /// The callback runs on the receiving threads, so it also captures the malformed packets which are later skipped.
+    /// Packets replayed by a [Player] are not tapped
+    /// # Arguments
+    /// * `raw_tap` - the callback
+    pub fn set_raw_tap(&mut self, raw_tap: impl Fn(SocketAddr, &[u8]) + Send + Sync + 'static) {
+        let raw_tap: RawTap = Arc::new(raw_tap);
+
+        for receiver in &self.osc_receivers {
+            receiver.set_raw_tap(Arc::clone(&raw_tap));
+        }
+    }
+
+    /// Registers a [Listener] notified of the events of every following [Client::refresh]
+    ///
+    /// Listeners are notified in their registration order, each of them before the next event.
+    /// A panicking listener does not prevent the others from being notified, see [TuioEvents::dispatch_to]
+    /// # Arguments
+    /// * `listener` - the [Listener] to add
+    pub fn add_listener(&mut self, listener: SharedListener) {
+        self.listeners.push(listener);
+    }
+
+    /// Enables or disables the event queue
+    ///
+    /// While enabled, [Client::refresh] pushes its events to an internal queue drained by [Client::drain_events] instead of returning them.
+    /// Disabling the queue discards the events not drained yet
+    /// # Arguments
+    /// * `enabled` - true to queue the events
+    pub fn set_event_queue(&mut self, enabled: bool) {
+        self.event_queue = enabled.then(RefCell::default);
+    }
+
+    /// Bounds the length of the event queue, so a stalled consumer cannot grow it without limit. The queue is unbounded by default
+    ///
+    /// # Arguments
+    /// * `max_len` - the maximum number of queued events
+    /// * `policy` - the [QueuePolicy] applied once the queue is full
+    pub fn set_event_queue_limit(&mut self, max_len: usize, policy: QueuePolicy) {
+        self.event_queue_limit = Some((max_len, policy));
+    }
+
+    /// Sets the number of contacts the [Cursor], [Object] and [Blob] tables of a new source are sized for,
+    /// so they do not reallocate while the first busy frames are processed. Defaults to 0, growing the tables on demand
+    ///
+    /// # Arguments
+    /// * `n` - the number of contacts routinely tracked per profile
+    pub fn set_expected_contacts(&mut self, n: usize) {
+        self.expected_contacts = n;
+    }
+
+    /// Returns the number of events which still fit in a full [QueuePolicy::Block] event queue, or [None] if the processing is never blocked
+    fn blocking_queue_room(&self) -> Option<usize> {
+        match (&self.event_queue, self.event_queue_limit) {
+            (Some(queue), Some((max_len, QueuePolicy::Block))) => Some(max_len.saturating_sub(queue.borrow().len())),
+            _ => None,
+        }
+    }
+
+    /// Enables or disables the remapping of session IDs, so inputs of several sources sharing a session ID stay distinct
+    ///
+    /// While enabled, the events returned, queued and dispatched by [Client::refresh] carry the global session IDs of a [SessionIdRemapper].
+    /// The inputs returned by accessors such as [Client::cursors] keep the session IDs of their source
+    /// # Arguments
+    /// * `enabled` - true to remap the session IDs
+    pub fn set_session_id_remapping(&mut self, enabled: bool) {
+        self.session_id_remapper = enabled.then(RefCell::default);
+    }
+
+    /// Limits the update events of each input to one per interval, so fast trackers do not flood the listeners with updates
+    ///
+    /// An update arriving within the interval after the previous event of its input is held back, replacing any update held back before,
+    /// and is delivered by the first [Client::refresh] after the interval elapsed, even if no packet was received meanwhile.
+    /// New and remove events are delivered immediately, and a remove event discards the update held back for its input.
+    /// Both [Client::refresh] and [Client::recv_once] coalesce the updates.
+    /// A zero interval disables the coalescing
+    /// # Arguments
+    /// * `interval` - the minimum [Duration] between two update events of an input
+    pub fn set_update_coalescing(&mut self, interval: Duration) {
+        self.update_coalescer = (!interval.is_zero()).then(|| RefCell::new(UpdateCoalescer::new(interval)));
+    }
+
+    /// Keeps the session ID of [Object]s briefly lost by the tracker, such as fiducials occluded by a hand
+    ///
+    /// The remove event of an object is held back for the maximum gap. If an object of the same class and source appears within the maximum distance meanwhile,
+    /// its new event is replaced by an update carrying the session ID of the removed object, as are its following events.
+    /// Otherwise the remove event is delivered by the first [Client::refresh] after the gap elapsed. A zero gap disables the reconciliation.
+    /// A frame whose only events are held back still makes [Client::refresh] return [TuioEvents], empty, since the inputs of the [Client] changed.
+    /// The inputs returned by accessors such as [Client::objects] keep the session IDs of their source
+    /// # Arguments
+    /// * `max_distance` - the largest normalized distance between a removed object and its reappearance
+    /// * `max_gap` - the longest [Duration] between a removal and a reappearance
+    pub fn set_object_persistence(&mut self, max_distance: f32, max_gap: Duration) {
+        self.object_persistence = (!max_gap.is_zero()).then(|| RefCell::new(ObjectPersistence::new(max_distance, max_gap)));
+    }
+
+    /// Sets the [Clock] timing the entity timeout, the object persistence, the update coalescing and the age of the received inputs. Defaults to [SystemClock]
+    ///
+    /// # Arguments
+    /// * `clock` - a [Clock], such as a [crate::clock::TestClock] to expire sources deterministically in tests
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        self.clock = Box::new(clock);
+    }
+
+    /// Processes the bundles of a profile again after [Client::disable_profile]. Every profile is enabled by default
+    ///
+    /// # Arguments
+    /// * `profile` - the [TuioBundleType] of the profile
+    pub fn enable_profile(&mut self, profile: TuioBundleType) {
+        self.disabled_profiles.remove(&profile);
+    }
+
+    /// Ignores the bundles of a profile before decoding them, so no input nor event of the profile is reported
+    ///
+    /// The inputs of the profile already received are kept until the profile is enabled again and their source removes them
+    /// # Arguments
+    /// * `profile` - the [TuioBundleType] of the profile
+    pub fn disable_profile(&mut self, profile: TuioBundleType) {
+        self.disabled_profiles.insert(profile);
+    }
+
+    /// Returns true if the bundles of a profile are processed
+    ///
+    /// # Arguments
+    /// * `profile` - the [TuioBundleType] of the profile
+    pub fn is_profile_enabled(&self, profile: TuioBundleType) -> bool {
+        !self.disabled_profiles.contains(&profile)
+    }
+
+    /// Returns the queued events, oldest first, and empties the queue
+    ///
+    /// Returns nothing unless the queue was enabled with [Client::set_event_queue]
+    pub fn drain_events(&self) -> impl Iterator<Item = TuioEvent> {
+        self.event_queue.as_ref().map(|queue| queue.take()).unwrap_or_default().into_iter()
+    }
+
+    /// Receives and processes a single packet without any thread, waiting at most for a timeout, and returns the events it produced
+    ///
+    /// Packets already buffered, such as the ones sent through a [LoopbackTransport], are processed first,
+    /// and a stream receiver is connected if needed.
+    /// Like [Client::refresh], the silent sources are expired and the events go through the object persistence, the update coalescing and the session ID remapping
+    /// before being dispatched to the registered [Listener]s, but they are never queued for [Client::drain_events].
+    /// Returns a [TuioError::AlreadyConnected] error while the [Client] is connected, since its threads would compete for the packets,
+    /// and an [ErrorKind::TimedOut] socket error if no packet was received in time
+    /// # Arguments
+    /// * `timeout` - the longest time to wait for a packet
+    pub fn recv_once(&self, timeout: Duration) -> Result<Vec<TuioEvent>, TuioError> {
+        if *self.listen.read().unwrap() {
+            return Err(TuioError::AlreadyConnected);
+        }
+
+        let deadline = Instant::now() + timeout;
+
+        let packet = loop {
+            if let Some(packet) = self.packet_buffer.lock().unwrap().dequeue() {
+                break packet;
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if let Some(receiver) = self.osc_receivers.first() {
+                if !receiver.is_connected() {
+                    receiver.connect()?;
+                }
+
+                break receiver.recv_timeout(remaining)?;
             }
+
+            if remaining.is_zero() {
+                return Err(TuioError::Socket(std::io::Error::from(ErrorKind::TimedOut)));
+            }
+
+            thread::sleep(remaining.min(Duration::from_millis(1)));
+        };
+
+        self.stats.bytes_received.fetch_add(osc_encode_decode::encoded_size(&packet) as u64, Ordering::Relaxed);
+
+        let mut events = TuioEvents::default();
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::trace_span!("decode").entered();
+
+        let updated = self.process_osc_packet(packet, &mut events)?;
+
+        #[cfg(feature = "tracing")]
+        span.exit();
+
+        self.deliver_events(&mut events, updated);
+
+        Ok(events.into_iter().collect())
+    }
+
+    /// Refreshes the client's state
+    /// 
+    /// On success, returns an [Option] containing the evnts of all new, updated and removed TUIO inputs, after notifying the registered [Listener]s.
+    /// When the event queue is enabled, the events are queued for [Client::drain_events] and [None] is returned
+    ///
+    /// Malformed bundles and `set` messages are skipped and counted in [Client::stats] so a single faulty packet does not discard the others
+    pub fn refresh(&self) -> Result<Option<TuioEvents>, TuioError> {
+        let mut updated = false;
+        let mut events = TuioEvents::default();
+        let queue_room = self.blocking_queue_room();
+
+        loop {
+            if queue_room.is_some_and(|room| events.len() >= room) {
+                break;
+            }
+
+            let Some(packet) = self.packet_buffer.lock().unwrap().dequeue() else {
+                break;
+            };
+
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("decode").entered();
+
+            self.stats.bytes_received.fetch_add(osc_encode_decode::encoded_size(&packet) as u64, Ordering::Relaxed);
+
+            match self.process_osc_packet(packet, &mut events) {
+                Ok(true) => updated = true,
+                Ok(false) => (),
+                Err(err) => self.skip_packet(err),
+            }
+        };
+
+        let updated = self.deliver_events(&mut events, updated);
+
+        if let (true, Some(queue)) = (updated, &self.event_queue) {
+            let mut queue = queue.borrow_mut();
+
+            match self.event_queue_limit {
+                Some((max_len, QueuePolicy::DropOldest)) => {
+                    queue.extend(events);
+                    let excess = queue.len().saturating_sub(max_len);
+                    queue.drain(..excess);
+                },
+                Some((max_len, QueuePolicy::DropNewest)) => {
+                    let room = max_len.saturating_sub(queue.len());
+                    queue.extend(events.into_iter().take(room));
+                },
+                Some((_, QueuePolicy::Block)) | None => queue.extend(events),
+            }
+
+            Ok(None)
+        }
+        else if updated {
+            Ok(Some(events))
+        }
+        else {
+            Ok(None)
+        }
+    }
+
+    /// Expires the silent sources, applies the object persistence, the update coalescing and the session ID remapping
+    /// to the events of the processed packets, then dispatches them to the registered [Listener]s
+    ///
+    /// Returns true if any input was added, updated or removed
+    /// # Arguments
+    /// * `events` - the [TuioEvents] of the processed packets
+    /// * `updated` - true if the processed packets updated any input
+    fn deliver_events(&self, events: &mut TuioEvents, mut updated: bool) -> bool {
+        if self.expire_sources(events) {
+            updated = true;
+        }
+
+        if let Some(persistence) = &self.object_persistence {
+            updated |= persistence.borrow_mut().apply(events, self.clock.now());
+        }
+
+        if let Some(coalescer) = &self.update_coalescer {
+            updated |= coalescer.borrow_mut().apply(events, self.clock.now());
+        }
+
+        if let (true, Some(remapper)) = (updated, &self.session_id_remapper) {
+            remapper.borrow_mut().apply(events);
+        }
+
+        if updated {
+            events.dispatch_to(&self.listeners);
         }
-        false
+
+        updated
+    }
+
+    /// Removes the sources silent for longer than the entity timeout and pushes the removal events of their inputs
+    ///
+    /// Returns true if any input was removed
+    fn expire_sources(&self, events: &mut TuioEvents) -> bool {
+        let Some(timeout) = self.entity_timeout else {
+            return false;
+        };
+
+        let time = SystemTime::now();
+        let now = self.clock.now();
+        let mut updated = false;
+
+        self.source_list.borrow_mut().retain(|source_name, source_collection| {
+            if source_collection.last_update.is_none_or(|last_update| now.saturating_duration_since(last_update) <= timeout) {
+                return true;
+            }
+
+            let source_name = source_name.clone();
+
+            for (_, cursor) in source_collection.cursor_map.drain(..) {
+                events.cursor_events.push(CursorEvent::Remove(CursorData { source_name: source_name.clone(), time, fseq: -1, cursor }));
+                updated = true;
+            }
+
+            for (_, object) in source_collection.object_map.drain(..) {
+                events.object_events.push(ObjectEvent::Remove(ObjectData { source_name: source_name.clone(), time, fseq: -1, object }));
+                updated = true;
+            }
+
+            for (_, blob) in source_collection.blob_map.drain(..) {
+                events.blob_events.push(BlobEvent::Remove(BlobData { source_name: source_name.clone(), time, fseq: -1, blob }));
+                updated = true;
+            }
+
+            for (_, cursor) in source_collection.cursor_3d_map.drain(..) {
+                events.cursor_3d_events.push(Cursor3DEvent::Remove(Cursor3DData { source_name: source_name.clone(), time, fseq: -1, cursor }));
+                updated = true;
+            }
+
+            for (_, cursor) in source_collection.cursor_25d_map.drain(..) {
+                events.cursor_25d_events.push(Cursor25DEvent::Remove(Cursor25DData { source_name: source_name.clone(), time, fseq: -1, cursor }));
+                updated = true;
+            }
+
+            for (_, blob) in source_collection.blob_3d_map.drain(..) {
+                events.blob_3d_events.push(Blob3DEvent::Remove(Blob3DData { source_name: source_name.clone(), time, fseq: -1, blob }));
+                updated = true;
+            }
+
+            for (_, token) in source_collection.token_map.drain(..) {
+                events.token_events.push(TokenEvent::Remove(TokenData { source_name: source_name.clone(), time, fseq: -1, token }));
+                updated = true;
+            }
+
+            for (_, pointer) in source_collection.pointer_map.drain(..) {
+                events.pointer_events.push(PointerEvent::Remove(PointerData { source_name: source_name.clone(), time, fseq: -1, pointer }));
+                updated = true;
+            }
+
+            for (_, symbol) in source_collection.symbol_map.drain(..) {
+                events.symbol_events.push(SymbolEvent::Remove(SymbolData { source_name: source_name.clone(), time, fseq: -1, symbol }));
+                updated = true;
+            }
+
+            for (_, bounds) in source_collection.bounds_map.drain(..) {
+                events.bounds_events.push(BoundsEvent::Remove(BoundsData { source_name: source_name.clone(), time, fseq: -1, bounds }));
+                updated = true;
+            }
+
+            for (_, geometry) in source_collection.geometry_map.drain(..) {
+                events.geometry_events.push(GeometryEvent::Remove(GeometryData { source_name: source_name.clone(), time, fseq: -1, geometry }));
+                updated = true;
+            }
+
+            for (_, control) in source_collection.control_map.drain(..) {
+                events.control_events.push(ControlEvent::Remove(ControlData { source_name: source_name.clone(), time, fseq: -1, control }));
+                updated = true;
+            }
+
+            false
+        });
+
+        updated
     }
 
     fn process_osc_packet(&self, packet: OscPacket, events: &mut TuioEvents) -> Result<bool, TuioError> {
-        if let OscPacket::Bundle(bundle) = packet {
-            let decoded_bundle = OscDecoder::decode_bundle(bundle)?;
-            
-            let to_keep: HashSet<i32> = HashSet::from_iter(decoded_bundle.alive);
-            
-            if self.update_frame(decoded_bundle.fseq) {
-                let mut source_list = self.source_list.borrow_mut();
-                let source_collection = source_list.entry(decoded_bundle.source.clone()).or_default(); 
-                match decoded_bundle.tuio_type {
-                    osc_encode_decode::TuioBundleType::Cursor => {
-                        let cursor_map = &mut source_collection.cursor_map;
-
-                        for cursor in retain_by_ids(cursor_map, to_keep).into_iter() {
-                            events.cursor_events.push(CursorEvent::Remove(CursorData{source_name: decoded_bundle.source.clone(), cursor: cursor.clone()}));
-                        }
+        if let OscPacket::Bundle(mut bundle) = packet {
+            // A frame of several profiles is sent as one bundle per profile nested in an outer bundle
+            if !bundle.content.is_empty() && bundle.content.iter().all(|packet| matches!(packet, OscPacket::Bundle(_))) {
+                let mut updated = false;
 
-                        if let Some(Set::Cursor(cursor_collection)) = decoded_bundle.set {
-                            for cursor in cursor_collection {
-                                match cursor_map.entry(cursor.get_session_id()) {
-                                    indexmap::map::Entry::Occupied(mut entry) => {
-                                        events.cursor_events.push(CursorEvent::Update(CursorData{source_name: decoded_bundle.source.clone(), cursor: cursor.clone()}));
-                                        entry.insert(cursor);
-                                    },
-                                    indexmap::map::Entry::Vacant(entry) => {
-                                        events.cursor_events.push(CursorEvent::New(CursorData{source_name: decoded_bundle.source.clone(), cursor: cursor.clone()}));
-                                        entry.insert(cursor);
-                                    },
-                                }
-                            }
-                        }
-                    },
-                    osc_encode_decode::TuioBundleType::Object => {
-                        let object_map = &mut source_collection.object_map;
+                for packet in bundle.content {
+                    match self.process_osc_packet(packet, events) {
+                        Ok(true) => updated = true,
+                        Ok(false) => (),
+                        Err(err) => self.skip_packet(err),
+                    }
+                }
 
-                        for object in retain_by_ids(object_map, to_keep).into_iter() {
-                            events.object_events.push(ObjectEvent::Remove(ObjectData { source_name: decoded_bundle.source.clone(), object: object.clone() }));
-                        }
+                return Ok(updated);
+            }
 
-                        if let Some(Set::Object(object_collection)) = decoded_bundle.set {
-                            for object in object_collection {
-                                match object_map.entry(object.get_session_id()) {
-                                    indexmap::map::Entry::Occupied(mut entry) => {
-                                        events.object_events.push(ObjectEvent::Update(ObjectData { source_name: decoded_bundle.source.clone(), object: object.clone() }));
-                                        entry.insert(object);
-                                    },
-                                    indexmap::map::Entry::Vacant(entry) => {
-                                        events.object_events.push(ObjectEvent::New(ObjectData { source_name: decoded_bundle.source.clone(), object: object.clone() }));
-                                        entry.insert(object);
-                                    },
-                                }
-                            }
-                        }
-                    },
-                    osc_encode_decode::TuioBundleType::Blob => {
-                        let blob_map = &mut source_collection.blob_map;
+            self.stats.bundles_received.fetch_add(1, Ordering::Relaxed);
 
-                        for blob in retain_by_ids(blob_map, to_keep).into_iter() {
-                            events.blob_events.push(BlobEvent::Remove(BlobData { source_name: decoded_bundle.source.clone(), blob: blob.clone() }));
-                        }
+            if !osc_encode_decode::strip_namespace(&mut bundle, &self.namespace) {
+                return Ok(false);
+            }
 
-                        if let Some(Set::Blob(blob_collection)) = decoded_bundle.set {
-                            for blob in blob_collection {
-                                match blob_map.entry(blob.get_session_id()) {
-                                    indexmap::map::Entry::Occupied(mut entry) => {
-                                        events.blob_events.push(BlobEvent::Update(BlobData { source_name: decoded_bundle.source.clone(), blob: blob.clone() }));
-                                        entry.insert(blob);
-                                    },
-                                    indexmap::map::Entry::Vacant(entry) => {
-                                        events.blob_events.push(BlobEvent::New(BlobData { source_name: decoded_bundle.source.clone(), blob: blob.clone() }));
-                                        entry.insert(blob);
-                                    },
-                                }
-                            }
-                        }
-                    },
-                    osc_encode_decode::TuioBundleType::Unknown => (),
+            // The messages of a single frame may still be spread over nested bundles by OSC routing software
+            osc_encode_decode::flatten_bundle(&mut bundle);
+
+            let message_count = bundle.content.len() as u64;
+
+            let tuio2_frame = bundle.content.iter().any(|packet| matches!(packet, OscPacket::Message(message) if message.addr == "/tuio2/frm"));
+
+            // A TUIO 2.0 frame without any component message has no profile of its own, but still ends the components missing from its alive message
+            if tuio2_frame && (self.tuio2_frames || osc_encode_decode::bundle_profile(&bundle).is_none()) {
+                let decoded_bundles = OscDecoder::decode_tuio2_frame(bundle, self.strictness).inspect_err(|_| {
+                    self.stats.messages_dropped.fetch_add(message_count, Ordering::Relaxed);
+                })?;
+
+                self.count_messages(message_count, decoded_bundles.iter().map(|decoded_bundle| decoded_bundle.skipped.len() as u64).sum());
+
+                let mut updated = false;
+
+                for decoded_bundle in decoded_bundles.into_iter().filter(|decoded_bundle| self.is_profile_enabled(decoded_bundle.tuio_type)) {
+                    updated |= self.process_decoded_bundle(decoded_bundle, events);
                 }
-                Ok(true)
+
+                return Ok(updated);
             }
-            else {
-                Ok(false)
+
+            if osc_encode_decode::bundle_profile(&bundle).is_some_and(|profile| !self.is_profile_enabled(profile)) {
+                return Ok(false);
             }
+
+            let decoded_bundle = OscDecoder::decode_bundle_with_version(bundle, self.strictness, self.tuio_version).inspect_err(|_| {
+                self.stats.messages_dropped.fetch_add(message_count, Ordering::Relaxed);
+            })?;
+
+            self.count_messages(message_count, decoded_bundle.skipped.len() as u64);
+
+            Ok(self.process_decoded_bundle(decoded_bundle, events))
         }
         else {
             Err(TuioError::NotABundle(packet))
         }
     }
 
-    pub fn local_receiver(&self) -> bool {
-        self.local_receiver
+    /// Skips a packet which could not be processed, counting an undecodable packet or a message sent outside of a bundle in the [ClientStats]
+    ///
+    /// The messages of a malformed bundle are already counted while decoding it
+    /// # Arguments
+    /// * `err` - the [TuioError] returned while decoding or processing the packet
+    pub(crate) fn skip_packet(&self, err: TuioError) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(%err, "skipping malformed TUIO packet");
+
+        if let TuioError::Decode(_) | TuioError::NotABundle(_) = err {
+            self.stats.messages_dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Adds the messages of a decoded bundle to the [ClientStats]
+    /// # Arguments
+    /// * `message_count` - the number of messages of the bundle
+    /// * `skipped_count` - the number of malformed messages skipped while decoding
+    fn count_messages(&self, message_count: u64, skipped_count: u64) {
+        self.stats.messages_decoded.fetch_add(message_count.saturating_sub(skipped_count), Ordering::Relaxed);
+        self.stats.messages_dropped.fetch_add(skipped_count, Ordering::Relaxed);
     }
-}
\ No newline at end of file
+
+    /// Reconciles the inputs of a source with a decoded bundle, adding the events of the new, updated and removed inputs
+    ///
+    /// Returns true if the bundle was processed, false if it is a duplicate or a late frame
+    fn process_decoded_bundle(&self, mut decoded_bundle: TuioBundle, events: &mut TuioEvents) -> bool {
+        let now = self.clock.now();
+
+        if let (Some(transform), Some(set)) = (&self.transform, &mut decoded_bundle.set) {
+            transform.apply_set(set);
+        }
+
+        if let Some(set) = &mut decoded_bundle.set {
+            self.bounds_policy.apply_set(set);
+        }
+
+        #[cfg(feature = "tracing")]
+        for err in &decoded_bundle.skipped {
+            tracing::warn!(%err, "skipping malformed TUIO message");
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(source = %decoded_bundle.source, profile = ?decoded_bundle.tuio_type, fseq = decoded_bundle.fseq, skipped = decoded_bundle.skipped.len(), "frame assembled");
+        
+        let mut to_keep: HashSet<i32> = HashSet::from_iter(decoded_bundle.alive);
+        
+        let mut source_list = self.source_list.borrow_mut();
+        let source_collection = source_list.entry(decoded_bundle.source.clone()).or_insert_with(|| SourceCollection::with_capacity(self.expected_contacts));
+        source_collection.last_update = Some(self.clock.now());
+
+        // A TUIO 2.0 profile without any component in a frame is only reconciled with the alive session IDs
+        if decoded_bundle.tuio_type != TuioBundleType::Unknown && (decoded_bundle.set.is_some() || !decoded_bundle.tuio_type.is_tuio2()) {
+            source_collection.observed_profiles.insert(decoded_bundle.tuio_type);
+        }
+
+        // A frame too large for a single packet may be split into several bundles sharing its frame sequence number, each with the whole alive message.
+        // The first part reconciles the alive inputs, the following parts only add or update the inputs of their set messages
+        let continuation = source_collection.is_current_frame(decoded_bundle.tuio_type, decoded_bundle.fseq);
+
+        if source_collection.is_frame_gap(decoded_bundle.tuio_type, decoded_bundle.fseq) {
+            self.stats.fseq_gaps.fetch_add(1, Ordering::Relaxed);
+        }
+
+        if continuation || source_collection.update_frame(decoded_bundle.tuio_type, decoded_bundle.fseq) {
+            if continuation {
+                to_keep = source_collection.session_ids(decoded_bundle.tuio_type);
+            }
+
+            let frame_session_ids = source_collection.frame_session_ids.entry(decoded_bundle.tuio_type).or_default();
+
+            if !continuation {
+                frame_session_ids.clear();
+            }
+
+            if let Some(set) = &mut decoded_bundle.set {
+                // Inputs already set in this frame come from a duplicated packet
+                set.retain_session_ids(|session_id| !frame_session_ids.contains(&session_id));
+                frame_session_ids.extend(set.session_ids());
+            }
+
+            if continuation && decoded_bundle.set.as_ref().is_none_or(|set| set.session_ids().is_empty()) {
+                return false;
+            }
+
+            self.last_bundle_time.set(Some(decoded_bundle.time));
+
+            if let Some(dimension) = decoded_bundle.dimension {
+                *self.last_tuio2_frame.borrow_mut() = Some((decoded_bundle.source.clone(), dimension));
+            }
+
+            match decoded_bundle.tuio_type {
+                osc_encode_decode::TuioBundleType::Cursor => {
+                    let cursor_map = &mut source_collection.cursor_map;
+
+                    for mut cursor in retain_by_ids(cursor_map, to_keep).into_iter() {
+                        cursor.lifetime = cursor.lifetime.seen(now);
+                        events.cursor_events.push(CursorEvent::Remove(CursorData{source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone()}));
+                    }
+
+                    if !continuation {
+                        cursor_map.values_mut().for_each(|cursor| cursor.lifetime = cursor.lifetime.next_frame().seen(now));
+                    }
+
+                    if let Some(Set::Cursor(cursor_collection)) = decoded_bundle.set {
+                        for mut cursor in cursor_collection {
+                            match cursor_map.entry(cursor.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    cursor.hold_position(entry.get(), self.position_deadband);
+                                    cursor.smooth_motion(entry.get(), self.smoothing);
+                                    cursor.lifetime = entry.get().lifetime.updated().seen(now);
+                                    events.cursor_events.push(CursorEvent::Update(CursorData{source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone()}));
+                                    entry.insert(cursor);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    cursor.lifetime = Lifetime::new(now).first_frame();
+                                    events.cursor_events.push(CursorEvent::New(CursorData{source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone()}));
+                                    entry.insert(cursor);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Object => {
+                    let object_map = &mut source_collection.object_map;
+
+                    for mut object in retain_by_ids(object_map, to_keep).into_iter() {
+                        object.lifetime = object.lifetime.seen(now);
+                        events.object_events.push(ObjectEvent::Remove(ObjectData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, object: object.clone() }));
+                    }
+
+                    if !continuation {
+                        object_map.values_mut().for_each(|object| object.lifetime = object.lifetime.next_frame().seen(now));
+                    }
+
+                    if let Some(Set::Object(object_collection)) = decoded_bundle.set {
+                        for mut object in object_collection {
+                            match object_map.entry(object.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    object.hold_position(entry.get(), self.position_deadband);
+                                    object.smooth_motion(entry.get(), self.smoothing);
+                                    object.lifetime = entry.get().lifetime.updated().seen(now);
+                                    events.object_events.push(ObjectEvent::Update(ObjectData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, object: object.clone() }));
+                                    entry.insert(object);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    object.lifetime = Lifetime::new(now).first_frame();
+                                    events.object_events.push(ObjectEvent::New(ObjectData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, object: object.clone() }));
+                                    entry.insert(object);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Blob => {
+                    let blob_map = &mut source_collection.blob_map;
+
+                    for mut blob in retain_by_ids(blob_map, to_keep).into_iter() {
+                        blob.lifetime = blob.lifetime.seen(now);
+                        events.blob_events.push(BlobEvent::Remove(BlobData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, blob: blob.clone() }));
+                    }
+
+                    if !continuation {
+                        blob_map.values_mut().for_each(|blob| blob.lifetime = blob.lifetime.next_frame().seen(now));
+                    }
+
+                    if let Some(Set::Blob(blob_collection)) = decoded_bundle.set {
+                        for mut blob in blob_collection {
+                            match blob_map.entry(blob.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    blob.hold_position(entry.get(), self.position_deadband);
+                                    blob.smooth_motion(entry.get(), self.smoothing);
+                                    blob.lifetime = entry.get().lifetime.updated().seen(now);
+                                    events.blob_events.push(BlobEvent::Update(BlobData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, blob: blob.clone() }));
+                                    entry.insert(blob);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    blob.lifetime = Lifetime::new(now).first_frame();
+                                    events.blob_events.push(BlobEvent::New(BlobData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, blob: blob.clone() }));
+                                    entry.insert(blob);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Cursor3D => {
+                    let cursor_map = &mut source_collection.cursor_3d_map;
+
+                    for cursor in retain_by_ids(cursor_map, to_keep).into_iter() {
+                        events.cursor_3d_events.push(Cursor3DEvent::Remove(Cursor3DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone() }));
+                    }
+
+                    if let Some(Set::Cursor3D(cursor_collection)) = decoded_bundle.set {
+                        for mut cursor in cursor_collection {
+                            match cursor_map.entry(cursor.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    cursor.smooth_motion(entry.get(), self.smoothing);
+                                    events.cursor_3d_events.push(Cursor3DEvent::Update(Cursor3DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone() }));
+                                    entry.insert(cursor);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.cursor_3d_events.push(Cursor3DEvent::New(Cursor3DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone() }));
+                                    entry.insert(cursor);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Cursor25D => {
+                    let cursor_map = &mut source_collection.cursor_25d_map;
+
+                    for cursor in retain_by_ids(cursor_map, to_keep).into_iter() {
+                        events.cursor_25d_events.push(Cursor25DEvent::Remove(Cursor25DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone() }));
+                    }
+
+                    if let Some(Set::Cursor25D(cursor_collection)) = decoded_bundle.set {
+                        for mut cursor in cursor_collection {
+                            match cursor_map.entry(cursor.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    cursor.smooth_motion(entry.get(), self.smoothing);
+                                    events.cursor_25d_events.push(Cursor25DEvent::Update(Cursor25DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone() }));
+                                    entry.insert(cursor);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.cursor_25d_events.push(Cursor25DEvent::New(Cursor25DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, cursor: cursor.clone() }));
+                                    entry.insert(cursor);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Blob3D => {
+                    let blob_map = &mut source_collection.blob_3d_map;
+
+                    for blob in retain_by_ids(blob_map, to_keep).into_iter() {
+                        events.blob_3d_events.push(Blob3DEvent::Remove(Blob3DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, blob: blob.clone() }));
+                    }
+
+                    if let Some(Set::Blob3D(blob_collection)) = decoded_bundle.set {
+                        for mut blob in blob_collection {
+                            match blob_map.entry(blob.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    blob.smooth_motion(entry.get(), self.smoothing);
+                                    events.blob_3d_events.push(Blob3DEvent::Update(Blob3DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, blob: blob.clone() }));
+                                    entry.insert(blob);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.blob_3d_events.push(Blob3DEvent::New(Blob3DData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, blob: blob.clone() }));
+                                    entry.insert(blob);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Token => {
+                    let token_map = &mut source_collection.token_map;
+
+                    for token in retain_by_ids(token_map, to_keep).into_iter() {
+                        events.token_events.push(TokenEvent::Remove(TokenData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, token: token.clone() }));
+                    }
+
+                    if let Some(Set::Token(token_collection)) = decoded_bundle.set {
+                        for mut token in token_collection {
+                            match token_map.entry(token.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    token.smooth_motion(entry.get(), self.smoothing);
+                                    events.token_events.push(TokenEvent::Update(TokenData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, token: token.clone() }));
+                                    entry.insert(token);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.token_events.push(TokenEvent::New(TokenData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, token: token.clone() }));
+                                    entry.insert(token);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Pointer => {
+                    let pointer_map = &mut source_collection.pointer_map;
+
+                    for pointer in retain_by_ids(pointer_map, to_keep).into_iter() {
+                        events.pointer_events.push(PointerEvent::Remove(PointerData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, pointer: pointer.clone() }));
+                    }
+
+                    if let Some(Set::Pointer(pointer_collection)) = decoded_bundle.set {
+                        for mut pointer in pointer_collection {
+                            match pointer_map.entry(pointer.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    pointer.smooth_motion(entry.get(), self.smoothing);
+                                    events.pointer_events.push(PointerEvent::Update(PointerData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, pointer: pointer.clone() }));
+                                    entry.insert(pointer);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.pointer_events.push(PointerEvent::New(PointerData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, pointer: pointer.clone() }));
+                                    entry.insert(pointer);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Symbol => {
+                    let symbol_map = &mut source_collection.symbol_map;
+
+                    for symbol in retain_by_ids(symbol_map, to_keep).into_iter() {
+                        events.symbol_events.push(SymbolEvent::Remove(SymbolData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, symbol: symbol.clone() }));
+                    }
+
+                    if let Some(Set::Symbol(symbol_collection)) = decoded_bundle.set {
+                        for symbol in symbol_collection {
+                            match symbol_map.entry(symbol.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    events.symbol_events.push(SymbolEvent::Update(SymbolData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, symbol: symbol.clone() }));
+                                    entry.insert(symbol);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.symbol_events.push(SymbolEvent::New(SymbolData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, symbol: symbol.clone() }));
+                                    entry.insert(symbol);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Bounds => {
+                    let bounds_map = &mut source_collection.bounds_map;
+
+                    for bounds in retain_by_ids(bounds_map, to_keep).into_iter() {
+                        events.bounds_events.push(BoundsEvent::Remove(BoundsData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, bounds: bounds.clone() }));
+                    }
+
+                    if let Some(Set::Bounds(bounds_collection)) = decoded_bundle.set {
+                        for mut bounds in bounds_collection {
+                            match bounds_map.entry(bounds.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    bounds.smooth_motion(entry.get(), self.smoothing);
+                                    events.bounds_events.push(BoundsEvent::Update(BoundsData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, bounds: bounds.clone() }));
+                                    entry.insert(bounds);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.bounds_events.push(BoundsEvent::New(BoundsData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, bounds: bounds.clone() }));
+                                    entry.insert(bounds);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Geometry => {
+                    let geometry_map = &mut source_collection.geometry_map;
+
+                    for geometry in retain_by_ids(geometry_map, to_keep).into_iter() {
+                        events.geometry_events.push(GeometryEvent::Remove(GeometryData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, geometry: geometry.clone() }));
+                    }
+
+                    if let Some(Set::Geometry(geometry_collection)) = decoded_bundle.set {
+                        for geometry in geometry_collection {
+                            match geometry_map.entry(geometry.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    events.geometry_events.push(GeometryEvent::Update(GeometryData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, geometry: geometry.clone() }));
+                                    entry.insert(geometry);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.geometry_events.push(GeometryEvent::New(GeometryData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, geometry: geometry.clone() }));
+                                    entry.insert(geometry);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Control => {
+                    let control_map = &mut source_collection.control_map;
+
+                    for control in retain_by_ids(control_map, to_keep).into_iter() {
+                        events.control_events.push(ControlEvent::Remove(ControlData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, control: control.clone() }));
+                    }
+
+                    if let Some(Set::Control(control_collection)) = decoded_bundle.set {
+                        for control in control_collection {
+                            match control_map.entry(control.get_session_id()) {
+                                indexmap::map::Entry::Occupied(mut entry) => {
+                                    events.control_events.push(ControlEvent::Update(ControlData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, control: control.clone() }));
+                                    entry.insert(control);
+                                },
+                                indexmap::map::Entry::Vacant(entry) => {
+                                    events.control_events.push(ControlEvent::New(ControlData { source_name: decoded_bundle.source.clone(), time: decoded_bundle.time, fseq: decoded_bundle.fseq, control: control.clone() }));
+                                    entry.insert(control);
+                                },
+                            }
+                        }
+                    }
+                },
+                osc_encode_decode::TuioBundleType::Unknown => (),
+            }
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    /// Returns a snapshot of the alive [Cursor]s of every source as of the last processed bundle
+    pub fn cursors(&self) -> Vec<Cursor> {
+        self.source_list.borrow().values().flat_map(|source| source.cursor_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Object]s of every source as of the last processed bundle
+    pub fn objects(&self) -> Vec<Object> {
+        self.source_list.borrow().values().flat_map(|source| source.object_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Blob]s of every source as of the last processed bundle
+    pub fn blobs(&self) -> Vec<Blob> {
+        self.source_list.borrow().values().flat_map(|source| source.blob_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Cursor]s, [Object]s and [Blob]s of every source as [TouchPoint]s, as of the last processed bundle
+    ///
+    /// The contacts of each source are listed by profile, cursors first
+    pub fn touch_points(&self) -> Vec<TouchPoint> {
+        self.source_list.borrow().values().flat_map(|source| {
+            source.cursor_map.values().cloned().map(TouchPoint::Cursor)
+                .chain(source.object_map.values().cloned().map(TouchPoint::Object))
+                .chain(source.blob_map.values().cloned().map(TouchPoint::Blob))
+        }).collect()
+    }
+
+    /// Calls a closure with each alive [Cursor] of every source as of the last processed bundle, without allocating a snapshot
+    ///
+    /// The closure must not refresh this [Client], which panics while the entities are borrowed
+    /// # Arguments
+    /// * `f` - the closure called with each [Cursor]
+    pub fn for_each_cursor(&self, mut f: impl FnMut(&Cursor)) {
+        self.source_list.borrow().values().flat_map(|source| source.cursor_map.values()).for_each(&mut f);
+    }
+
+    /// Calls a closure with each alive [Object] of every source as of the last processed bundle, without allocating a snapshot
+    ///
+    /// The closure must not refresh this [Client], which panics while the entities are borrowed
+    /// # Arguments
+    /// * `f` - the closure called with each [Object]
+    pub fn for_each_object(&self, mut f: impl FnMut(&Object)) {
+        self.source_list.borrow().values().flat_map(|source| source.object_map.values()).for_each(&mut f);
+    }
+
+    /// Calls a closure with each alive [Blob] of every source as of the last processed bundle, without allocating a snapshot
+    ///
+    /// The closure must not refresh this [Client], which panics while the entities are borrowed
+    /// # Arguments
+    /// * `f` - the closure called with each [Blob]
+    pub fn for_each_blob(&self, mut f: impl FnMut(&Blob)) {
+        self.source_list.borrow().values().flat_map(|source| source.blob_map.values()).for_each(&mut f);
+    }
+
+    /// Returns a human-readable listing of the alive [Cursor]s, [Object]s and [Blob]s with their session ID, position and velocity, grouped by source
+    pub fn dump_state(&self) -> String {
+        let mut dump = String::new();
+
+        for (source_name, source) in self.source_list.borrow().iter() {
+            let _ = writeln!(dump, "source {:?}", source_name);
+
+            for cursor in source.cursor_map.values() {
+                let _ = writeln!(dump, "  {}", cursor);
+            }
+
+            for object in source.object_map.values() {
+                let _ = writeln!(dump, "  {}", object);
+            }
+
+            for blob in source.blob_map.values() {
+                let _ = writeln!(dump, "  {}", blob);
+            }
+        }
+
+        dump
+    }
+
+    /// Returns a snapshot of the alive [Cursor3D]s of every source as of the last processed bundle
+    pub fn cursors_3d(&self) -> Vec<Cursor3D> {
+        self.source_list.borrow().values().flat_map(|source| source.cursor_3d_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Cursor25D]s of every source as of the last processed bundle
+    pub fn cursors_25d(&self) -> Vec<Cursor25D> {
+        self.source_list.borrow().values().flat_map(|source| source.cursor_25d_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Blob3D]s of every source as of the last processed bundle
+    pub fn blobs_3d(&self) -> Vec<Blob3D> {
+        self.source_list.borrow().values().flat_map(|source| source.blob_3d_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Token]s of every source as of the last processed bundle
+    pub fn tokens(&self) -> Vec<Token> {
+        self.source_list.borrow().values().flat_map(|source| source.token_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Pointer]s of every source as of the last processed bundle
+    pub fn pointers(&self) -> Vec<Pointer> {
+        self.source_list.borrow().values().flat_map(|source| source.pointer_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Symbol]s of every source as of the last processed bundle
+    pub fn symbols(&self) -> Vec<Symbol> {
+        self.source_list.borrow().values().flat_map(|source| source.symbol_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Bounds] of every source as of the last processed bundle
+    pub fn bounds(&self) -> Vec<Bounds> {
+        self.source_list.borrow().values().flat_map(|source| source.bounds_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the decoding counters, to quantify packet loss and malformed input on a link
+    pub fn stats(&self) -> ClientStats {
+        self.stats.snapshot()
+    }
+
+    /// Returns a snapshot of the alive [Geometry]s of every source as of the last processed bundle
+    pub fn geometries(&self) -> Vec<Geometry> {
+        self.source_list.borrow().values().flat_map(|source| source.geometry_map.values().cloned()).collect()
+    }
+
+    /// Returns a snapshot of the alive [Control]s of every source as of the last processed bundle
+    pub fn controls(&self) -> Vec<Control> {
+        self.source_list.borrow().values().flat_map(|source| source.control_map.values().cloned()).collect()
+    }
+
+    /// Returns the time tag of the last processed bundle, or [None] if no bundle was processed yet
+    ///
+    /// Sources sending the immediate time tag are timestamped on reception
+    pub fn last_bundle_time(&self) -> Option<SystemTime> {
+        self.last_bundle_time.get()
+    }
+
+    /// Returns the width and height in pixels of the sensor of the tracker, as sent by the `/tuio2/frm` message of the last processed TUIO 2.0 frame,
+    /// or [None] if no TUIO 2.0 frame was processed yet
+    ///
+    /// Trackers which do not know their resolution send a dimension of 0 by 0
+    pub fn frame_dimension(&self) -> Option<(u16, u16)> {
+        self.last_tuio2_frame.borrow().as_ref().map(|(_, dimension)| *dimension)
+    }
+
+    /// Returns the source of the last processed TUIO 2.0 frame, such as `name:instance@address`, or [None] if no TUIO 2.0 frame was processed yet
+    pub fn frame_source(&self) -> Option<String> {
+        self.last_tuio2_frame.borrow().as_ref().map(|(source, _)| source.clone())
+    }
+
+    /// Returns the frame sequence number of the last processed frame of a source and a profile, or [None] if no numbered frame was processed yet
+    ///
+    /// A single [Client::refresh] may process several frames, so this is the last of them rather than the frame of each event.
+    /// The frame of an event is the `fseq` of its [CursorData], [ObjectData] or [BlobData]
+    /// # Arguments
+    /// * `source_name` - the name of the source
+    /// * `tuio_type` - the profile of the frame
+    pub fn current_fseq(&self, source_name: &str, tuio_type: TuioBundleType) -> Option<i32> {
+        self.source_list.borrow().get(source_name)?.last_frames.get(&tuio_type).copied()
+    }
+
+    /// Returns the profiles of every bundle decoded from a source, such as [TuioBundleType::Blob] for a tracker sending blobs,
+    /// or an empty set if the source is unknown or expired
+    /// # Arguments
+    /// * `source_name` - the name of the source
+    pub fn observed_profiles(&self, source_name: &str) -> HashSet<TuioBundleType> {
+        self.source_list.borrow().get(source_name).map(|source| source.observed_profiles.clone()).unwrap_or_default()
+    }
+
+    /// Returns the local socket address of the first receiver having one, such as the port chosen by the system when binding the port 0,
+    /// or [None] if no receiver has a bound socket
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.osc_receivers.iter().find_map(|receiver| receiver.local_addr())
+    }
+
+    pub fn local_receiver(&self) -> bool {
+        self.local_receiver
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rosc::OscPacket;
+
+    use std::{cell::Cell, sync::Mutex};
+
+    use crate::{osc_encode_decode::{TuioBundleType, OscEncoder, EncodeOsc}, cursor::{Position, Velocity}, clock::TestClock, Cursor, Server};
+
+    use super::*;
+
+    fn test_client() -> Client {
+        Client::from_loopback(&LoopbackTransport::new())
+    }
+
+    #[derive(Default)]
+    struct BlobCounter {
+        added: Cell<usize>,
+        updated: Cell<usize>,
+        removed: Cell<usize>,
+    }
+
+    impl Listener for BlobCounter {
+        fn blob_added(&self, _: &str, _: &Blob) {
+            self.added.set(self.added.get() + 1);
+        }
+
+        fn blob_updated(&self, _: &str, _: &Blob) {
+            self.updated.set(self.updated.get() + 1);
+        }
+
+        fn blob_removed(&self, _: &str, _: &Blob) {
+            self.removed.set(self.removed.get() + 1);
+        }
+    }
+
+    #[test]
+    fn blob_listener() {
+        let client = test_client();
+        let listener = BlobCounter::default();
+        let blob = Blob::new(0, Position {x: 0.5, y: 0.5}, 0., 0.1, 0.1, 0.01);
+
+        for (frame, blobs) in [vec![blob.clone()], vec![blob], vec![]].iter().enumerate() {
+            let mut events = TuioEvents::default();
+            client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(blobs, "source".into(), frame as i32)), &mut events).unwrap();
+            events.dispatch(&listener);
+        }
+
+        assert_eq!(listener.added.get(), 1);
+        assert_eq!(listener.updated.get(), 1);
+        assert_eq!(listener.removed.get(), 1);
+        assert!(client.blobs().is_empty());
+    }
+
+    #[test]
+    fn velocity_smoothing() {
+        let mut client = test_client();
+        client.set_smoothing(0.25);
+
+        for (frame, velocity) in [0., 1.].iter().enumerate() {
+            let cursor = Cursor::new(0, Position {x: 0.5, y: 0.5}).with_motion(Velocity {x: *velocity, y: -*velocity}, *velocity);
+            client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[cursor], "source".into(), frame as i32)), &mut TuioEvents::default()).unwrap();
+        }
+
+        let cursor = &client.cursors()[0];
+        assert_eq!(cursor.get_x_velocity(), 0.25);
+        assert_eq!(cursor.get_y_velocity(), -0.25);
+        assert_eq!(cursor.get_acceleration(), 0.25);
+    }
+
+    #[test]
+    fn position_deadband() {
+        let mut client = test_client();
+        client.set_position_deadband(0.001);
+
+        for (frame, (x, velocity)) in [(0.5, 0.), (0.5005, 0.05), (0.4997, -0.08)].iter().enumerate() {
+            let cursor = Cursor::new(0, Position {x: *x, y: 0.5}).with_motion(Velocity {x: *velocity, y: 0.}, *velocity);
+            client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[cursor], "source".into(), frame as i32)), &mut TuioEvents::default()).unwrap();
+        }
+
+        let cursor = &client.cursors()[0];
+        assert_eq!(cursor.get_x_position(), 0.5);
+        assert_eq!(cursor.get_x_velocity(), 0.);
+        assert_eq!(cursor.get_acceleration(), 0.);
+
+        let cursor = Cursor::new(0, Position {x: 0.6, y: 0.5}).with_motion(Velocity {x: 1., y: 0.}, 0.);
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[cursor], "source".into(), 3)), &mut TuioEvents::default()).unwrap();
+        assert_eq!(client.cursors()[0].get_x_velocity(), 1.);
+    }
+
+    #[test]
+    fn source_separation() {
+        let client = test_client();
+        let mut events = TuioEvents::default();
+
+        let first_source = OscEncoder::encode_cursor_bundle(&[Cursor::new(5, Position {x: 0.1, y: 0.1})], "first".into(), 1);
+        let second_source = OscEncoder::encode_cursor_bundle(&[Cursor::new(5, Position {x: 0.9, y: 0.9})], "second".into(), 1);
+
+        assert!(client.process_osc_packet(OscPacket::Bundle(first_source), &mut events).unwrap());
+        assert!(client.process_osc_packet(OscPacket::Bundle(second_source), &mut events).unwrap());
+
+        let source_list = client.source_list.borrow();
+        assert_eq!(source_list.len(), 2);
+        assert_eq!(source_list["first"].cursor_map[&5].get_x_position(), 0.1);
+        assert_eq!(source_list["second"].cursor_map[&5].get_x_position(), 0.9);
+
+        drop(source_list);
+        assert_eq!(client.cursors().len(), 2);
+
+        assert_eq!(events.cursor_events.len(), 2);
+        assert!(matches!(&events.cursor_events[0], CursorEvent::New(data) if data.source_name == "first"));
+        assert!(matches!(&events.cursor_events[1], CursorEvent::New(data) if data.source_name == "second"));
+    }
+
+    #[test]
+    fn for_each_entity() {
+        let client = test_client();
+        let mut events = TuioEvents::default();
+
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(1, Position {x: 0.1, y: 0.1})], "first".into(), 1)), &mut events).unwrap();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(2, Position {x: 0.2, y: 0.2})], "second".into(), 1)), &mut events).unwrap();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(&[Blob::new(3, Position {x: 0.3, y: 0.3}, 0., 0.1, 0.1, 0.01)], "first".into(), 1)), &mut events).unwrap();
+
+        let mut cursor_ids = Vec::new();
+        client.for_each_cursor(|cursor| cursor_ids.push(cursor.get_session_id()));
+        assert_eq!(cursor_ids, vec![1, 2]);
+
+        let mut blob_count = 0;
+        client.for_each_blob(|_| blob_count += 1);
+        assert_eq!(blob_count, 1);
+
+        client.for_each_object(|_| panic!("no object is alive"));
+    }
+
+    struct OrderListener {
+        name: &'static str,
+        log: Arc<Mutex<Vec<(&'static str, i32)>>>,
+        panics: bool,
+    }
+
+    impl Listener for OrderListener {
+        fn cursor_added(&self, _: &str, cursor: &Cursor) {
+            self.log.lock().unwrap().push((self.name, cursor.get_session_id()));
+            assert!(!self.panics, "listener failure");
+        }
+    }
+
+    #[test]
+    fn listener_order() {
+        let transport = LoopbackTransport::new();
+        let server = crate::Server::from_osc_sender(transport.clone());
+        let mut client = Client::from_loopback(&transport);
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        client.add_listener(Arc::new(OrderListener { name: "gestures", log: log.clone(), panics: true }));
+        client.add_listener(Arc::new(OrderListener { name: "logging", log: log.clone(), panics: false }));
+
+        server.send_frame(&[Cursor::new(0, Position {x: 0.1, y: 0.1}), Cursor::new(1, Position {x: 0.2, y: 0.2})], &[], &[]).unwrap();
+        assert!(client.refresh().unwrap().is_some());
+
+        assert_eq!(*log.lock().unwrap(), vec![("gestures", 0), ("logging", 0), ("gestures", 1), ("logging", 1)]);
+    }
+
+    #[test]
+    fn dump_state() {
+        let client = test_client();
+        let mut events = TuioEvents::default();
+
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(1, Position {x: 0.5, y: 0.25})], "first".into(), 1)), &mut events).unwrap();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(&[Object::new(2, 7, Position {x: 0.1, y: 0.2}, 1.5)], "second".into(), 1)), &mut events).unwrap();
+
+        assert_eq!(
+            client.dump_state(),
+            "source \"first\"\n  cursor 1 at (0.500, 0.250) moving (0.000, 0.000)\nsource \"second\"\n  object 2 of class 7 at (0.100, 0.200) angle 1.500 moving (0.000, 0.000)\n"
+        );
+    }
+
+    #[test]
+    fn frame_ordering() {
+        let mut source = SourceCollection::default();
+
+        assert!(source.update_frame(TuioBundleType::Cursor, 10));
+        assert!(!source.update_frame(TuioBundleType::Cursor, 10));
+        assert!(!source.update_frame(TuioBundleType::Cursor, 9));
+        assert!(source.update_frame(TuioBundleType::Cursor, -1));
+        assert!(source.update_frame(TuioBundleType::Cursor, 11));
+        assert!(source.update_frame(TuioBundleType::Object, 11));
+        assert!(!source.update_frame(TuioBundleType::Cursor, 0));
+        assert!(source.update_frame(TuioBundleType::Cursor, 200));
+        assert!(source.update_frame(TuioBundleType::Cursor, 0));
+    }
+
+    #[test]
+    fn split_frame() {
+        let client = test_client();
+        let mut events = TuioEvents::default();
+
+        let cursors = [Cursor::new(0, Position {x: 0.1, y: 0.1}), Cursor::new(1, Position {x: 0.2, y: 0.2}), Cursor::new(2, Position {x: 0.3, y: 0.3})];
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 5)), &mut events).unwrap();
+
+        // The frame 6 moves every cursor but is split in two bundles
+        let moved = cursors.iter().map(|cursor| Cursor::new(cursor.get_session_id(), Position {x: 0.5, y: 0.5})).collect::<Vec<_>>();
+        let mut first_part = OscEncoder::encode_cursor_bundle(&moved, "test".into(), 6);
+        let mut second_part = first_part.clone();
+        first_part.content.remove(4);
+        second_part.content.drain(2..4);
+        let (first_part, second_part) = (OscPacket::Bundle(first_part), OscPacket::Bundle(second_part));
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(first_part, &mut events).unwrap());
+        assert!(client.process_osc_packet(second_part.clone(), &mut events).unwrap());
+        assert!(!client.process_osc_packet(second_part, &mut events).unwrap());
+
+        assert_eq!(client.cursors().len(), 3);
+        assert!(client.cursors().iter().all(|cursor| cursor.get_x_position() == 0.5));
+        assert_eq!(events.cursor_events.len(), 3);
+        assert!(events.cursor_events.iter().all(|event| matches!(event, CursorEvent::Update(_))));
+
+        let mut events = TuioEvents::default();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&moved[..1], "test".into(), 7)), &mut events).unwrap();
+
+        assert_eq!(client.cursors().len(), 1);
+    }
+
+    #[test]
+    fn frame_wraparound() {
+        let mut source = SourceCollection::default();
+
+        assert!(source.update_frame(TuioBundleType::Cursor, i32::MAX));
+        assert!(source.update_frame(TuioBundleType::Cursor, i32::MIN));
+        assert!(!source.update_frame(TuioBundleType::Cursor, i32::MAX));
+    }
+
+    #[test]
+    fn coordinate_transform() {
+        let mut client = test_client();
+        client.set_transform(Transform::flip_y());
+
+        let cursors = vec![Cursor::new(0, Position { x: 0.25, y: 0.25 })];
+        let mut events = TuioEvents::default();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 0)), &mut events).unwrap();
+
+        assert_eq!(client.cursors()[0].get_y_position(), 0.75);
+    }
+
+    #[test]
+    fn new_this_frame() {
+        let client = test_client();
+        let mut events = TuioEvents::default();
+
+        let cursors = vec![Cursor::new(0, Position { x: 0.25, y: 0.25 })];
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 0)), &mut events).unwrap();
+        assert!(client.cursors()[0].is_new_this_frame());
+
+        let cursors = vec![Cursor::new(0, Position { x: 0.5, y: 0.25 }), Cursor::new(1, Position { x: 0.75, y: 0.75 })];
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 1)), &mut events).unwrap();
+        assert_eq!(client.touch_points().iter().map(TouchPoint::is_new_this_frame).collect::<Vec<_>>(), vec![false, true]);
+
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 2)), &mut events).unwrap();
+        assert!(client.cursors().iter().all(|cursor| !cursor.is_new_this_frame()));
+    }
+
+    #[test]
+    fn pixel_rect() {
+        let mut client = test_client();
+        assert_eq!(client.to_pixel(&Position { x: 0.5, y: 0.25 }), (0.5, 0.25));
+
+        client.set_transform(Transform::flip_y());
+        client.set_pixel_rect(PixelRect { x: 1920., y: 0., width: 1920., height: 1080. });
+
+        let cursors = vec![Cursor::new(0, Position { x: 0.5, y: 0.25 })];
+        let mut events = TuioEvents::default();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 0)), &mut events).unwrap();
+
+        assert_eq!(client.to_pixel(client.cursors()[0].get_position()), (2880., 810.));
+    }
+
+    #[test]
+    fn bounds_policy() {
+        let mut client = test_client();
+        client.set_bounds_policy(BoundsPolicy::Reject);
+
+        let mut events = TuioEvents::default();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position { x: 0.25, y: 0.25 })], "test".into(), 0)), &mut events).unwrap();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position { x: 1.02, y: 0.25 })], "test".into(), 1)), &mut events).unwrap();
+
+        assert_eq!(client.cursors()[0].get_x_position(), 0.25);
+
+        client.set_bounds_policy(BoundsPolicy::Clamp);
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position { x: 1.02, y: 0.25 })], "test".into(), 2)), &mut events).unwrap();
+
+        assert_eq!(client.cursors()[0].get_x_position(), 1.);
+    }
+
+    #[test]
+    fn bundle_time() {
+        let client = test_client();
+        assert!(client.last_bundle_time().is_none());
+
+        let cursors = vec![Cursor::new(0, Position { x: 0., y: 0. })];
+        let mut events = TuioEvents::default();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 0)), &mut events).unwrap();
+
+        let time = client.last_bundle_time().unwrap();
+        match &events.cursor_events[0] {
+            CursorEvent::New(data) => assert_eq!(data.time, time),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn nested_bundles() {
+        let client = test_client();
+        let cursors = vec![Cursor::new(0, Position { x: 0., y: 0. })];
+        let blobs = vec![Blob::new(1, Position { x: 0., y: 0. }, 0., 0.1, 0.1, 0.01)];
+
+        let frame = rosc::OscBundle {
+            timetag: rosc::OscTime { seconds: 0, fractional: 1 },
+            content: vec![
+                OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 1)),
+                OscPacket::Bundle(OscEncoder::encode_blob_bundle(&blobs, "test".into(), 1)),
+            ]
+        };
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(OscPacket::Bundle(frame), &mut events).unwrap());
+        assert_eq!(events.cursor_events.len(), 1);
+        assert_eq!(events.blob_events.len(), 1);
+    }
+
+    #[test]
+    fn flattened_bundles() {
+        let client = test_client();
+        let cursors = vec![Cursor::new(0, Position { x: 0.5, y: 0.5 })];
+
+        let mut content = OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 1).content;
+        let fseq_message = content.pop().unwrap();
+        let source_message = content.remove(0);
+
+        let frame = rosc::OscBundle {
+            timetag: rosc::OscTime { seconds: 0, fractional: 1 },
+            content: vec![
+                source_message,
+                OscPacket::Bundle(rosc::OscBundle { timetag: rosc::OscTime { seconds: 0, fractional: 1 }, content }),
+                fseq_message,
+            ]
+        };
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(OscPacket::Bundle(frame), &mut events).unwrap());
+        assert_eq!(events.cursor_events.len(), 1);
+        assert_eq!(client.cursors()[0].get_x_position(), 0.5);
+    }
+
+    #[test]
+    fn malformed_bundle_is_skipped() {
+        let client = test_client();
+        let cursors = vec![Cursor::new(0, Position { x: 0., y: 0. })];
+
+        let mut malformed = OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 0);
+        malformed.content.remove(0);
+
+        {
+            let mut buffer = client.packet_buffer.lock().unwrap();
+            buffer.push(OscPacket::Bundle(malformed));
+            buffer.push(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 1)));
+        }
+
+        let events = client.refresh().unwrap().unwrap();
+        assert_eq!(events.cursor_events.len(), 1);
+        assert_eq!(client.cursors().len(), 1);
+    }
+
+    #[test]
+    fn namespace() {
+        let mut client = test_client();
+        client.set_namespace("mytable/");
+        let cursors = vec![Cursor::new(0, Position { x: 0., y: 0. })];
+
+        let mut namespaced = OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 1));
+        osc_encode_decode::add_namespace(&mut namespaced, "/mytable");
+
+        let mut other = OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "other".into(), 1));
+        osc_encode_decode::add_namespace(&mut other, "/othertable");
+
+        let mut events = TuioEvents::default();
+        assert!(!client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "standard".into(), 1)), &mut events).unwrap());
+        assert!(!client.process_osc_packet(other, &mut events).unwrap());
+        assert!(client.process_osc_packet(namespaced, &mut events).unwrap());
+
+        assert_eq!(events.cursor_events.len(), 1);
+        assert_eq!(client.source_list.borrow().keys().collect::<Vec<_>>(), vec!["test"]);
+    }
+
+    #[test]
+    fn large_bundle() {
+        let client = Client::builder().address(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).recv_buffer_size(1 << 20).build().unwrap();
+        let server = crate::Server::from_socket_addr(client.local_addr().unwrap()).unwrap();
+
+        let cursors: Vec<Cursor> = (0..1000).map(|id| Cursor::new(id, Position {x: id as f32 / 1000., y: 0.5})).collect();
+        let frame = OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 1));
+        assert!(rosc::encoder::encode(&frame).unwrap().len() > 60_000);
+
+        server.send_frame(&cursors, &[], &[]).unwrap();
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(client.osc_receivers[0].recv().unwrap(), &mut events).unwrap());
+        assert_eq!(client.cursors().len(), 1000);
+        assert_eq!(client.cursors()[999].get_x_position(), 0.999);
+    }
+
+    #[test]
+    fn ipv6_round_trip() {
+        let client = Client::from_address(SocketAddr::new(std::net::IpAddr::V6(std::net::Ipv6Addr::LOCALHOST), 0)).unwrap();
+        let server = crate::Server::from_socket_addr(client.local_addr().unwrap()).unwrap();
+        assert!(client.local_receiver());
+
+        server.send_frame(&[Cursor::new(0, Position {x: 0.5, y: 0.5})], &[], &[]).unwrap();
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(client.osc_receivers[0].recv().unwrap(), &mut events).unwrap());
+        assert_eq!(events.cursor_events.len(), 1);
+        assert_eq!(client.cursors()[0].get_x_position(), 0.5);
+    }
+
+    #[test]
+    fn entity_lifetime() {
+        let client = test_client();
+
+        for frame in 0..3 {
+            let cursor = Cursor::new(0, Position {x: 0.5, y: 0.5});
+            client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[cursor], "source".into(), frame)), &mut TuioEvents::default()).unwrap();
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        let cursor = &client.cursors()[0];
+        assert_eq!(cursor.update_count(), 2);
+        assert!(cursor.age() >= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn clock_lifetime() {
+        let mut client = test_client();
+        let clock = TestClock::new();
+        client.set_clock(clock.clone());
+
+        let cursors = [Cursor::new(0, Position {x: 0.5, y: 0.5})];
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "source".into(), 1)), &mut TuioEvents::default()).unwrap();
+
+        clock.advance(Duration::from_millis(40));
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "source".into(), 2)), &mut TuioEvents::default()).unwrap();
+        assert_eq!(client.cursors()[0].age(), Duration::from_millis(40));
+
+        clock.advance(Duration::from_millis(60));
+        let mut events = TuioEvents::default();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[], "source".into(), 3)), &mut events).unwrap();
+        assert!(matches!(&events.cursor_events[..], [CursorEvent::Remove(data)] if data.cursor.age() == Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn expected_contacts() {
+        let mut client = test_client();
+        client.set_expected_contacts(64);
+
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.5, y: 0.5})], "source".into(), 1)), &mut TuioEvents::default()).unwrap();
+
+        let source_list = client.source_list.borrow();
+        let source = source_list.get("source").unwrap();
+        assert!(source.cursor_map.capacity() >= 64);
+        assert!(source.object_map.capacity() >= 64);
+        assert!(source.blob_map.capacity() >= 64);
+    }
+
+    #[test]
+    fn entity_timeout() {
+        let transport = LoopbackTransport::new();
+        let mut client = Client::from_loopback(&transport);
+        let clock = TestClock::new();
+        client.set_clock(clock.clone());
+        client.set_entity_timeout(Duration::from_millis(20));
+
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position {x: 0.5, y: 0.5})], "source".into(), 1)), &mut TuioEvents::default()).unwrap();
+        assert!(client.refresh().unwrap().is_none());
+
+        clock.advance(Duration::from_millis(20));
+        assert!(client.refresh().unwrap().is_none());
+
+        clock.advance(Duration::from_millis(1));
+        let events = client.refresh().unwrap().unwrap();
+        assert!(matches!(&events.cursor_events[..], [CursorEvent::Remove(data)] if data.source_name == "source"));
+        assert!(client.cursors().is_empty());
+        assert!(client.source_list.borrow().is_empty());
+    }
+
+    #[test]
+    fn event_queue() {
+        let transport = LoopbackTransport::new();
+        let mut server = crate::Server::from_osc_sender(transport.clone());
+        let mut client = Client::from_loopback(&transport);
+        client.set_event_queue(true);
+
+        server.init_frame();
+        let session_id = server.create_cursor(0.5, 0.5);
+        let object_id = server.create_object(1, 0.2, 0.2, 0.);
+        server.commit_frame().unwrap();
+
+        assert!(client.refresh().unwrap().is_none());
+
+        server.init_frame();
+        server.remove_cursor(session_id);
+        server.commit_frame().unwrap();
+
+        assert!(client.refresh().unwrap().is_none());
+
+        let events: Vec<TuioEvent> = client.drain_events().collect();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(&events[0], TuioEvent::Cursor(CursorEvent::New(data)) if data.cursor.get_session_id() == session_id));
+        assert!(matches!(&events[1], TuioEvent::Object(ObjectEvent::New(_))));
+        assert!(matches!(&events[2], TuioEvent::Cursor(CursorEvent::Remove(_))));
+        assert_eq!(client.drain_events().count(), 0);
+
+        client.set_event_queue(false);
+        server.init_frame();
+        server.update_object(object_id, 0.3, 0.3, 0.);
+        server.commit_frame().unwrap();
+
+        assert!(client.refresh().unwrap().is_some());
+        assert_eq!(client.drain_events().count(), 0);
+    }
+
+    #[test]
+    fn event_queue_limit() {
+        fn queued_positions(client: &Client) -> Vec<f32> {
+            client.drain_events().map(|event| match event {
+                TuioEvent::Cursor(CursorEvent::New(data) | CursorEvent::Update(data)) => data.cursor.get_x_position(),
+                _ => panic!("unexpected event"),
+            }).collect()
+        }
+
+        for (policy, refresh_every_frame, expected, remaining) in [
+            (QueuePolicy::DropOldest, true, vec![0.5, 0.75], vec![]),
+            (QueuePolicy::DropNewest, true, vec![0.25, 0.5], vec![]),
+            (QueuePolicy::Block, false, vec![0.25, 0.5], vec![0.75]),
+        ] {
+            let transport = LoopbackTransport::new();
+            let mut server = Server::from_osc_sender(transport.clone());
+            let mut client = Client::from_loopback(&transport);
+            client.set_event_queue(true);
+            client.set_event_queue_limit(2, policy);
+
+            server.init_frame();
+            let session_id = server.create_cursor(0.25, 0.5);
+            server.commit_frame().unwrap();
+
+            for x in [0.5, 0.75] {
+                if refresh_every_frame {
+                    client.refresh().unwrap();
+                }
+
+                server.init_frame();
+                server.update_cursor(session_id, x, 0.5);
+                server.commit_frame().unwrap();
+            }
+
+            client.refresh().unwrap();
+            assert_eq!(queued_positions(&client), expected);
+
+            client.refresh().unwrap();
+            assert_eq!(queued_positions(&client), remaining);
+        }
+    }
+
+    #[test]
+    fn session_id_remapping() {
+        let transport = LoopbackTransport::new();
+        let mut left = crate::Server::from_osc_sender(transport.clone());
+        left.set_source_name("left");
+        let mut right = crate::Server::from_osc_sender(transport.clone());
+        right.set_source_name("right");
+        let mut client = Client::from_loopback(&transport);
+        client.set_session_id_remapping(true);
+
+        let session_ids = |events: &TuioEvents| events.cursor_events.iter().map(|event| match event {
+            CursorEvent::New(data) => ("new", data.cursor.get_session_id()),
+            CursorEvent::Update(data) => ("update", data.cursor.get_session_id()),
+            CursorEvent::Remove(data) => ("remove", data.cursor.get_session_id()),
+        }).collect::<Vec<_>>();
+
+        left.send_frame(&[Cursor::new(5, Position { x: 0.1, y: 0.1 })], &[], &[]).unwrap();
+        right.send_frame(&[Cursor::new(5, Position { x: 0.9, y: 0.9 })], &[], &[]).unwrap();
+        assert_eq!(session_ids(&client.refresh().unwrap().unwrap()), vec![("new", 0), ("new", 1)]);
+
+        left.send_frame(&[], &[], &[]).unwrap();
+        right.send_frame(&[Cursor::new(5, Position { x: 0.8, y: 0.9 }), Cursor::new(6, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        assert_eq!(session_ids(&client.refresh().unwrap().unwrap()), vec![("remove", 0), ("update", 1), ("new", 0)]);
+
+        assert_eq!(client.cursors().iter().map(|cursor| cursor.get_session_id()).collect::<Vec<_>>(), vec![5, 6]);
+    }
+
+    #[test]
+    fn stats() {
+        use crate::server::SendOsc;
+
+        let transport = LoopbackTransport::new();
+        let client = Client::from_loopback(&transport);
+        let cursors = [Cursor::new(0, Position { x: 0.5, y: 0.5 })];
+
+        let mut packets: Vec<OscPacket> = [1, 2, 4].into_iter()
+            .map(|frame| OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), frame)))
+            .collect();
+
+        let mut malformed = OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 5);
+        malformed.content.insert(3, OscPacket::Message(rosc::OscMessage {
+            addr: "/tuio/2Dcur".into(),
+            args: vec![rosc::OscType::String("set".into()), rosc::OscType::Int(0), rosc::OscType::String("x".into())]
+        }));
+        packets.push(OscPacket::Bundle(malformed));
+
+        for packet in &packets {
+            transport.send_osc_packet(packet).unwrap();
+        }
+
+        client.refresh().unwrap();
+
+        assert_eq!(client.stats(), ClientStats {
+            bundles_received: 4,
+            messages_decoded: 16,
+            messages_dropped: 1,
+            fseq_gaps: 1,
+            bytes_received: packets.iter().map(|packet| rosc::encoder::encode(packet).unwrap().len() as u64).sum(),
+        });
+    }
+
+    #[test]
+    fn tuio2_frames() {
+        let mut client = test_client();
+        client.set_tuio2_frames(true);
+
+        let frame = |fseq: i32, tokens: &[Token], pointers: &[Pointer]| {
+            let mut content = OscEncoder::encode_token_bundle(tokens, "test".into(), fseq).content;
+            content.pop();
+
+            let pointer_content = OscEncoder::encode_pointer_bundle(pointers, "test".into(), fseq).content;
+            content.extend(pointer_content[1..pointer_content.len() - 1].iter().cloned());
+
+            let alive = tokens.iter().map(Token::get_session_id).chain(pointers.iter().map(Pointer::get_session_id)).map(rosc::OscType::Int).collect();
+            content.push(OscPacket::Message(rosc::OscMessage { addr: "/tuio2/alv".into(), args: alive }));
+
+            OscPacket::Bundle(rosc::OscBundle { timetag: rosc::OscTime { seconds: 0, fractional: 1 }, content })
+        };
+
+        let tokens = [Token::new(0, 1, 0, 0, Position { x: 0.5, y: 0.5 }, 0.)];
+        let pointers = [Pointer::new(1, 1, 0, 0, Position { x: 0.25, y: 0.25 }, 0., 0., 0.01, 0.5)];
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(frame(1, &tokens, &pointers), &mut events).unwrap());
+        assert_eq!((events.token_events.len(), events.pointer_events.len()), (1, 1));
+        assert_eq!(client.observed_profiles("test"), HashSet::from([TuioBundleType::Token, TuioBundleType::Pointer]));
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(frame(2, &tokens, &[]), &mut events).unwrap());
+        assert!(matches!(&events.token_events[..], [TokenEvent::Update(_)]));
+        assert!(matches!(&events.pointer_events[..], [PointerEvent::Remove(data)] if data.pointer.get_session_id() == 1));
+        assert!(client.pointers().is_empty());
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(frame(3, &[], &[]), &mut events).unwrap());
+        assert!(matches!(&events.token_events[..], [TokenEvent::Remove(_)]));
+    }
+
+    #[test]
+    fn vanished_tokens() {
+        let client = test_client();
+        let tokens = [Token::new(0, 1, 0, 0, Position { x: 0.5, y: 0.5 }, 0.), Token::new(1, 2, 0, 0, Position { x: 0.25, y: 0.25 }, 0.)];
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_token_bundle(&tokens, "test".into(), 1)), &mut events).unwrap());
+        assert_eq!(client.tokens().len(), 2);
+
+        let mut events = TuioEvents::default();
+        assert!(client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_token_bundle(&[], "test".into(), 2)), &mut events).unwrap());
+        assert!(matches!(&events.token_events[..], [TokenEvent::Remove(_), TokenEvent::Remove(_)]));
+        assert!(client.tokens().is_empty());
+    }
+
+    #[test]
+    fn frame_dimension() {
+        let client = test_client();
+        assert_eq!(client.frame_dimension(), None);
+
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[], "test".into(), 1)), &mut TuioEvents::default()).unwrap();
+        assert_eq!(client.frame_source(), None);
+
+        let mut frame = OscEncoder::encode_token_bundle(&[Token::new(0, 1, 0, 0, Position { x: 0.5, y: 0.5 }, 0.)], "test".into(), 1);
+
+        if let OscPacket::Message(message) = &mut frame.content[0] {
+            message.args[2] = rosc::OscType::Int((1920 << 16) | 1080);
+            message.args[3] = rosc::OscType::String("tracker:1@10.0.0.2".into());
+        }
+
+        client.process_osc_packet(OscPacket::Bundle(frame), &mut TuioEvents::default()).unwrap();
+        assert_eq!(client.frame_dimension(), Some((1920, 1080)));
+        assert_eq!(client.frame_source().as_deref(), Some("tracker:1@10.0.0.2"));
+    }
+
+    #[test]
+    fn observed_profiles() {
+        let client = test_client();
+        let mut events = TuioEvents::default();
+        assert!(client.observed_profiles("test").is_empty());
+
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&[Cursor::new(0, Position { x: 0.5, y: 0.5 })], "test".into(), 1)), &mut events).unwrap();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(&[], "test".into(), -1)), &mut events).unwrap();
+        client.process_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(&[], "other".into(), 1)), &mut events).unwrap();
+
+        assert_eq!(client.observed_profiles("test"), HashSet::from([TuioBundleType::Cursor, TuioBundleType::Object]));
+        assert_eq!(client.observed_profiles("other"), HashSet::from([TuioBundleType::Blob]));
+    }
+
+    #[test]
+    fn current_fseq() {
+        use crate::server::SendOsc;
+
+        let transport = LoopbackTransport::new();
+        let client = Client::from_loopback(&transport);
+        let cursors = [Cursor::new(0, Position { x: 0.5, y: 0.5 })];
+
+        assert_eq!(client.current_fseq("test", TuioBundleType::Cursor), None);
+
+        transport.send_osc_packet(&OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 14087))).unwrap();
+        let events = client.refresh().unwrap().unwrap();
+
+        assert!(matches!(&events.cursor_events[0], CursorEvent::New(data) if data.fseq == 14087));
+        assert_eq!(client.current_fseq("test", TuioBundleType::Cursor), Some(14087));
+        assert_eq!(client.current_fseq("test", TuioBundleType::Object), None);
+    }
+
+    #[test]
+    fn profile_filter() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let mut client = Client::from_loopback(&transport);
+        client.disable_profile(TuioBundleType::Object);
+        client.disable_profile(TuioBundleType::Blob);
+
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+        server.create_object(1, 0.25, 0.25, 0.);
+        server.create_blob(0.75, 0.75, 0., 0.1, 0.1, 0.01);
+        server.commit_frame().unwrap();
+
+        let events = client.refresh().unwrap().unwrap();
+        assert_eq!(events.cursor_events.len(), 1);
+        assert!(events.object_events.is_empty() && events.blob_events.is_empty());
+        assert!(client.objects().is_empty() && client.blobs().is_empty());
+        assert!(!client.is_profile_enabled(TuioBundleType::Object));
+
+        client.enable_profile(TuioBundleType::Object);
+        server.full_update = true;
+        server.init_frame();
+        server.create_object(2, 0.5, 0.25, 0.);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(client.objects().len(), 2);
+        assert!(client.blobs().is_empty());
+    }
+
+    #[test]
+    fn touch_points() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+
+        server.init_frame();
+        let object_id = server.create_object(3, 0.25, 0.75, 0.);
+        let cursor_id = server.create_cursor(0.5, 0.5);
+        let blob_id = server.create_blob(0.75, 0.25, 0., 0.1, 0.1, 0.01);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        let touch_points = client.touch_points();
+        let positions: Vec<(i32, f32, f32)> = touch_points.iter().map(|point| (point.get_session_id(), point.get_position().x, point.get_position().y)).collect();
+
+        assert_eq!(positions, vec![(cursor_id, 0.5, 0.5), (object_id, 0.25, 0.75), (blob_id, 0.75, 0.25)]);
+        assert!(matches!(&touch_points[1], TouchPoint::Object(object) if object.get_class_id() == 3));
+        assert!(touch_points.iter().all(|point| point.get_velocity() == &Velocity::default()));
+    }
+
+    #[test]
+    fn drop_joins_receive_threads() {
+        for _ in 0..50 {
+            let client = Client::from_address(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+            client.connect().unwrap();
+
+            let listen = Arc::clone(&client.listen);
+            assert_eq!(Arc::strong_count(&listen), 3);
+
+            drop(client);
+            assert_eq!(Arc::strong_count(&listen), 1);
+        }
+    }
+
+    #[test]
+    fn undecodable_packet() {
+        let port = std::net::UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap().local_addr().unwrap().port();
+        let client = Client::from_port(port).unwrap();
+        let address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
+        let server = Server::from_socket_addr(address).unwrap();
+        client.connect().unwrap();
+
+        std::net::UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap().send_to(b"not an OSC packet", address).unwrap();
+        server.send_frame(&[Cursor::new(2, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(1);
+        let mut received = 0;
+
+        while received == 0 && Instant::now() < deadline {
+            received += client.refresh().unwrap().map_or(0, |events| events.len());
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        client.disconnect();
+
+        assert_eq!(received, 1);
+        assert_eq!(client.stats().messages_dropped, 1);
+    }
+
+    #[test]
+    fn tcp_connect_retry() {
+        let listener = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+
+        let client = Client::new_tcp(address);
+        assert!(matches!(client.connect(), Err(TuioError::Socket(_))));
+
+        let _listener = std::net::TcpListener::bind(address).unwrap();
+        client.connect().unwrap();
+        client.disconnect();
+    }
+
+    #[test]
+    fn held_back_removal() {
+        let transport = LoopbackTransport::new();
+        let server = Server::from_osc_sender(transport.clone());
+        let clock = TestClock::new();
+        let mut client = Client::from_loopback(&transport);
+        client.set_clock(clock.clone());
+        client.set_object_persistence(0.05, Duration::from_millis(500));
+
+        server.send_frame(&[], &[Object::new(1, 7, Position { x: 0.5, y: 0.5 }, 0.)], &[]).unwrap();
+        assert_eq!(client.refresh().unwrap().unwrap().object_events.len(), 1);
+
+        server.send_frame(&[], &[], &[]).unwrap();
+        assert!(client.refresh().unwrap().is_some_and(|events| events.is_empty()));
+        assert!(client.objects().is_empty());
+
+        clock.advance(Duration::from_millis(501));
+        assert!(matches!(&client.refresh().unwrap().unwrap().object_events[..], [ObjectEvent::Remove(_)]));
+    }
+
+    #[test]
+    fn recv_once() {
+        let port = std::net::UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap().local_addr().unwrap().port();
+        let client = Client::from_port(port).unwrap();
+        let server = Server::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port)).unwrap();
+
+        assert!(matches!(client.recv_once(Duration::from_millis(10)), Err(TuioError::Socket(err)) if err.kind() == ErrorKind::TimedOut));
+
+        server.send_frame(&[Cursor::new(2, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        let events = client.recv_once(Duration::from_secs(1)).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], TuioEvent::Cursor(CursorEvent::New(data)) if data.cursor.get_session_id() == 2));
+    }
+
+    #[test]
+    fn recv_once_coalescing() {
+        let transport = LoopbackTransport::new();
+        let server = Server::from_osc_sender(transport.clone());
+        let clock = TestClock::new();
+        let mut client = Client::from_loopback(&transport);
+        client.set_clock(clock.clone());
+        client.set_update_coalescing(Duration::from_millis(100));
+
+        let send_cursor = |x| server.send_frame(&[Cursor::new(2, Position { x, y: 0.5 })], &[], &[]).unwrap();
+        let is_update = |events: &[TuioEvent]| matches!(events, [TuioEvent::Cursor(CursorEvent::Update(_))]);
+
+        send_cursor(0.1);
+        assert_eq!(client.recv_once(Duration::ZERO).unwrap().len(), 1);
+
+        send_cursor(0.2);
+        assert!(client.recv_once(Duration::ZERO).unwrap().is_empty());
+
+        clock.advance(Duration::from_millis(100));
+        send_cursor(0.3);
+        assert!(is_update(&client.recv_once(Duration::ZERO).unwrap()));
+    }
+}
@@ -0,0 +1,95 @@
+use std::{sync::{Arc, Mutex}, time::{Duration, Instant}};
+
+/// A source of [Instant]s, read by the [crate::Server] to time its frames and by the [crate::Client] to expire silent sources and coalesce updates
+pub trait Clock: Send + Sync {
+    /// Returns the current [Instant]
+    fn now(&self) -> Instant;
+}
+
+/// The [Clock] of the system, returning [Instant::now]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [Clock] which only advances when told to, so the velocities computed from frame durations are exact in tests
+///
+/// Clones share the same time, so a clone given to a [crate::Server] or a [crate::Client] can be advanced from the test
+///
+/// ```
+/// use std::time::Duration;
+/// use tuio_rs::{Client, Server, clock::TestClock, loopback::LoopbackTransport};
+///
+/// let clock = TestClock::new();
+/// let transport = LoopbackTransport::new();
+/// let mut server = Server::from_osc_sender(transport.clone());
+/// let client = Client::from_loopback(&transport);
+/// server.set_clock(clock.clone());
+///
+/// server.init_frame();
+/// let cursor_id = server.create_cursor(0.5, 0.5);
+/// server.commit_frame().unwrap();
+///
+/// clock.advance(Duration::from_millis(250));
+/// server.init_frame();
+/// server.update_cursor(cursor_id, 0.75, 0.5);
+/// server.commit_frame().unwrap();
+/// client.refresh().unwrap();
+///
+/// assert_eq!(client.cursors()[0].get_x_velocity(), 1.);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TestClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl TestClock {
+    /// Creates a [TestClock] stopped at the current [Instant]
+    pub fn new() -> Self {
+        Self { now: Arc::new(Mutex::new(Instant::now())) }
+    }
+
+    /// Moves the time of this [TestClock] and of its clones forward
+    /// # Arguments
+    /// * `duration` - the [Duration] to advance by
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_advance() {
+        let clock = TestClock::new();
+        let start = clock.now();
+        let shared = clock.clone();
+
+        assert_eq!(clock.now(), start);
+
+        shared.advance(Duration::from_millis(40));
+        assert_eq!(clock.now() - start, Duration::from_millis(40));
+
+        clock.advance(Duration::from_millis(60));
+        assert_eq!(clock.now() - start, Duration::from_millis(100));
+        assert_eq!(shared.now(), clock.now());
+    }
+}
@@ -0,0 +1,221 @@
+use std::{collections::HashMap, time::{Duration, Instant}};
+
+use crate::{client::{TuioEvents, TuioEvent, CursorEvent, ObjectEvent, BlobEvent, Cursor3DEvent, Cursor25DEvent, Blob3DEvent, TokenEvent, PointerEvent, SymbolEvent, BoundsEvent, GeometryEvent, ControlEvent}, osc_encode_decode::TuioBundleType};
+
+/// The lifecycle step of an event
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Step {
+    New,
+    Update,
+    Remove,
+}
+
+type InputKey = (String, TuioBundleType, i32);
+
+/// Limits the update events of each input to one per interval, holding back the latest update until the interval elapses
+///
+/// New and remove events pass through immediately, and a remove event discards the update held back for its input
+pub(crate) struct UpdateCoalescer {
+    interval: Duration,
+    last_delivery: HashMap<InputKey, Instant>,
+    pending: HashMap<InputKey, TuioEvent>,
+}
+
+impl UpdateCoalescer {
+    /// Creates an [UpdateCoalescer] delivering at most one update per input and per interval
+    /// # Arguments
+    /// * `interval` - the minimum [Duration] between two update events of an input
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_delivery: HashMap::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Holds back the update events of [TuioEvents] arriving too soon and adds the held back updates whose interval elapsed
+    ///
+    /// Returns true if any event remains to be delivered
+    /// # Arguments
+    /// * `events` - the [TuioEvents] to coalesce
+    /// * `now` - the delivery [Instant]
+    pub(crate) fn apply(&mut self, events: &mut TuioEvents, now: Instant) -> bool {
+        self.coalesce(TuioBundleType::Cursor, &mut events.cursor_events, now, TuioEvent::Cursor, |event| match event {
+            CursorEvent::New(data) => (Step::New, &data.source_name, data.cursor.session_id),
+            CursorEvent::Update(data) => (Step::Update, &data.source_name, data.cursor.session_id),
+            CursorEvent::Remove(data) => (Step::Remove, &data.source_name, data.cursor.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Object, &mut events.object_events, now, TuioEvent::Object, |event| match event {
+            ObjectEvent::New(data) => (Step::New, &data.source_name, data.object.session_id),
+            ObjectEvent::Update(data) => (Step::Update, &data.source_name, data.object.session_id),
+            ObjectEvent::Remove(data) => (Step::Remove, &data.source_name, data.object.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Blob, &mut events.blob_events, now, TuioEvent::Blob, |event| match event {
+            BlobEvent::New(data) => (Step::New, &data.source_name, data.blob.session_id),
+            BlobEvent::Update(data) => (Step::Update, &data.source_name, data.blob.session_id),
+            BlobEvent::Remove(data) => (Step::Remove, &data.source_name, data.blob.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Cursor3D, &mut events.cursor_3d_events, now, TuioEvent::Cursor3D, |event| match event {
+            Cursor3DEvent::New(data) => (Step::New, &data.source_name, data.cursor.session_id),
+            Cursor3DEvent::Update(data) => (Step::Update, &data.source_name, data.cursor.session_id),
+            Cursor3DEvent::Remove(data) => (Step::Remove, &data.source_name, data.cursor.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Cursor25D, &mut events.cursor_25d_events, now, TuioEvent::Cursor25D, |event| match event {
+            Cursor25DEvent::New(data) => (Step::New, &data.source_name, data.cursor.session_id),
+            Cursor25DEvent::Update(data) => (Step::Update, &data.source_name, data.cursor.session_id),
+            Cursor25DEvent::Remove(data) => (Step::Remove, &data.source_name, data.cursor.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Blob3D, &mut events.blob_3d_events, now, TuioEvent::Blob3D, |event| match event {
+            Blob3DEvent::New(data) => (Step::New, &data.source_name, data.blob.session_id),
+            Blob3DEvent::Update(data) => (Step::Update, &data.source_name, data.blob.session_id),
+            Blob3DEvent::Remove(data) => (Step::Remove, &data.source_name, data.blob.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Token, &mut events.token_events, now, TuioEvent::Token, |event| match event {
+            TokenEvent::New(data) => (Step::New, &data.source_name, data.token.session_id),
+            TokenEvent::Update(data) => (Step::Update, &data.source_name, data.token.session_id),
+            TokenEvent::Remove(data) => (Step::Remove, &data.source_name, data.token.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Pointer, &mut events.pointer_events, now, TuioEvent::Pointer, |event| match event {
+            PointerEvent::New(data) => (Step::New, &data.source_name, data.pointer.session_id),
+            PointerEvent::Update(data) => (Step::Update, &data.source_name, data.pointer.session_id),
+            PointerEvent::Remove(data) => (Step::Remove, &data.source_name, data.pointer.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Symbol, &mut events.symbol_events, now, TuioEvent::Symbol, |event| match event {
+            SymbolEvent::New(data) => (Step::New, &data.source_name, data.symbol.session_id),
+            SymbolEvent::Update(data) => (Step::Update, &data.source_name, data.symbol.session_id),
+            SymbolEvent::Remove(data) => (Step::Remove, &data.source_name, data.symbol.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Bounds, &mut events.bounds_events, now, TuioEvent::Bounds, |event| match event {
+            BoundsEvent::New(data) => (Step::New, &data.source_name, data.bounds.session_id),
+            BoundsEvent::Update(data) => (Step::Update, &data.source_name, data.bounds.session_id),
+            BoundsEvent::Remove(data) => (Step::Remove, &data.source_name, data.bounds.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Geometry, &mut events.geometry_events, now, TuioEvent::Geometry, |event| match event {
+            GeometryEvent::New(data) => (Step::New, &data.source_name, data.geometry.session_id),
+            GeometryEvent::Update(data) => (Step::Update, &data.source_name, data.geometry.session_id),
+            GeometryEvent::Remove(data) => (Step::Remove, &data.source_name, data.geometry.session_id),
+        });
+
+        self.coalesce(TuioBundleType::Control, &mut events.control_events, now, TuioEvent::Control, |event| match event {
+            ControlEvent::New(data) => (Step::New, &data.source_name, data.control.session_id),
+            ControlEvent::Update(data) => (Step::Update, &data.source_name, data.control.session_id),
+            ControlEvent::Remove(data) => (Step::Remove, &data.source_name, data.control.session_id),
+        });
+
+        let due: Vec<InputKey> = self.pending.keys()
+            .filter(|key| self.is_due(key, now))
+            .cloned()
+            .collect();
+
+        for key in due {
+            if let Some(event) = self.pending.remove(&key) {
+                events.push(event);
+                self.last_delivery.insert(key, now);
+            }
+        }
+
+        !events.is_empty()
+    }
+
+    /// Returns true if the interval elapsed since the last delivered event of an input
+    fn is_due(&self, key: &InputKey, now: Instant) -> bool {
+        self.last_delivery.get(key).is_none_or(|last_delivery| now.saturating_duration_since(*last_delivery) >= self.interval)
+    }
+
+    /// Holds back the update events of a profile arriving too soon
+    /// # Arguments
+    /// * `tuio_type` - the profile of the events
+    /// * `events` - the events to coalesce
+    /// * `now` - the delivery [Instant]
+    /// * `wrap` - wraps an event held back into a [TuioEvent]
+    /// * `parts` - returns the lifecycle step of an event, the name of its source and its session ID
+    fn coalesce<E>(&mut self, tuio_type: TuioBundleType, events: &mut Vec<E>, now: Instant, wrap: impl Fn(E) -> TuioEvent, parts: impl Fn(&E) -> (Step, &String, i32)) {
+        for event in std::mem::take(events) {
+            let (step, source_name, session_id) = parts(&event);
+            let key = (source_name.clone(), tuio_type, session_id);
+
+            match step {
+                Step::Update if !self.is_due(&key, now) => {
+                    self.pending.insert(key, wrap(event));
+                    continue;
+                },
+                Step::New | Step::Update => {
+                    self.pending.remove(&key);
+                    self.last_delivery.insert(key, now);
+                },
+                Step::Remove => {
+                    self.pending.remove(&key);
+                    self.last_delivery.remove(&key);
+                },
+            }
+
+            events.push(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::{client::CursorData, cursor::Position, Cursor};
+
+    use super::*;
+
+    fn cursor_events(events: impl IntoIterator<Item = fn(CursorData) -> CursorEvent>, x: f32) -> TuioEvents {
+        TuioEvents {
+            cursor_events: events.into_iter()
+                .map(|event| event(CursorData { source_name: "test".into(), time: SystemTime::now(), fseq: 1, cursor: Cursor::new(1, Position { x, y: 0.5 }) }))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn positions(events: &TuioEvents) -> Vec<(&'static str, f32)> {
+        events.cursor_events.iter().map(|event| match event {
+            CursorEvent::New(data) => ("new", data.cursor.get_x_position()),
+            CursorEvent::Update(data) => ("update", data.cursor.get_x_position()),
+            CursorEvent::Remove(data) => ("remove", data.cursor.get_x_position()),
+        }).collect()
+    }
+
+    #[test]
+    fn coalesce_updates() {
+        let mut coalescer = UpdateCoalescer::new(Duration::from_millis(100));
+        let start = Instant::now();
+
+        let mut events = cursor_events([CursorEvent::New as fn(_) -> _], 0.1);
+        assert!(coalescer.apply(&mut events, start));
+        assert_eq!(positions(&events), vec![("new", 0.1)]);
+
+        let mut events = cursor_events([CursorEvent::Update as fn(_) -> _], 0.2);
+        assert!(!coalescer.apply(&mut events, start + Duration::from_millis(40)));
+
+        let mut events = cursor_events([CursorEvent::Update as fn(_) -> _], 0.3);
+        assert!(!coalescer.apply(&mut events, start + Duration::from_millis(80)));
+
+        let mut events = TuioEvents::default();
+        assert!(coalescer.apply(&mut events, start + Duration::from_millis(100)));
+        assert_eq!(positions(&events), vec![("update", 0.3)]);
+
+        let mut events = cursor_events([CursorEvent::Update as fn(_) -> _], 0.4);
+        assert!(!coalescer.apply(&mut events, start + Duration::from_millis(150)));
+
+        let mut events = cursor_events([CursorEvent::Remove as fn(_) -> _], 0.5);
+        assert!(coalescer.apply(&mut events, start + Duration::from_millis(160)));
+        assert_eq!(positions(&events), vec![("remove", 0.5)]);
+
+        let mut events = TuioEvents::default();
+        assert!(!coalescer.apply(&mut events, start + Duration::from_secs(1)));
+    }
+}
@@ -0,0 +1,69 @@
+/// A single value of a [Control], such as the state of a button or the position of a slider
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ControlValue {
+    Bool(bool),
+    /// A normalized value, between -1 and 1 for a bipolar control or between 0 and 1 otherwise
+    Float(f32),
+}
+
+impl ControlValue {
+    /// Returns the value as a float, 1 for a pressed button and 0 for a released one
+    pub fn as_f32(&self) -> f32 {
+        match self {
+            ControlValue::Bool(value) => f32::from(u8::from(*value)),
+            ControlValue::Float(value) => *value,
+        }
+    }
+}
+
+/// The values of the tangible widgets of a component, such as the buttons and sliders of a token, as sent by the TUIO 2.0 control message
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Control {
+    pub(crate) session_id: i32,
+    pub(crate) values: Vec<ControlValue>,
+}
+
+impl Control {
+    /// Creates a new [Control]
+    /// # Arguments
+    /// * `session_id` - the session ID of the component carrying the controls
+    /// * `values` - the [ControlValue]s, in the order of the widgets
+    pub fn new(session_id: i32, values: Vec<ControlValue>) -> Self {
+        Self {
+            session_id,
+            values,
+        }
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    /// Returns the [ControlValue]s, in the order of the widgets
+    pub fn get_values(&self) -> &[ControlValue] {
+        &self.values
+    }
+
+    /// Returns the [ControlValue] of a widget, or [None] if the index is out of range
+    /// # Arguments
+    /// * `index` - the index of the widget
+    pub fn get_value(&self, index: usize) -> Option<ControlValue> {
+        self.values.get(index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::control::{Control, ControlValue};
+
+    #[test]
+    fn control_values() {
+        let control = Control::new(4, vec![ControlValue::Bool(true), ControlValue::Float(-0.5), ControlValue::Bool(false)]);
+
+        assert_eq!(control.get_values().iter().map(ControlValue::as_f32).collect::<Vec<_>>(), vec![1., -0.5, 0.]);
+        assert_eq!(control.get_value(1), Some(ControlValue::Float(-0.5)));
+        assert_eq!(control.get_value(3), None);
+    }
+}
@@ -1,6 +1,7 @@
-use std::time::Duration;
+use std::{f32::consts::{PI, TAU}, fmt, ops::{Add, Mul, Sub}, time::{Duration, Instant}};
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     pub x: f32,
     pub y: f32,
@@ -8,30 +9,378 @@ pub struct Position {
 
 impl Position {
     pub fn distance_from(&self, position: &Position) -> f32 {
-        let dx = self.x - position.x;
-        let dy = self.y - position.y;
-        (dx * dx + dy * dy).sqrt()
+        self.distance_squared_from(position).sqrt()
+    }
+
+    /// Returns the squared distance to another [Position], cheaper than [Position::distance_from] when only comparing distances
+    /// # Arguments
+    /// * `other` - the other [Position]
+    pub fn distance_squared_from(&self, other: &Position) -> f32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        dx * dx + dy * dy
+    }
+
+    /// Returns the bearing from this [Position] to another one in radians, between 0 and 2π
+    ///
+    /// 0 points along the x axis and the angle grows towards the y axis, clockwise on screen since y points down. Returns 0 for the same [Position]
+    /// # Arguments
+    /// * `other` - the [Position] to aim at
+    pub fn angle_to(&self, other: &Position) -> f32 {
+        normalize_angle((other.y - self.y).atan2(other.x - self.x))
+    }
+
+    /// Returns the [Position] halfway between this [Position] and another one
+    /// # Arguments
+    /// * `other` - the other [Position]
+    pub fn midpoint(&self, other: &Position) -> Position {
+        self.lerp(other, 0.5)
+    }
+
+    /// Returns the linear interpolation between this [Position] and another one
+    /// # Arguments
+    /// * `other` - the [Position] reached when `t` is 1
+    /// * `t` - the interpolation factor, 0 returns this [Position]
+    pub fn lerp(&self, other: &Position, t: f32) -> Position {
+        *self + (*other - *self) * t
+    }
+
+    /// Returns the pixel containing this [Position] in screen space
+    ///
+    /// Coordinates are truncated like [crate::Blob::get_pixel_width], and clamped so 1 maps to the last pixel
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn to_pixel(&self, screen_width: u16, screen_height: u16) -> PixelPosition {
+        PixelPosition {
+            x: ((self.x * screen_width as f32) as u16).min(screen_width.saturating_sub(1)),
+            y: ((self.y * screen_height as f32) as u16).min(screen_height.saturating_sub(1)),
+        }
     }
 }
 
+/// A position in screen space
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PixelPosition {
+    pub x: u16,
+    pub y: u16,
+}
+
+/// A rectangle of a canvas in pixels, such as the region of a display wall covered by a single tracker
 #[derive(Default, PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PixelRect {
+    /// The left edge in pixels
+    pub x: f32,
+    /// The top edge in pixels
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl PixelRect {
+    /// Returns the pixel coordinates of a normalized [Position] within this [PixelRect], without clamping
+    /// # Arguments
+    /// * `position` - a normalized [Position]
+    pub fn map(&self, position: &Position) -> (f32, f32) {
+        (self.x + position.x * self.width, self.y + position.y * self.height)
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+
+    fn add(self, other: Position) -> Position {
+        Position { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Position {
+    type Output = Position;
+
+    fn sub(self, other: Position) -> Position {
+        Position { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Mul<f32> for Position {
+    type Output = Position;
+
+    fn mul(self, factor: f32) -> Position {
+        Position { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Velocity {
     pub x: f32,
     pub y: f32,
 }
 
 impl Velocity {
+    /// Returns the speed, the magnitude of this [Velocity]
     pub fn get_speed(&self) -> f32 {
+        self.magnitude()
+    }
+
+    /// Returns the length of this [Velocity]
+    pub fn magnitude(&self) -> f32 {
         (self.x * self.x + self.y * self.y).sqrt()
     }
+
+    /// Returns the heading of this [Velocity] in radians, between 0 and 2π, measured like [Position::angle_to]
+    ///
+    /// A zero [Velocity] has no heading and returns 0
+    pub fn direction(&self) -> f32 {
+        normalize_angle(self.y.atan2(self.x))
+    }
+
+    /// Returns the unit [Velocity] pointing in the same direction, or a zero [Velocity] if this one is zero
+    pub fn normalized(&self) -> Velocity {
+        let magnitude = self.magnitude();
+
+        if magnitude > 0. {
+            *self * (1. / magnitude)
+        }
+        else {
+            Velocity::default()
+        }
+    }
+
+    /// Returns this [Velocity] in pixels per second in screen space
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn to_pixels(&self, screen_width: u16, screen_height: u16) -> Velocity {
+        Velocity { x: self.x * f32::from(screen_width), y: self.y * f32::from(screen_height) }
+    }
+
+    /// Returns this [Velocity] exponentially smoothed against a previous [Velocity]
+    pub(crate) fn smooth(&self, previous: &Velocity, alpha: f32) -> Velocity {
+        Velocity {
+            x: smooth(self.x, previous.x, alpha),
+            y: smooth(self.y, previous.y, alpha),
+        }
+    }
+
+    /// Returns the linear interpolation between this [Velocity] and another one
+    /// # Arguments
+    /// * `other` - the [Velocity] reached when `t` is 1
+    /// * `t` - the interpolation factor, 0 returns this [Velocity]
+    pub fn lerp(&self, other: &Velocity, t: f32) -> Velocity {
+        *self + (*other - *self) * t
+    }
+}
+
+impl Add for Velocity {
+    type Output = Velocity;
+
+    fn add(self, other: Velocity) -> Velocity {
+        Velocity { x: self.x + other.x, y: self.y + other.y }
+    }
 }
 
+impl Sub for Velocity {
+    type Output = Velocity;
+
+    fn sub(self, other: Velocity) -> Velocity {
+        Velocity { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Mul<f32> for Velocity {
+    type Output = Velocity;
+
+    fn mul(self, factor: f32) -> Velocity {
+        Velocity { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+/// The shortest delta time for which the `update` methods of TUIO inputs compute motion, see [crate::Server::set_min_delta_time]
+pub const DEFAULT_MIN_DELTA_TIME: Duration = Duration::from_millis(1);
+
+/// The state of a TUIO input its next motion is measured from
+///
+/// Updates closer than the minimum delta time move the input but keep its previous motion,
+/// so the next motion is measured over the whole time since the last update which computed motion
 #[derive(Debug, Clone, Default)]
+pub(crate) struct MotionOrigin<T> {
+    state: Option<T>,
+    elapsed: Duration,
+}
+
+impl<T: Clone> MotionOrigin<T> {
+    /// Returns the state and the delta time in seconds to compute the motion of an update from,
+    /// or [None] if less than the minimum delta time elapsed since the last update which computed motion
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `current` - the state of the input before the update
+    pub(crate) fn advance(&mut self, delta_time: Duration, min_delta_time: Duration, current: &T) -> Option<(T, f32)> {
+        self.elapsed += delta_time;
+        let origin = self.state.get_or_insert_with(|| current.clone()).clone();
+
+        if self.elapsed < min_delta_time {
+            return None;
+        }
+
+        self.state = None;
+        Some((origin, std::mem::take(&mut self.elapsed).as_secs_f32()))
+    }
+}
+
+/// Returns an angle in radians normalized between 0 included and 2π excluded, or 0 if it is not finite
+///
+/// The remainder is computed in double precision so an angle already in range is returned unchanged
+pub(crate) fn normalize_angle(angle: f32) -> f32 {
+    if !angle.is_finite() {
+        return 0.;
+    }
+
+    let normalized = (angle as f64).rem_euclid(std::f64::consts::TAU) as f32;
+
+    if normalized >= TAU {
+        0.
+    }
+    else {
+        normalized
+    }
+}
+
+/// Returns the signed difference between two angles in radians, normalized between -π and π
+pub(crate) fn angle_difference(angle: f32, previous: f32) -> f32 {
+    let difference = (angle - previous).rem_euclid(TAU);
+
+    if difference > PI {
+        difference - TAU
+    }
+    else {
+        difference
+    }
+}
+
+/// Returns the [Velocity] and the acceleration of an input moving from `previous` to `position` in `delta_time` seconds
+pub(crate) fn linear_motion(position: &Position, previous: &Position, previous_velocity: &Velocity, delta_time: f32) -> (Velocity, f32) {
+    let velocity = Velocity {
+        x: (position.x - previous.x) / delta_time,
+        y: (position.y - previous.y) / delta_time,
+    };
+
+    let speed = position.distance_from(previous) / delta_time;
+    (velocity, (speed - previous_velocity.get_speed()) / delta_time)
+}
+
+/// Returns the [Position] of an input extrapolated `ahead` in time from its [Velocity]
+///
+/// With an acceleration, the speed also changes along the direction of motion, stopping rather than reversing when it decelerates
+pub(crate) fn predict_position(position: &Position, velocity: &Velocity, acceleration: Option<f32>, ahead: Duration) -> Position {
+    let ahead = ahead.as_secs_f32();
+    let speed = velocity.get_speed();
+
+    match acceleration {
+        Some(acceleration) if speed > 0. => {
+            let ahead = if acceleration < 0. { ahead.min(speed / -acceleration) } else { ahead };
+            let distance = speed * ahead + 0.5 * acceleration * ahead * ahead;
+
+            Position { x: position.x + velocity.x / speed * distance, y: position.y + velocity.y / speed * distance }
+        },
+        _ => Position { x: position.x + velocity.x * ahead, y: position.y + velocity.y * ahead },
+    }
+}
+
+/// Returns the rotation speed in turns per second of an angle in radians changing from `previous` in `delta_time` seconds
+pub(crate) fn rotation_speed(angle: f32, previous: f32, delta_time: f32) -> f32 {
+    angle_difference(angle, previous) / TAU / delta_time
+}
+
+/// Returns the rotation speed in turns per second and the rotation acceleration in turns per second squared
+/// of an angle in radians changing from `previous` in `delta_time` seconds
+pub(crate) fn rotation_motion(angle: f32, previous: f32, previous_rotation_speed: f32, delta_time: f32) -> (f32, f32) {
+    let rotation_speed = rotation_speed(angle, previous, delta_time);
+    (rotation_speed, (rotation_speed - previous_rotation_speed) / delta_time)
+}
+
+/// Exponentially smooths a value with `alpha * value + (1 - alpha) * previous`
+pub(crate) fn smooth(value: f32, previous: f32, alpha: f32) -> f32 {
+    alpha * value + (1. - alpha) * previous
+}
+
+/// The creation time, the last time seen, the number of updates and the first frame flag of a TUIO input
+///
+/// The times are given by the [crate::clock::Clock] of the [crate::Client] or [crate::Server] tracking the input
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Lifetime {
+    created_at: Instant,
+    seen_at: Instant,
+    update_count: u32,
+    new_this_frame: bool,
+}
+
+impl Default for Lifetime {
+    fn default() -> Self {
+        Self::new(Instant::now())
+    }
+}
+
+impl Lifetime {
+    /// Creates a [Lifetime] starting at an [Instant]
+    /// # Arguments
+    /// * `now` - the creation [Instant]
+    pub(crate) fn new(now: Instant) -> Self {
+        Self { created_at: now, seen_at: now, update_count: 0, new_this_frame: false }
+    }
+
+    /// Returns the time elapsed between the creation and the last time seen
+    pub(crate) fn age(&self) -> Duration {
+        self.seen_at.saturating_duration_since(self.created_at)
+    }
+
+    pub(crate) fn update_count(&self) -> u32 {
+        self.update_count
+    }
+
+    pub(crate) fn is_new_this_frame(&self) -> bool {
+        self.new_this_frame
+    }
+
+    /// Returns this [Lifetime] with one more update, which always happens after the first frame
+    pub(crate) fn updated(self) -> Self {
+        Self { update_count: self.update_count.saturating_add(1), new_this_frame: false, ..self }
+    }
+
+    /// Returns this [Lifetime] last seen at an [Instant]
+    /// # Arguments
+    /// * `now` - the [Instant] of the frame the input is part of
+    pub(crate) fn seen(self, now: Instant) -> Self {
+        Self { seen_at: now, ..self }
+    }
+
+    /// Returns this [Lifetime] flagged as new in the current frame
+    pub(crate) fn first_frame(self) -> Self {
+        Self { new_this_frame: true, ..self }
+    }
+
+    /// Returns this [Lifetime] with the first frame flag cleared by a following frame
+    pub(crate) fn next_frame(self) -> Self {
+        Self { new_this_frame: false, ..self }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cursor {
     pub(crate) session_id: i32,
     pub(crate) position: Position,
     pub(crate) velocity: Velocity,
     pub(crate) acceleration: f32,
+    pub(crate) extra_args: Vec<f32>,
+    pub(crate) source: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) lifetime: Lifetime,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<Position>,
 }
 
 impl Cursor {
@@ -45,6 +394,10 @@ impl Cursor {
             position,
             velocity: Velocity::default(),
             acceleration: 0f32,
+            extra_args: Vec::new(),
+            source: None,
+            lifetime: Lifetime::default(),
+            motion_origin: MotionOrigin::default(),
         }
     }
 
@@ -90,44 +443,130 @@ impl Cursor {
         self.acceleration
     }
 
-    /// Updates the [Cursor], computing its velocity and acceleration
+    /// Returns the name of the source which sent this [Cursor], or [None] if it was not received or its bundle had no `source` message
+    pub fn get_source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the float arguments following the standard ones in the received `set` message, such as vendor-specific values of extended trackers
+    pub fn get_extra_args(&self) -> &[f32] {
+        &self.extra_args
+    }
+
+    /// Returns the time elapsed between the creation or first reception of this [Cursor] and the last frame it was part of,
+    /// measured by the [crate::clock::Clock] of the [crate::Client] or [crate::Server] tracking it
+    pub fn age(&self) -> Duration {
+        self.lifetime.age()
+    }
+
+    /// Returns the number of updates of this [Cursor] since it was created or first received
+    pub fn update_count(&self) -> u32 {
+        self.lifetime.update_count()
+    }
+
+    /// Returns true if this [Cursor] first appeared in the last frame received from its source, which lets a polling application detect new inputs without the add events
+    pub fn is_new_this_frame(&self) -> bool {
+        self.lifetime.is_new_this_frame()
+    }
+
+    /// Returns the position extrapolated from the velocity, to render smooth motion between two frames
+    /// # Arguments
+    /// * `ahead` - the [Duration] since the last update
+    pub fn predicted_position(&self, ahead: Duration) -> Position {
+        predict_position(&self.position, &self.velocity, None, ahead)
+    }
+
+    /// Returns the position extrapolated from the velocity and the acceleration along the direction of motion
+    /// # Arguments
+    /// * `ahead` - the [Duration] since the last update
+    pub fn predicted_position_with_acceleration(&self, ahead: Duration) -> Position {
+        predict_position(&self.position, &self.velocity, Some(self.acceleration), ahead)
+    }
+
+    /// Returns the position in screen space
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn get_pixel_position(&self, screen_width: u16, screen_height: u16) -> PixelPosition {
+        self.position.to_pixel(screen_width, screen_height)
+    }
+
+    /// Returns the speed in pixels per second in screen space
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn get_pixel_speed(&self, screen_width: u16, screen_height: u16) -> f32 {
+        self.velocity.to_pixels(screen_width, screen_height).get_speed()
+    }
+
+    /// Updates the [Cursor], computing its velocity and acceleration once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
     /// # Arguments
     /// * `delta_time` - the [Duration] since last update
     /// * `position` - the new [Position]
     pub fn update(&mut self, delta_time: Duration, position: Position) {
-        let delta_time = delta_time.as_secs_f32();
-        let distance = position.distance_from(&self.position);
-        let delta_x = position.x - self.position.x;
-        let delta_y = position.y - self.position.y;
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position);
+    }
 
-        let last_speed = self.velocity.get_speed();
-        let speed = distance / delta_time;
+    /// Updates the [Cursor], computing its velocity and acceleration once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// Closer updates move the [Cursor] but keep its previous velocity and acceleration instead of producing a spike
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position]
+    pub fn update_with_min_delta_time(&mut self, delta_time: Duration, min_delta_time: Duration, position: Position) {
+        self.lifetime = self.lifetime.updated();
 
-        self.velocity = Velocity {
-            x: delta_x / delta_time,
-            y: delta_y / delta_time,
-        };
+        if let Some((previous, delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &self.position) {
+            (self.velocity, self.acceleration) = linear_motion(&position, &previous, &self.velocity, delta_time);
+        }
 
-        self.acceleration = (speed - last_speed) / delta_time;
         self.position = position;
     }
+
+    /// Keeps the position, velocity and acceleration of the previous state of this [Cursor] if it moved less than a dead-band
+    /// # Arguments
+    /// * `previous` - the previous state of this [Cursor]
+    /// * `deadband` - the minimum normalized distance of a position change, 0 keeps every change
+    pub(crate) fn hold_position(&mut self, previous: &Cursor, deadband: f32) {
+        if self.position.distance_from(&previous.position) < deadband {
+            self.position = previous.position;
+            self.velocity = previous.velocity;
+            self.acceleration = previous.acceleration;
+        }
+    }
+
+    /// Low-pass filters the velocity and acceleration of this [Cursor] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Cursor]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Cursor, alpha: f32) {
+        self.velocity = self.velocity.smooth(&previous.velocity, alpha);
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+    }
 }
 
 impl PartialEq for Cursor {
     fn eq(&self, other: &Self) -> bool {
         self.session_id == other.session_id
             && self.get_x_position() == other.get_x_position()
-            && self.get_x_position() == other.get_y_position()
+            && self.get_y_position() == other.get_y_position()
             && self.velocity == other.velocity
             && self.acceleration == other.acceleration
     }
 }
 
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cursor {} at ({:.3}, {:.3}) moving ({:.3}, {:.3})", self.session_id, self.position.x, self.position.y, self.velocity.x, self.velocity.y)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{f32::consts::SQRT_2, time::Duration};
+    use std::{f32::consts::{FRAC_PI_2, PI, SQRT_2}, time::Duration};
 
-    use crate::cursor::{Cursor, Position};
+    use crate::cursor::{Cursor, PixelPosition, PixelRect, Position, Velocity};
 
     #[test]
     fn cursor_update() {
@@ -140,5 +579,128 @@ mod tests {
         assert_eq!(cursor.get_x_velocity(), 1.);
         assert_eq!(cursor.get_y_velocity(), 1.);
         assert_eq!(cursor.get_acceleration(), SQRT_2);
+        assert_eq!(cursor.update_count(), 1);
+    }
+
+    #[test]
+    fn pixel_position() {
+        let cursor = Cursor::new(0, Position { x: 0.5, y: 0.25 });
+        assert_eq!(cursor.get_pixel_position(1920, 1080), PixelPosition { x: 960, y: 270 });
+
+        assert_eq!(Position { x: 1., y: 1. }.to_pixel(1920, 1080), PixelPosition { x: 1919, y: 1079 });
+        assert_eq!(Position { x: 0., y: 0. }.to_pixel(1920, 1080), PixelPosition { x: 0, y: 0 });
+        assert_eq!(Position { x: 0.99999, y: 0.3 }.to_pixel(100, 10), PixelPosition { x: 99, y: 3 });
+    }
+
+    #[test]
+    fn pixel_velocity() {
+        let cursor = Cursor::new(0, Position { x: 0.5, y: 0.5 }).with_motion(Velocity { x: 0.1, y: 0.25 }, 0.);
+
+        assert_eq!(cursor.get_velocity().to_pixels(1920, 1080), Velocity { x: 192., y: 270. });
+        assert_eq!(cursor.get_pixel_speed(1920, 1080), 192f32.hypot(270.));
+        assert_eq!(Velocity::default().to_pixels(1920, 1080).get_speed(), 0.);
+    }
+
+    #[test]
+    fn pixel_rect() {
+        let rect = PixelRect { x: 1920., y: 0., width: 1920., height: 1080. };
+
+        assert_eq!(rect.map(&Position { x: 0., y: 0. }), (1920., 0.));
+        assert_eq!(rect.map(&Position { x: 0.5, y: 0.25 }), (2880., 270.));
+        assert_eq!(rect.map(&Position { x: 1., y: 1. }), (3840., 1080.));
+    }
+
+    #[test]
+    fn distance() {
+        let a = Position { x: 0.1, y: 0.2 };
+        let b = Position { x: 0.4, y: 0.6 };
+
+        assert!((a.distance_squared_from(&b) - 0.25).abs() < 1e-6);
+        assert!((a.distance_from(&b) - 0.5).abs() < 1e-6);
+        assert_eq!(a.distance_squared_from(&a), 0.);
+    }
+
+    #[test]
+    fn velocity_vector() {
+        let velocity = Velocity { x: 0., y: -0.5 };
+
+        assert_eq!(velocity.magnitude(), 0.5);
+        assert_eq!(velocity.get_speed(), 0.5);
+        assert_eq!(velocity.direction(), 3. * FRAC_PI_2);
+        assert_eq!(velocity.normalized(), Velocity { x: 0., y: -1. });
+        assert!((Velocity { x: 3., y: 4. }.normalized().magnitude() - 1.).abs() < 1e-6);
+
+        assert_eq!(Velocity::default().magnitude(), 0.);
+        assert_eq!(Velocity::default().direction(), 0.);
+        assert_eq!(Velocity::default().normalized(), Velocity::default());
+    }
+
+    #[test]
+    fn bearing() {
+        let center = Position { x: 0.5, y: 0.5 };
+
+        assert_eq!(center.angle_to(&Position { x: 0.9, y: 0.5 }), 0.);
+        assert_eq!(center.angle_to(&Position { x: 0.5, y: 0.9 }), FRAC_PI_2);
+        assert_eq!(center.angle_to(&Position { x: 0.1, y: 0.5 }), PI);
+        assert_eq!(center.angle_to(&Position { x: 0.5, y: 0.1 }), 3. * FRAC_PI_2);
+        assert_eq!(center.angle_to(&center), 0.);
+
+        assert_eq!(Position { x: 0.25, y: 0.5 }.midpoint(&Position { x: 0.75, y: 1. }), Position { x: 0.5, y: 0.75 });
+    }
+
+    #[test]
+    fn prediction() {
+        let cursor = Cursor::new(0, Position { x: 0.5, y: 0.5 }).with_motion(Velocity { x: 0.3, y: -0.4 }, -1.);
+
+        assert_eq!(cursor.predicted_position(Duration::ZERO), Position { x: 0.5, y: 0.5 });
+        assert_eq!(cursor.predicted_position(Duration::from_millis(500)), Position { x: 0.65, y: 0.3 });
+
+        // Decelerating at 1/s² from 0.5/s travels 0.125 before stopping, whatever the prediction horizon
+        let predicted = cursor.predicted_position_with_acceleration(Duration::from_secs(2));
+        assert!((predicted.x - 0.575).abs() < 1e-6 && (predicted.y - 0.4).abs() < 1e-6);
+
+        let still = Cursor::new(0, Position { x: 0.5, y: 0.5 }).with_motion(Velocity::default(), 1.);
+        assert_eq!(still.predicted_position_with_acceleration(Duration::from_secs(1)), Position { x: 0.5, y: 0.5 });
+    }
+
+    #[test]
+    fn interpolation() {
+        let from = Position { x: 0., y: 1. };
+        let to = Position { x: 1., y: 0. };
+
+        assert_eq!(from.lerp(&to, 0.), from);
+        assert_eq!(from.lerp(&to, 0.5), Position { x: 0.5, y: 0.5 });
+        assert_eq!(from.lerp(&to, 1.), to);
+        assert_eq!(from + to, Position { x: 1., y: 1. });
+        assert_eq!(to - from, Position { x: 1., y: -1. });
+        assert_eq!(to * 2., Position { x: 2., y: 0. });
+
+        let velocity = Velocity { x: 1., y: 2. };
+        assert_eq!(Velocity::default().lerp(&velocity, 0.5), Velocity { x: 0.5, y: 1. });
+        assert_eq!(velocity + velocity - velocity * 0.5, Velocity { x: 1.5, y: 3. });
+    }
+
+    #[test]
+    fn tiny_delta_time() {
+        let mut cursor = Cursor::new(0, Position { x: 0., y: 0. });
+
+        cursor.update(Duration::from_secs(1), Position { x: 0.1, y: 0. });
+        cursor.update(Duration::from_micros(100), Position { x: 0.2, y: 0. });
+
+        assert_eq!(cursor.get_x_position(), 0.2);
+        assert!(cursor.get_velocity().get_speed().is_finite());
+        assert!(cursor.get_velocity().get_speed() <= 0.1);
+        assert!(cursor.get_acceleration().abs() <= 0.1);
+
+        // The next motion is measured from the position of the last update which computed motion
+        cursor.update(Duration::from_micros(900), Position { x: 0.3, y: 0. });
+        assert!((cursor.get_x_velocity() - 200.).abs() < 0.1);
+
+        let mut cursor = Cursor::new(0, Position { x: 0., y: 0. });
+        cursor.update_with_min_delta_time(Duration::from_millis(10), Duration::from_millis(20), Position { x: 0.1, y: 0. });
+        assert_eq!(cursor.get_velocity(), &Velocity::default());
+
+        cursor.update_with_min_delta_time(Duration::from_millis(10), Duration::from_millis(20), Position { x: 0.2, y: 0. });
+        assert!((cursor.get_x_velocity() - 10.).abs() < 0.001);
     }
 }
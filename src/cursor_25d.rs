@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use crate::{cursor::{smooth, MotionOrigin, DEFAULT_MIN_DELTA_TIME}, cursor_3d::{Position3D, Velocity3D}};
+
+/// A 2.5D cursor, a [crate::Cursor] hovering at a height above the surface
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cursor25D {
+    pub(crate) session_id: i32,
+    pub(crate) position: Position3D,
+    pub(crate) velocity: Velocity3D,
+    pub(crate) acceleration: f32,
+    pub(crate) extra_args: Vec<f32>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<Position3D>,
+}
+
+impl Cursor25D {
+    /// Creates a new [Cursor25D]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `position` - a normalized [Position3D], whose z is the height above the surface
+    pub fn new(session_id: i32, position: Position3D) -> Self {
+        Self {
+            session_id,
+            position,
+            velocity: Velocity3D::default(),
+            acceleration: 0f32,
+            extra_args: Vec::new(),
+            motion_origin: MotionOrigin::default(),
+        }
+    }
+
+    /// Returns this [Cursor25D] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity3D]
+    /// * `acceleration` - a normalized acceleration
+    pub fn with_motion(mut self, velocity: Velocity3D, acceleration: f32) -> Self {
+        self.velocity = velocity;
+        self.acceleration = acceleration;
+        self
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    /// Returns the float arguments following the standard ones in the received `set` message, such as vendor-specific values of extended trackers
+    pub fn get_extra_args(&self) -> &[f32] {
+        &self.extra_args
+    }
+
+    pub fn get_position(&self) -> &Position3D {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    /// Returns the normalized height above the surface
+    pub fn get_z_position(&self) -> f32 {
+        self.position.z
+    }
+
+    pub fn get_velocity(&self) -> &Velocity3D {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    /// Returns the vertical velocity, negative while the cursor approaches the surface
+    pub fn get_z_velocity(&self) -> f32 {
+        self.velocity.z
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns true if the cursor moves toward the surface
+    pub fn is_approaching(&self) -> bool {
+        self.velocity.z < 0.
+    }
+
+    /// Returns true if the cursor moves away from the surface
+    pub fn is_retreating(&self) -> bool {
+        self.velocity.z > 0.
+    }
+
+    /// Updates the [Cursor25D], computing its velocity and acceleration once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
+    ///
+    /// The acceleration only accounts for the motion along the surface, so approaching or retreating does not change it
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position3D]
+    pub fn update(&mut self, delta_time: Duration, position: Position3D) {
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position);
+    }
+
+    /// Updates the [Cursor25D], computing its velocity and acceleration once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// The acceleration only accounts for the motion along the surface, so approaching or retreating does not change it
+    /// Closer updates keep the previous motion instead of producing a spike, while the other values are still updated
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position3D]
+    pub fn update_with_min_delta_time(&mut self, delta_time: Duration, min_delta_time: Duration, position: Position3D) {
+        if let Some((previous, delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &self.position) {
+            let last_speed = (self.velocity.x * self.velocity.x + self.velocity.y * self.velocity.y).sqrt();
+
+            self.velocity = Velocity3D {
+                x: (position.x - previous.x) / delta_time,
+                y: (position.y - previous.y) / delta_time,
+                z: (position.z - previous.z) / delta_time,
+            };
+
+            let speed = (self.velocity.x * self.velocity.x + self.velocity.y * self.velocity.y).sqrt();
+
+            self.acceleration = (speed - last_speed) / delta_time;
+        }
+
+        self.position = position;
+    }
+
+    /// Low-pass filters the velocity and acceleration of this [Cursor25D] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Cursor25D]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Cursor25D, alpha: f32) {
+        self.velocity = Velocity3D {
+            x: smooth(self.velocity.x, previous.velocity.x, alpha),
+            y: smooth(self.velocity.y, previous.velocity.y, alpha),
+            z: smooth(self.velocity.z, previous.velocity.z, alpha),
+        };
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+    }
+}
+
+impl PartialEq for Cursor25D {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.get_z_position() == other.get_z_position()
+            && self.velocity == other.velocity
+            && self.acceleration == other.acceleration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::SQRT_2, time::Duration};
+
+    use crate::{cursor_25d::Cursor25D, cursor_3d::Position3D};
+
+    #[test]
+    fn cursor_25d_update() {
+        let mut cursor = Cursor25D::new(0, Position3D { x: 0., y: 0., z: 0.5 });
+
+        cursor.update(Duration::from_secs(1), Position3D { x: 1., y: 1., z: 0.25 });
+
+        assert_eq!(cursor.get_x_velocity(), 1.);
+        assert_eq!(cursor.get_y_velocity(), 1.);
+        assert_eq!(cursor.get_z_velocity(), -0.25);
+        assert_eq!(cursor.get_acceleration(), SQRT_2);
+        assert!(cursor.is_approaching());
+
+        cursor.update(Duration::from_secs(1), Position3D { x: 2., y: 2., z: 0.5 });
+
+        assert_eq!(cursor.get_acceleration(), 0.);
+        assert!(cursor.is_retreating());
+    }
+}
@@ -0,0 +1,251 @@
+use std::{fmt, io::{self, Write}, net::SocketAddr, sync::{Arc, Mutex}, thread, time::{Duration, Instant}};
+
+use crate::{client::{Client, Listener}, errors::TuioError, Cursor, Object, Blob, Cursor3D, Cursor25D, Blob3D, Token, Pointer, Symbol, Bounds, Geometry, Control};
+
+/// The interval between two refreshes of the [Client] of [print_stream]
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A [Listener] writing a line per event, with the time elapsed since its creation, the source name and the input
+pub struct StreamPrinter {
+    out: Mutex<Box<dyn Write + Send>>,
+    start: Instant,
+}
+
+impl StreamPrinter {
+    /// Creates a [StreamPrinter] writing to the standard output
+    pub fn new() -> Self {
+        Self::with_writer(io::stdout())
+    }
+
+    /// Creates a [StreamPrinter] writing to any writer, such as a log file
+    ///
+    /// # Arguments
+    /// * `out` - the [Write] receiving the lines
+    pub fn with_writer(out: impl Write + Send + 'static) -> Self {
+        Self {
+            out: Mutex::new(Box::new(out)),
+            start: Instant::now(),
+        }
+    }
+
+    fn print(&self, source_name: &str, event: &str, input: &dyn fmt::Display) {
+        let _ = writeln!(self.out.lock().unwrap(), "[{:>10.3}] {}: {} {}", self.start.elapsed().as_secs_f64(), source_name, event, input);
+    }
+}
+
+impl Default for StreamPrinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Listener for StreamPrinter {
+    fn cursor_added(&self, source_name: &str, cursor: &Cursor) {
+        self.print(source_name, "cursor added", cursor);
+    }
+
+    fn cursor_updated(&self, source_name: &str, cursor: &Cursor) {
+        self.print(source_name, "cursor updated", cursor);
+    }
+
+    fn cursor_removed(&self, source_name: &str, cursor: &Cursor) {
+        self.print(source_name, "cursor removed", cursor);
+    }
+
+    fn object_added(&self, source_name: &str, object: &Object) {
+        self.print(source_name, "object added", object);
+    }
+
+    fn object_updated(&self, source_name: &str, object: &Object) {
+        self.print(source_name, "object updated", object);
+    }
+
+    fn object_removed(&self, source_name: &str, object: &Object) {
+        self.print(source_name, "object removed", object);
+    }
+
+    fn blob_added(&self, source_name: &str, blob: &Blob) {
+        self.print(source_name, "blob added", blob);
+    }
+
+    fn blob_updated(&self, source_name: &str, blob: &Blob) {
+        self.print(source_name, "blob updated", blob);
+    }
+
+    fn blob_removed(&self, source_name: &str, blob: &Blob) {
+        self.print(source_name, "blob removed", blob);
+    }
+
+    fn cursor_3d_added(&self, source_name: &str, cursor: &Cursor3D) {
+        self.print(source_name, "cursor 3D added", &format_args!("{cursor:?}"));
+    }
+
+    fn cursor_3d_updated(&self, source_name: &str, cursor: &Cursor3D) {
+        self.print(source_name, "cursor 3D updated", &format_args!("{cursor:?}"));
+    }
+
+    fn cursor_3d_removed(&self, source_name: &str, cursor: &Cursor3D) {
+        self.print(source_name, "cursor 3D removed", &format_args!("{cursor:?}"));
+    }
+
+    fn cursor_25d_added(&self, source_name: &str, cursor: &Cursor25D) {
+        self.print(source_name, "cursor 2.5D added", &format_args!("{cursor:?}"));
+    }
+
+    fn cursor_25d_updated(&self, source_name: &str, cursor: &Cursor25D) {
+        self.print(source_name, "cursor 2.5D updated", &format_args!("{cursor:?}"));
+    }
+
+    fn cursor_25d_removed(&self, source_name: &str, cursor: &Cursor25D) {
+        self.print(source_name, "cursor 2.5D removed", &format_args!("{cursor:?}"));
+    }
+
+    fn blob_3d_added(&self, source_name: &str, blob: &Blob3D) {
+        self.print(source_name, "blob 3D added", &format_args!("{blob:?}"));
+    }
+
+    fn blob_3d_updated(&self, source_name: &str, blob: &Blob3D) {
+        self.print(source_name, "blob 3D updated", &format_args!("{blob:?}"));
+    }
+
+    fn blob_3d_removed(&self, source_name: &str, blob: &Blob3D) {
+        self.print(source_name, "blob 3D removed", &format_args!("{blob:?}"));
+    }
+
+    fn token_added(&self, source_name: &str, token: &Token) {
+        self.print(source_name, "token added", &format_args!("{token:?}"));
+    }
+
+    fn token_updated(&self, source_name: &str, token: &Token) {
+        self.print(source_name, "token updated", &format_args!("{token:?}"));
+    }
+
+    fn token_removed(&self, source_name: &str, token: &Token) {
+        self.print(source_name, "token removed", &format_args!("{token:?}"));
+    }
+
+    fn pointer_added(&self, source_name: &str, pointer: &Pointer) {
+        self.print(source_name, "pointer added", &format_args!("{pointer:?}"));
+    }
+
+    fn pointer_updated(&self, source_name: &str, pointer: &Pointer) {
+        self.print(source_name, "pointer updated", &format_args!("{pointer:?}"));
+    }
+
+    fn pointer_removed(&self, source_name: &str, pointer: &Pointer) {
+        self.print(source_name, "pointer removed", &format_args!("{pointer:?}"));
+    }
+
+    fn symbol_added(&self, source_name: &str, symbol: &Symbol) {
+        self.print(source_name, "symbol added", &format_args!("{symbol:?}"));
+    }
+
+    fn symbol_updated(&self, source_name: &str, symbol: &Symbol) {
+        self.print(source_name, "symbol updated", &format_args!("{symbol:?}"));
+    }
+
+    fn symbol_removed(&self, source_name: &str, symbol: &Symbol) {
+        self.print(source_name, "symbol removed", &format_args!("{symbol:?}"));
+    }
+
+    fn bounds_added(&self, source_name: &str, bounds: &Bounds) {
+        self.print(source_name, "bounds added", &format_args!("{bounds:?}"));
+    }
+
+    fn bounds_updated(&self, source_name: &str, bounds: &Bounds) {
+        self.print(source_name, "bounds updated", &format_args!("{bounds:?}"));
+    }
+
+    fn bounds_removed(&self, source_name: &str, bounds: &Bounds) {
+        self.print(source_name, "bounds removed", &format_args!("{bounds:?}"));
+    }
+
+    fn geometry_added(&self, source_name: &str, geometry: &Geometry) {
+        self.print(source_name, "geometry added", &format_args!("{geometry:?}"));
+    }
+
+    fn geometry_updated(&self, source_name: &str, geometry: &Geometry) {
+        self.print(source_name, "geometry updated", &format_args!("{geometry:?}"));
+    }
+
+    fn geometry_removed(&self, source_name: &str, geometry: &Geometry) {
+        self.print(source_name, "geometry removed", &format_args!("{geometry:?}"));
+    }
+
+    fn control_added(&self, source_name: &str, control: &Control) {
+        self.print(source_name, "control added", &format_args!("{control:?}"));
+    }
+
+    fn control_updated(&self, source_name: &str, control: &Control) {
+        self.print(source_name, "control updated", &format_args!("{control:?}"));
+    }
+
+    fn control_removed(&self, source_name: &str, control: &Control) {
+        self.print(source_name, "control removed", &format_args!("{control:?}"));
+    }
+}
+
+/// Prints every event received by a [Client] bound to a socket address to the standard output, until the process is interrupted with Ctrl-C
+///
+/// Returns only if the [Client] fails to bind or connect
+///
+/// # Arguments
+/// * `addr` - the local socket address to receive TUIO on, such as 0.0.0.0:3333
+pub fn print_stream(addr: SocketAddr) -> Result<(), TuioError> {
+    let mut client = Client::from_address(addr)?;
+    client.add_listener(Arc::new(StreamPrinter::new()));
+    client.connect()?;
+
+    loop {
+        if let Err(err) = client.refresh() {
+            println!("Error refreshing the client: {}", err);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{loopback::LoopbackTransport, cursor::Position, Client, Cursor, Server};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn stream_printer() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        server.set_source_name("test");
+        let mut client = Client::from_loopback(&transport);
+        let buffer = SharedBuffer::default();
+        client.add_listener(Arc::new(StreamPrinter::with_writer(buffer.clone())));
+
+        server.send_frame(&[Cursor::new(0, Position { x: 0.5, y: 0.25 })], &[], &[]).unwrap();
+        client.refresh().unwrap();
+        server.send_frame(&[], &[], &[]).unwrap();
+        client.refresh().unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let events: Vec<&str> = output.lines().map(|line| line.split_once("] ").unwrap().1).collect();
+
+        assert_eq!(events, vec![
+            "test@local: cursor added cursor 0 at (0.500, 0.250) moving (0.000, 0.000)",
+            "test@local: cursor removed cursor 0 at (0.500, 0.250) moving (0.000, 0.000)",
+        ]);
+    }
+}
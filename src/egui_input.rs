@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use egui::{Event, InputState, Pos2, Rect, TouchPhase, Vec2};
+
+use crate::Server;
+
+/// Maps the pointer and touch input of an [egui] context to the [crate::Cursor]s of a [Server], so an egui application can act as a TUIO source
+///
+/// Every touch creates a cursor when it starts, updates it when it moves and removes it when it ends or is cancelled.
+/// While no touch is active, the mouse creates a cursor when the primary button is pressed and removes it when the button is released.
+/// Positions are normalized by the screen rect of egui.
+/// The input must be handled between [Server::init_frame] and [Server::commit_frame]
+#[derive(Default)]
+pub struct PointerTracker {
+    mouse_session_id: Option<i32>,
+    touch_session_ids: HashMap<(u64, u64), i32>,
+}
+
+impl PointerTracker {
+    /// Creates a [PointerTracker] without any active pointer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates, updates or removes the [crate::Cursor]s of the touches and of the mouse from the input of an egui pass
+    /// # Arguments
+    /// * `server` - the [Server] sending the cursors
+    /// * `input` - the egui [InputState] of the pass, such as the one given by `ctx.input(|input| ...)`
+    pub fn handle_input(&mut self, server: &mut Server, input: &InputState) {
+        let rect = input.viewport_rect();
+        let mut touched = false;
+
+        for event in &input.events {
+            let Event::Touch { device_id, id, phase, pos, .. } = event else {
+                continue;
+            };
+
+            touched = true;
+            let key = (device_id.0, id.0);
+            let (x, y) = normalize(*pos, rect);
+
+            match phase {
+                TouchPhase::Start => {
+                    if let Some(session_id) = self.touch_session_ids.remove(&key) {
+                        server.remove_cursor(session_id);
+                    }
+
+                    self.touch_session_ids.insert(key, server.create_cursor(x, y));
+                },
+                TouchPhase::Move => {
+                    if let Some(session_id) = self.touch_session_ids.get(&key) {
+                        server.update_cursor(*session_id, x, y);
+                    }
+                },
+                TouchPhase::End | TouchPhase::Cancel => {
+                    if let Some(session_id) = self.touch_session_ids.remove(&key) {
+                        server.remove_cursor(session_id);
+                    }
+                },
+            }
+        }
+
+        let mouse_position = input.pointer.interact_pos()
+            .filter(|_| input.pointer.primary_down() && !touched && self.touch_session_ids.is_empty());
+
+        match (self.mouse_session_id, mouse_position) {
+            (None, Some(pos)) => {
+                let (x, y) = normalize(pos, rect);
+                self.mouse_session_id = Some(server.create_cursor(x, y));
+            },
+            (Some(session_id), Some(pos)) => {
+                if input.pointer.delta() != Vec2::ZERO {
+                    let (x, y) = normalize(pos, rect);
+                    server.update_cursor(session_id, x, y);
+                }
+            },
+            (Some(session_id), None) => {
+                server.remove_cursor(session_id);
+                self.mouse_session_id = None;
+            },
+            (None, None) => (),
+        }
+    }
+
+    /// Returns the number of active pointers, counting the mouse while its primary button is pressed
+    pub fn len(&self) -> usize {
+        self.touch_session_ids.len() + usize::from(self.mouse_session_id.is_some())
+    }
+
+    /// Returns true if no pointer is active
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Returns the position of a point relative to a rect, between 0 and 1 inside the rect
+fn normalize(pos: Pos2, rect: Rect) -> (f32, f32) {
+    let size = rect.size().max(Vec2::splat(1.));
+    let relative = pos - rect.min;
+
+    (relative.x / size.x, relative.y / size.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{InputOptions, Modifiers, PointerButton, RawInput, TouchDeviceId, TouchId};
+
+    use crate::{loopback::LoopbackTransport, Client};
+
+    use super::*;
+
+    fn begin_pass(input: InputState, events: Vec<Event>) -> InputState {
+        let raw_input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800., 400.))),
+            events,
+            ..Default::default()
+        };
+
+        input.begin_pass(raw_input, false, 1., InputOptions::default())
+    }
+
+    fn touch(id: u64, phase: TouchPhase, x: f32, y: f32) -> Event {
+        Event::Touch { device_id: TouchDeviceId(0), id: TouchId(id), phase, pos: Pos2::new(x, y), force: None }
+    }
+
+    fn mouse_button(pressed: bool, x: f32, y: f32) -> Event {
+        Event::PointerButton { pos: Pos2::new(x, y), button: PointerButton::Primary, pressed, modifiers: Modifiers::NONE }
+    }
+
+    #[test]
+    fn egui_pointer_lifecycle() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+        let mut tracker = PointerTracker::new();
+        let mut input = InputState::default();
+
+        for (events, expected) in [
+            (vec![touch(7, TouchPhase::Start, 200., 100.)], vec![(0.25, 0.25)]),
+            (vec![touch(7, TouchPhase::Move, 400., 200.), touch(8, TouchPhase::Move, 0., 0.)], vec![(0.5, 0.5)]),
+            (vec![touch(7, TouchPhase::End, 400., 200.)], vec![]),
+            (vec![Event::PointerMoved(Pos2::new(600., 100.)), mouse_button(true, 600., 100.)], vec![(0.75, 0.25)]),
+            (vec![Event::PointerMoved(Pos2::new(200., 300.))], vec![(0.25, 0.75)]),
+            (vec![mouse_button(false, 200., 300.)], vec![]),
+        ] {
+            input = begin_pass(input, events);
+
+            server.init_frame();
+            tracker.handle_input(&mut server, &input);
+            server.commit_frame().unwrap();
+            client.refresh().unwrap();
+
+            let positions: Vec<(f32, f32)> = client.cursors().iter().map(|cursor| (cursor.get_x_position(), cursor.get_y_position())).collect();
+            assert_eq!(positions, expected);
+            assert_eq!(tracker.len(), expected.len());
+        }
+    }
+}
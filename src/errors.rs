@@ -2,8 +2,17 @@ use std::{fmt, io, error};
 
 use rosc::{OscMessage, OscBundle, OscPacket, OscError};
 
+/// The errors of the TUIO [crate::Server] and [crate::Client]
 #[derive(Debug)]
 pub enum TuioError {
+    /// A socket could not be created, connected, read or written
+    Socket(io::Error),
+    /// An OSC packet could not be encoded
+    Encode(OscError),
+    /// Received bytes could not be decoded as an OSC packet
+    Decode(OscError),
+    /// The [crate::Client] is already connected
+    AlreadyConnected,
     UnknownAddress(OscMessage),
     UnknownMessageType(OscMessage),
     EmptyMessage(OscMessage),
@@ -11,12 +20,17 @@ pub enum TuioError {
     MissingArguments(OscMessage),
     WrongArgumentType(OscMessage, u8),
     IncompleteBundle(OscBundle),
+    UnexpectedMessageOrder(OscMessage),
     NotABundle(OscPacket),
 }
 
 impl fmt::Display for TuioError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            TuioError::Socket(err) => write!(f, "socket error: {}", err),
+            TuioError::Encode(err) => write!(f, "error encoding OSC packet: {}", err),
+            TuioError::Decode(err) => write!(f, "error decoding OSC packet: {}", err),
+            TuioError::AlreadyConnected => write!(f, "Client is already connected"),
             TuioError::UnknownAddress(msg) => write!(f, "unknown address: {:?}", msg.addr),
             TuioError::UnknownMessageType(msg) => write!(f, "unknown message type: {:?}", msg),
             TuioError::EmptyMessage(msg) => write!(f, "empty message at: {:?}", msg),
@@ -24,11 +38,39 @@ impl fmt::Display for TuioError {
             TuioError::MissingArguments(msg) => write!(f, "missing one or more arguments at: {:?}", msg),
             TuioError::WrongArgumentType(msg, index) => write!(f, "wrong argument type at index {} in: {:?}", index, msg),
             TuioError::IncompleteBundle(bundle) => write!(f, "missing one or more mandatory messages in: {:?}", bundle),
+            TuioError::UnexpectedMessageOrder(msg) => write!(f, "unexpected message order at: {:?}", msg),
             TuioError::NotABundle(packet) => write!(f, "OSC packet is not a bundle: {:?}", packet),
         }
     }
 }
 
+impl error::Error for TuioError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            TuioError::Socket(err) => Some(err),
+            TuioError::Encode(err) => Some(err),
+            TuioError::Decode(err) => Some(err),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for TuioError {
+    fn from(err: io::Error) -> Self {
+        TuioError::Socket(err)
+    }
+}
+
+impl From<OscReceiverError> for TuioError {
+    fn from(err: OscReceiverError) -> Self {
+        match err {
+            OscReceiverError::Connect(err) | OscReceiverError::Receive(err) => TuioError::Socket(err),
+            OscReceiverError::AlreadyConnected() => TuioError::AlreadyConnected,
+            OscReceiverError::Decode(err) => TuioError::Decode(err),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum OscReceiverError {
     Connect(io::Error),
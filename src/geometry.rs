@@ -0,0 +1,59 @@
+use crate::cursor::Position;
+
+/// The outer contour of a contact, as sent by the TUIO 2.0 outer contour geometry message
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Geometry {
+    pub(crate) session_id: i32,
+    pub(crate) contour: Vec<Position>,
+}
+
+impl Geometry {
+    /// Creates a new [Geometry]
+    /// # Arguments
+    /// * `session_id` - the session ID of the contact
+    /// * `contour` - the normalized [Position]s of the contour points, in drawing order
+    pub fn new(session_id: i32, contour: Vec<Position>) -> Self {
+        Self {
+            session_id,
+            contour,
+        }
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    /// Returns the normalized [Position]s of the contour points, in drawing order
+    pub fn get_contour(&self) -> &[Position] {
+        &self.contour
+    }
+
+    /// Returns the area enclosed by the contour, in normalized units
+    pub fn get_area(&self) -> f32 {
+        let twice_area: f32 = self.contour.iter()
+            .zip(self.contour.iter().cycle().skip(1))
+            .map(|(point, next)| point.x * next.y - next.x * point.y)
+            .sum();
+
+        twice_area.abs() / 2.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cursor::Position, geometry::Geometry};
+
+    #[test]
+    fn contour_area() {
+        let square = Geometry::new(0, vec![
+            Position { x: 0.25, y: 0.25 },
+            Position { x: 0.75, y: 0.25 },
+            Position { x: 0.75, y: 0.75 },
+            Position { x: 0.25, y: 0.75 },
+        ]);
+
+        assert_eq!(square.get_area(), 0.25);
+        assert_eq!(Geometry::new(0, vec![Position { x: 0.5, y: 0.5 }]).get_area(), 0.);
+    }
+}
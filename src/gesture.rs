@@ -0,0 +1,375 @@
+use std::{collections::HashMap, sync::Mutex, time::{Duration, Instant}};
+
+use indexmap::IndexMap;
+
+use crate::{client::Listener, clock::{Clock, SystemClock}, cursor::{Position, angle_difference}, Cursor};
+
+/// The lifetime of a [Cursor] classified by a [GestureRecognizer]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GestureData {
+    pub source_name: String,
+    pub session_id: i32,
+    /// The [Position] where the [Cursor] was added
+    pub start: Position,
+    /// The last [Position] of the [Cursor] before its removal
+    pub end: Position,
+    /// The time between the addition and the removal of the [Cursor]
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gesture {
+    /// A short contact which stayed within the movement epsilon
+    Tap(GestureData),
+    /// A long contact which stayed within the movement epsilon
+    Hold(GestureData),
+    /// A contact which moved beyond the movement epsilon
+    Drag(GestureData),
+}
+
+struct TrackedCursor {
+    start: Position,
+    end: Position,
+    started_at: Instant,
+    moved: bool,
+}
+
+/// A [Listener] classifying the lifetime of every [Cursor] into a [Gesture] once it is removed
+pub struct GestureRecognizer {
+    callback: Box<dyn Fn(Gesture) + Send + Sync>,
+    max_tap_duration: Duration,
+    movement_epsilon: f32,
+    clock: Box<dyn Clock>,
+    cursors: Mutex<HashMap<(String, i32), TrackedCursor>>,
+}
+
+impl GestureRecognizer {
+    /// Creates a [GestureRecognizer] with a maximum tap duration of 300ms and a movement epsilon of 0.01
+    ///
+    /// # Arguments
+    /// * `callback` - called with every recognized [Gesture]
+    pub fn new(callback: impl Fn(Gesture) + Send + Sync + 'static) -> Self {
+        Self {
+            callback: Box::new(callback),
+            max_tap_duration: Duration::from_millis(300),
+            movement_epsilon: 0.01,
+            clock: Box::new(SystemClock),
+            cursors: Mutex::default(),
+        }
+    }
+
+    /// Returns this [GestureRecognizer] with a maximum tap duration, beyond which a still contact is a [Gesture::Hold]
+    ///
+    /// # Arguments
+    /// * `max_tap_duration` - the longest [Duration] of a [Gesture::Tap]
+    pub fn with_max_tap_duration(mut self, max_tap_duration: Duration) -> Self {
+        self.max_tap_duration = max_tap_duration;
+        self
+    }
+
+    /// Returns this [GestureRecognizer] with a movement epsilon, beyond which a contact is a [Gesture::Drag]
+    ///
+    /// # Arguments
+    /// * `movement_epsilon` - the normalized distance from the start [Position] a contact may move while staying still
+    pub fn with_movement_epsilon(mut self, movement_epsilon: f32) -> Self {
+        self.movement_epsilon = movement_epsilon;
+        self
+    }
+
+    /// Returns this [GestureRecognizer] timing the contacts with a [Clock]. Defaults to [SystemClock]
+    ///
+    /// # Arguments
+    /// * `clock` - a [Clock], such as the [crate::clock::TestClock] of a [crate::Client] to classify taps and holds deterministically in tests
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    fn track(&self, source_name: &str, cursor: &Cursor) {
+        let mut cursors = self.cursors.lock().unwrap();
+        let position = *cursor.get_position();
+
+        match cursors.get_mut(&(source_name.to_string(), cursor.get_session_id())) {
+            Some(tracked) => {
+                tracked.moved |= position.distance_squared_from(&tracked.start) > self.movement_epsilon * self.movement_epsilon;
+                tracked.end = position;
+            },
+            None => {
+                cursors.insert((source_name.to_string(), cursor.get_session_id()), TrackedCursor {
+                    start: position,
+                    end: position,
+                    started_at: self.clock.now(),
+                    moved: false,
+                });
+            },
+        }
+    }
+}
+
+impl Listener for GestureRecognizer {
+    fn cursor_added(&self, source_name: &str, cursor: &Cursor) {
+        self.track(source_name, cursor);
+    }
+
+    fn cursor_updated(&self, source_name: &str, cursor: &Cursor) {
+        self.track(source_name, cursor);
+    }
+
+    fn cursor_removed(&self, source_name: &str, cursor: &Cursor) {
+        let tracked = self.cursors.lock().unwrap().remove(&(source_name.to_string(), cursor.get_session_id()));
+
+        if let Some(tracked) = tracked {
+            let data = GestureData {
+                source_name: source_name.to_string(),
+                session_id: cursor.get_session_id(),
+                start: tracked.start,
+                end: tracked.end,
+                duration: self.clock.now().saturating_duration_since(tracked.started_at),
+            };
+
+            let gesture = if tracked.moved {
+                Gesture::Drag(data)
+            }
+            else if data.duration <= self.max_tap_duration {
+                Gesture::Tap(data)
+            }
+            else {
+                Gesture::Hold(data)
+            };
+
+            (self.callback)(gesture);
+        }
+    }
+}
+
+/// The incremental transform of a two-cursor manipulation since the previous one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Manipulation {
+    pub source_name: String,
+    /// The session IDs of the two manipulating [Cursor]s
+    pub session_ids: (i32, i32),
+    /// The middle of the two [Cursor]s
+    pub centroid: Position,
+    /// The displacement of the centroid
+    pub translation: Position,
+    /// The ratio between the new and the previous distance of the two [Cursor]s, above 1 when spreading
+    pub scale: f32,
+    /// The change of the angle between the two [Cursor]s in radians, between -π and π
+    pub rotation: f32,
+}
+
+struct Pair {
+    session_ids: (i32, i32),
+    start_spread: f32,
+    start_angle: f32,
+    started: bool,
+    spread: f32,
+    angle: f32,
+    centroid: Position,
+}
+
+/// Returns the centroid, the distance and the angle between two [Position]s
+fn measure(a: &Position, b: &Position) -> (Position, f32, f32) {
+    (a.midpoint(b), a.distance_from(b), a.angle_to(b))
+}
+
+/// A [Listener] recognizing pinch and rotate gestures from the first two alive [Cursor]s of each source
+///
+/// Once the two [Cursor]s moved beyond the minimum movement, a [Manipulation] is emitted for every update of either of them
+pub struct ManipulationRecognizer {
+    callback: Box<dyn Fn(Manipulation) + Send + Sync>,
+    min_movement: f32,
+    cursors: Mutex<HashMap<String, IndexMap<i32, Position>>>,
+    pairs: Mutex<HashMap<String, Pair>>,
+}
+
+impl ManipulationRecognizer {
+    /// Creates a [ManipulationRecognizer] with a minimum movement of 0.01
+    ///
+    /// # Arguments
+    /// * `callback` - called with every [Manipulation]
+    pub fn new(callback: impl Fn(Manipulation) + Send + Sync + 'static) -> Self {
+        Self {
+            callback: Box::new(callback),
+            min_movement: 0.01,
+            cursors: Mutex::default(),
+            pairs: Mutex::default(),
+        }
+    }
+
+    /// Returns this [ManipulationRecognizer] with a minimum movement before a manipulation starts
+    ///
+    /// # Arguments
+    /// * `min_movement` - the normalized change of distance, or the normalized arc swept by the rotation, between the two [Cursor]s
+    pub fn with_min_movement(mut self, min_movement: f32) -> Self {
+        self.min_movement = min_movement;
+        self
+    }
+
+    fn recognize(&self, source_name: &str) {
+        let cursors = self.cursors.lock().unwrap();
+        let mut pairs = self.pairs.lock().unwrap();
+
+        let (a, b) = match cursors.get(source_name).map(|cursors| (cursors.get_index(0), cursors.get_index(1))) {
+            Some((Some(a), Some(b))) => (a, b),
+            _ => {
+                pairs.remove(source_name);
+                return;
+            }
+        };
+
+        let session_ids = (*a.0, *b.0);
+        let (centroid, spread, angle) = measure(a.1, b.1);
+
+        let pair = match pairs.get_mut(source_name) {
+            Some(pair) if pair.session_ids == session_ids => pair,
+            _ => {
+                pairs.insert(source_name.to_string(), Pair { session_ids, start_spread: spread, start_angle: angle, started: false, spread, angle, centroid });
+                return;
+            }
+        };
+
+        if !pair.started {
+            let arc = angle_difference(angle, pair.start_angle).abs() * spread / 2.;
+
+            if (spread - pair.start_spread).abs() <= self.min_movement && arc <= self.min_movement {
+                return;
+            }
+
+            pair.started = true;
+        }
+
+        let manipulation = Manipulation {
+            source_name: source_name.to_string(),
+            session_ids,
+            centroid,
+            translation: centroid - pair.centroid,
+            scale: if pair.spread > 0. { spread / pair.spread } else { 1. },
+            rotation: angle_difference(angle, pair.angle),
+        };
+
+        pair.spread = spread;
+        pair.angle = angle;
+        pair.centroid = centroid;
+
+        drop(pairs);
+        drop(cursors);
+        (self.callback)(manipulation);
+    }
+}
+
+impl Listener for ManipulationRecognizer {
+    fn cursor_added(&self, source_name: &str, cursor: &Cursor) {
+        self.cursors.lock().unwrap().entry(source_name.to_string()).or_default().insert(cursor.get_session_id(), *cursor.get_position());
+        self.recognize(source_name);
+    }
+
+    fn cursor_updated(&self, source_name: &str, cursor: &Cursor) {
+        self.cursor_added(source_name, cursor);
+    }
+
+    fn cursor_removed(&self, source_name: &str, cursor: &Cursor) {
+        if let Some(cursors) = self.cursors.lock().unwrap().get_mut(source_name) {
+            cursors.shift_remove(&cursor.get_session_id());
+        }
+
+        self.recognize(source_name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::clock::TestClock;
+
+    use super::*;
+
+    #[test]
+    fn tap_hold_drag() {
+        let gestures = Arc::new(Mutex::new(Vec::new()));
+        let clock = TestClock::new();
+        let recognizer = {
+            let gestures = Arc::clone(&gestures);
+            GestureRecognizer::new(move |gesture| gestures.lock().unwrap().push(gesture))
+                .with_max_tap_duration(Duration::from_millis(20))
+                .with_clock(clock.clone())
+        };
+
+        let tap = Cursor::new(0, Position { x: 0.5, y: 0.5 });
+        recognizer.cursor_added("test", &tap);
+        recognizer.cursor_updated("test", &Cursor::new(0, Position { x: 0.505, y: 0.5 }));
+        recognizer.cursor_removed("test", &tap);
+
+        let hold = Cursor::new(1, Position { x: 0.5, y: 0.5 });
+        recognizer.cursor_added("test", &hold);
+        clock.advance(Duration::from_millis(30));
+        recognizer.cursor_removed("test", &hold);
+
+        let drag = Cursor::new(2, Position { x: 0.5, y: 0.5 });
+        recognizer.cursor_added("test", &drag);
+        recognizer.cursor_updated("test", &Cursor::new(2, Position { x: 0.6, y: 0.5 }));
+        recognizer.cursor_updated("test", &Cursor::new(2, Position { x: 0.5, y: 0.5 }));
+        recognizer.cursor_removed("test", &drag);
+
+        let gestures = gestures.lock().unwrap();
+        assert_eq!(gestures.len(), 3);
+        assert!(matches!(&gestures[0], Gesture::Tap(data) if data.session_id == 0 && data.end == Position { x: 0.505, y: 0.5 }));
+        assert!(matches!(&gestures[1], Gesture::Hold(data) if data.session_id == 1 && data.duration == Duration::from_millis(30)));
+        assert!(matches!(&gestures[2], Gesture::Drag(data) if data.session_id == 2 && data.start == Position { x: 0.5, y: 0.5 }));
+    }
+
+    #[test]
+    fn clock_tap_hold() {
+        let gestures = Arc::new(Mutex::new(Vec::new()));
+        let clock = TestClock::new();
+        let recognizer = {
+            let gestures = Arc::clone(&gestures);
+            GestureRecognizer::new(move |gesture| gestures.lock().unwrap().push(gesture)).with_clock(clock.clone())
+        };
+
+        let tap = Cursor::new(0, Position { x: 0.5, y: 0.5 });
+        recognizer.cursor_added("test", &tap);
+        clock.advance(Duration::from_millis(300));
+        recognizer.cursor_removed("test", &tap);
+
+        let hold = Cursor::new(1, Position { x: 0.5, y: 0.5 });
+        recognizer.cursor_added("test", &hold);
+        clock.advance(Duration::from_millis(301));
+        recognizer.cursor_removed("test", &hold);
+
+        let gestures = gestures.lock().unwrap();
+        assert!(matches!(&gestures[0], Gesture::Tap(data) if data.duration == Duration::from_millis(300)));
+        assert!(matches!(&gestures[1], Gesture::Hold(data) if data.duration == Duration::from_millis(301)));
+    }
+
+    #[test]
+    fn pinch_and_rotate() {
+        let manipulations = Arc::new(Mutex::new(Vec::new()));
+        let recognizer = {
+            let manipulations = Arc::clone(&manipulations);
+            ManipulationRecognizer::new(move |manipulation| manipulations.lock().unwrap().push(manipulation))
+        };
+
+        recognizer.cursor_added("test", &Cursor::new(0, Position { x: 0.4, y: 0.5 }));
+        recognizer.cursor_added("test", &Cursor::new(1, Position { x: 0.6, y: 0.5 }));
+        recognizer.cursor_updated("test", &Cursor::new(1, Position { x: 0.605, y: 0.5 }));
+        assert!(manipulations.lock().unwrap().is_empty());
+
+        recognizer.cursor_updated("test", &Cursor::new(0, Position { x: 0.305, y: 0.5 }));
+        recognizer.cursor_updated("test", &Cursor::new(1, Position { x: 0.305, y: 0.8 }));
+        recognizer.cursor_removed("test", &Cursor::new(0, Position { x: 0.305, y: 0.5 }));
+        recognizer.cursor_updated("test", &Cursor::new(1, Position { x: 0.5, y: 0.5 }));
+
+        let manipulations = manipulations.lock().unwrap();
+        assert_eq!(manipulations.len(), 2);
+
+        assert_eq!(manipulations[0].session_ids, (0, 1));
+        assert!((manipulations[0].scale - 1.5).abs() < 1e-5);
+        assert!(manipulations[0].rotation.abs() < 1e-5);
+        assert!((manipulations[0].translation.x + 0.045).abs() < 1e-5);
+
+        assert!((manipulations[1].scale - 1.).abs() < 1e-5);
+        assert!((manipulations[1].rotation - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+    }
+}
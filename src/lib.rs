@@ -1,14 +1,59 @@
 mod object;
 mod blob;
-mod errors;
+mod token;
+mod pointer;
+mod symbol;
+mod bounds;
+mod geometry;
+mod control;
+mod touch_point;
+mod cursor_25d;
+mod coalesce;
+mod persist;
 pub mod server;
 pub mod client;
+pub mod tuio_client;
+pub mod clock;
 pub mod cursor;
+pub mod cursor_3d;
+pub mod blob_3d;
 pub mod osc_encode_decode;
 pub mod osc_receiver;
+pub mod errors;
+pub mod recording;
+pub mod loopback;
+pub mod transform;
+pub mod gesture;
+pub mod remap;
+pub mod debug;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+#[cfg(feature = "egui")]
+pub mod egui_input;
+#[cfg(feature = "winit")]
+pub mod touch;
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "async")]
+pub mod async_client;
+#[cfg(feature = "bevy")]
+pub mod bevy_tuio;
 
 pub use server::Server;
 pub use client::Client;
+pub use tuio_client::TuioClient;
 pub use cursor::Cursor;
+pub use cursor_3d::Cursor3D;
+pub use cursor_25d::Cursor25D;
 pub use object::Object;
-pub use blob::Blob;
\ No newline at end of file
+pub use blob::Blob;
+pub use blob_3d::Blob3D;
+pub use token::Token;
+pub use pointer::Pointer;
+pub use symbol::Symbol;
+pub use bounds::Bounds;
+pub use geometry::Geometry;
+pub use control::{Control, ControlValue};
+pub use touch_point::TouchPoint;
\ No newline at end of file
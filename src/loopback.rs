@@ -0,0 +1,176 @@
+use std::sync::{Arc, Mutex};
+
+use ringbuffer::{ConstGenericRingBuffer, RingBufferWrite};
+use rosc::OscPacket;
+
+use crate::{server::SendOsc, errors::TuioError};
+
+pub(crate) type PacketBuffer = Arc<Mutex<ConstGenericRingBuffer<OscPacket, 128>>>;
+
+/// An in-memory transport connecting a [crate::Server] directly to a [crate::Client] without any socket
+///
+/// Packets sent by the [crate::Server] are queued and processed by the next [crate::Client::refresh], so listeners can be tested synchronously.
+/// Like the network receivers, only the last 128 unprocessed packets are kept
+#[derive(Clone, Default)]
+pub struct LoopbackTransport {
+    packet_buffer: PacketBuffer,
+}
+
+impl LoopbackTransport {
+    /// Creates a [LoopbackTransport] with an empty queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn packet_buffer(&self) -> PacketBuffer {
+        Arc::clone(&self.packet_buffer)
+    }
+}
+
+impl SendOsc<OscPacket, TuioError> for LoopbackTransport {
+    /// Queues an [OscPacket] for the connected [crate::Client]
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), TuioError> {
+        self.packet_buffer.lock().unwrap().push(packet.clone());
+        Ok(())
+    }
+
+    /// Always returns true because the transport is in memory
+    fn is_connected(&self) -> bool {
+        true
+    }
+
+    /// Always returns true because the transport is in memory
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use crate::{client::Listener, clock::TestClock, cursor::{Position, Velocity}, testing::{RecordingListener, RecordedEntity, EventKind}, Client, Cursor, Object, Server};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CursorCounter {
+        added: Cell<usize>,
+        removed: Cell<usize>,
+    }
+
+    impl Listener for CursorCounter {
+        fn cursor_added(&self, _: &str, _: &Cursor) {
+            self.added.set(self.added.get() + 1);
+        }
+
+        fn cursor_removed(&self, _: &str, _: &Cursor) {
+            self.removed.set(self.removed.get() + 1);
+        }
+    }
+
+    #[test]
+    fn server_to_client() {
+        let transport = LoopbackTransport::new();
+        let server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+        let listener = CursorCounter::default();
+
+        assert!(client.refresh().unwrap().is_none());
+
+        server.send_frame(&[Cursor::new(0, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        client.refresh().unwrap().unwrap().dispatch(&listener);
+
+        assert_eq!(listener.added.get(), 1);
+        assert_eq!(client.cursors().len(), 1);
+
+        server.send_frame(&[], &[], &[]).unwrap();
+        client.refresh().unwrap().unwrap().dispatch(&listener);
+
+        assert_eq!(listener.removed.get(), 1);
+        assert!(client.cursors().is_empty());
+    }
+
+    #[test]
+    fn object_round_trip() {
+        let transport = LoopbackTransport::new();
+        let server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+        let listener = RecordingListener::new();
+
+        let object = Object::new(4, 12, Position { x: 0.25, y: 0.75 }, 1.5).with_motion(Velocity { x: 0.1, y: -0.1 }, 0.25, 0.5, -0.125);
+
+        server.send_frame(&[], std::slice::from_ref(&object), &[]).unwrap();
+        client.refresh().unwrap().unwrap().dispatch(&listener);
+
+        let events = listener.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, EventKind::Added);
+
+        match &events[0].entity {
+            RecordedEntity::Object(received) => {
+                assert_eq!(received.get_class_id(), 12);
+                assert_eq!(received.get_angle(), 1.5);
+                assert_eq!(received.get_rotation_speed(), 0.25);
+                assert_eq!(received.get_rotation_acceleration(), -0.125);
+                assert_eq!(received, &object);
+            },
+            entity => panic!("not an object: {:?}", entity),
+        }
+
+        server.send_frame(&[], &[], &[]).unwrap();
+        client.refresh().unwrap().unwrap().dispatch(&listener);
+
+        assert_eq!(listener.history(4), vec![EventKind::Added, EventKind::Removed]);
+    }
+
+    #[test]
+    fn object_frames() {
+        for full_update in [false, true] {
+            let transport = LoopbackTransport::new();
+            let clock = TestClock::new();
+            let mut server = Server::from_osc_sender(transport.clone());
+            server.set_clock(clock.clone());
+            server.full_update = full_update;
+            let client = Client::from_loopback(&transport);
+            let listener = RecordingListener::new();
+
+            server.init_frame();
+            let moved = server.create_object(12, 0.25, 0.75, 1.5);
+            let still = server.create_object(13, 0.5, 0.5, 0.);
+            server.commit_frame().unwrap();
+            client.refresh().unwrap().unwrap().dispatch(&listener);
+
+            clock.advance(Duration::from_millis(100));
+            server.init_frame();
+            server.update_object(moved, 0.3, 0.75, 2.);
+            server.commit_frame().unwrap();
+            client.refresh().unwrap().unwrap().dispatch(&listener);
+
+            assert_eq!(listener.history(moved), vec![EventKind::Added, EventKind::Updated], "full update: {}", full_update);
+
+            let still_history = if full_update { vec![EventKind::Added, EventKind::Updated] } else { vec![EventKind::Added] };
+            assert_eq!(listener.history(still), still_history, "full update: {}", full_update);
+
+            let mut expected = Object::new(moved, 12, Position { x: 0.25, y: 0.75 }, 1.5);
+            expected.update(Duration::from_millis(100), Position { x: 0.3, y: 0.75 }, 2.);
+
+            let updated = listener.events().into_iter()
+                .find(|event| event.kind == EventKind::Updated && event.entity.get_session_id() == moved)
+                .unwrap();
+
+            match &updated.entity {
+                RecordedEntity::Object(received) => {
+                    assert_eq!(received.get_class_id(), 12);
+                    assert_eq!(received.get_angle(), 2.);
+                    assert_eq!(received.get_rotation_speed(), expected.get_rotation_speed());
+                    assert_ne!(received.get_rotation_speed(), 0.);
+                },
+                entity => panic!("not an object: {:?}", entity),
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use std::{f32::consts::PI, time::Duration};
+use std::{fmt, time::Duration};
 
-use crate::cursor::{Position, Velocity};
+use crate::cursor::{Lifetime, PixelPosition, Position, Velocity, linear_motion, normalize_angle, predict_position, smooth, rotation_motion, MotionOrigin, DEFAULT_MIN_DELTA_TIME};
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Object {
     pub(crate) session_id: i32,
     pub(crate) class_id: i32,
@@ -12,6 +13,12 @@ pub struct Object {
     pub(crate) rotation_speed: f32,
     pub(crate) acceleration: f32,
     pub(crate) rotation_acceleration: f32,
+    pub(crate) extra_args: Vec<f32>,
+    pub(crate) source: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) lifetime: Lifetime,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<(Position, f32)>,
 }
 
 impl Object {
@@ -20,7 +27,7 @@ impl Object {
     /// * `session_id` - a unique session ID
     /// * `class_id` - the object's class ID
     /// * `position` - a normalized [Position]
-    /// * `angle` - an angle in radians
+    /// * `angle` - an angle in radians, stored normalized between 0 and 2π
     pub fn new(session_id: i32, class_id: i32, position: Position, angle: f32) -> Self {
         Self {
             session_id,
@@ -28,9 +35,13 @@ impl Object {
             position,
             velocity: Velocity::default(),
             acceleration: 0f32,
-            angle,
+            angle: normalize_angle(angle),
             rotation_speed: 0f32,
             rotation_acceleration: 0f32,
+            extra_args: Vec::new(),
+            source: None,
+            lifetime: Lifetime::default(),
+            motion_origin: MotionOrigin::default(),
         }
     }
 
@@ -39,7 +50,7 @@ impl Object {
     /// * `velocity` - a normalized [Velocity]
     /// * `rotation_speed` - a rotation speed in turns per second
     /// * `acceleration` - a normalized acceleration
-    /// * `rotation_acceleration` - a roation acceleration in radians turn per second squared
+    /// * `rotation_acceleration` - a rotation acceleration in turns per second squared
     pub fn with_motion(
         mut self,
         velocity: Velocity,
@@ -54,10 +65,12 @@ impl Object {
         self
     }
 
+    /// Returns the session ID, which identifies this [Object] only while it stays on the surface
     pub fn get_session_id(&self) -> i32 {
         self.session_id
     }
 
+    /// Returns the class ID, such as the fiducial ID, which identifies the physical marker across sessions
     pub fn get_class_id(&self) -> i32 {
         self.class_id
     }
@@ -90,6 +103,62 @@ impl Object {
         self.acceleration
     }
 
+    /// Returns the name of the source which sent this [Object], or [None] if it was not received or its bundle had no `source` message
+    pub fn get_source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Returns the float arguments following the standard ones in the received `set` message, such as vendor-specific values of extended trackers
+    pub fn get_extra_args(&self) -> &[f32] {
+        &self.extra_args
+    }
+
+    /// Returns the time elapsed between the creation or first reception of this [Object] and the last frame it was part of,
+    /// measured by the [crate::clock::Clock] of the [crate::Client] or [crate::Server] tracking it
+    pub fn age(&self) -> Duration {
+        self.lifetime.age()
+    }
+
+    /// Returns the number of updates of this [Object] since it was created or first received
+    pub fn update_count(&self) -> u32 {
+        self.lifetime.update_count()
+    }
+
+    /// Returns true if this [Object] first appeared in the last frame received from its source, which lets a polling application detect new inputs without the add events
+    pub fn is_new_this_frame(&self) -> bool {
+        self.lifetime.is_new_this_frame()
+    }
+
+    /// Returns the position extrapolated from the velocity, to render smooth motion between two frames
+    /// # Arguments
+    /// * `ahead` - the [Duration] since the last update
+    pub fn predicted_position(&self, ahead: Duration) -> Position {
+        predict_position(&self.position, &self.velocity, None, ahead)
+    }
+
+    /// Returns the position extrapolated from the velocity and the acceleration along the direction of motion
+    /// # Arguments
+    /// * `ahead` - the [Duration] since the last update
+    pub fn predicted_position_with_acceleration(&self, ahead: Duration) -> Position {
+        predict_position(&self.position, &self.velocity, Some(self.acceleration), ahead)
+    }
+
+    /// Returns the position in screen space
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn get_pixel_position(&self, screen_width: u16, screen_height: u16) -> PixelPosition {
+        self.position.to_pixel(screen_width, screen_height)
+    }
+
+    /// Returns the speed in pixels per second in screen space
+    /// # Arguments
+    /// * `screen_width` - the screen width in pixels
+    /// * `screen_height` - the screen height in pixels
+    pub fn get_pixel_speed(&self, screen_width: u16, screen_height: u16) -> f32 {
+        self.velocity.to_pixels(screen_width, screen_height).get_speed()
+    }
+
     /// Returns the angle in radians
     pub fn get_angle(&self) -> f32 {
         self.angle
@@ -105,34 +174,56 @@ impl Object {
         self.rotation_acceleration
     }
 
-    /// Updates the [Object], computing its velocity, acceleration, rotation speed and rotation acceleration
+    /// Updates the [Object], computing its velocity, acceleration, rotation speed and rotation acceleration once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
     /// # Arguments
     /// * `delta_time` - the [Duration] since last update
     /// * `position` - the new [Position]
-    /// * `angle` - the new angle
+    /// * `angle` - the new angle in radians, stored normalized between 0 and 2π
     pub fn update(&mut self, delta_time: Duration, position: Position, angle: f32) {
-        let delta_time = delta_time.as_secs_f32();
-
-        let distance = position.distance_from(&self.position);
-        let delta_x = position.x - self.position.x;
-        let delta_y = position.y - self.position.y;
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position, angle);
+    }
 
-        let last_speed = self.velocity.get_speed();
-        let speed = distance / delta_time;
+    /// Updates the [Object], computing its velocity, acceleration, rotation speed and rotation acceleration once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// Closer updates keep the previous motion instead of producing a spike, while the other values are still updated
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle in radians, stored normalized between 0 and 2π
+    pub fn update_with_min_delta_time(&mut self, delta_time: Duration, min_delta_time: Duration, position: Position, angle: f32) {
+        self.lifetime = self.lifetime.updated();
 
-        self.velocity = Velocity {
-            x: delta_x / delta_time,
-            y: delta_y / delta_time,
-        };
+        if let Some(((previous_position, previous_angle), delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &(self.position, self.angle)) {
+            (self.velocity, self.acceleration) = linear_motion(&position, &previous_position, &self.velocity, delta_time);
+            (self.rotation_speed, self.rotation_acceleration) = rotation_motion(angle, previous_angle, self.rotation_speed, delta_time);
+        }
 
-        self.acceleration = (speed - last_speed) / delta_time;
         self.position = position;
+        self.angle = normalize_angle(angle);
+    }
 
-        let delta_turn = (angle - self.angle) / (2. * PI);
-        let rotation_speed = delta_turn / delta_time;
+    /// Keeps the position, velocity and acceleration of the previous state of this [Object] if it moved less than a dead-band
+    /// # Arguments
+    /// * `previous` - the previous state of this [Object]
+    /// * `deadband` - the minimum normalized distance of a position change, 0 keeps every change
+    pub(crate) fn hold_position(&mut self, previous: &Object, deadband: f32) {
+        if self.position.distance_from(&previous.position) < deadband {
+            self.position = previous.position;
+            self.velocity = previous.velocity;
+            self.acceleration = previous.acceleration;
+        }
+    }
 
-        self.rotation_acceleration = (rotation_speed - self.rotation_speed) / delta_time;
-        self.rotation_speed = rotation_speed;
+    /// Low-pass filters the velocity, rotation speed and accelerations of this [Object] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Object]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Object, alpha: f32) {
+        self.velocity = self.velocity.smooth(&previous.velocity, alpha);
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+        self.rotation_speed = smooth(self.rotation_speed, previous.rotation_speed, alpha);
+        self.rotation_acceleration = smooth(self.rotation_acceleration, previous.rotation_acceleration, alpha);
     }
 }
 
@@ -141,7 +232,7 @@ impl PartialEq for Object {
         self.session_id == other.session_id
             && self.class_id == other.class_id
             && self.get_x_position() == other.get_x_position()
-            && self.get_x_position() == other.get_y_position()
+            && self.get_y_position() == other.get_y_position()
             && self.angle == other.angle
             && self.velocity == other.velocity
             && self.rotation_speed == other.rotation_speed
@@ -150,11 +241,21 @@ impl PartialEq for Object {
     }
 }
 
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "object {} of class {} at ({:.3}, {:.3}) angle {:.3} moving ({:.3}, {:.3})",
+            self.session_id, self.class_id, self.position.x, self.position.y, self.angle, self.velocity.x, self.velocity.y
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{f32::consts::SQRT_2, time::Duration};
+    use std::{f32::consts::{PI, SQRT_2}, time::Duration};
 
-    use crate::{cursor::Position, object::Object};
+    use crate::{cursor::Position, object::Object, blob::Blob};
 
     #[test]
     fn object_update() {
@@ -174,4 +275,38 @@ mod tests {
         assert_eq!(object.get_rotation_speed(), 0.25);
         assert_eq!(object.get_rotation_acceleration(), 0.25);
     }
+
+    #[test]
+    fn object_angle_wraparound() {
+        let mut object = Object::new(0, 0, Position { x: 0., y: 0. }, 350f32.to_radians());
+
+        object.update(Duration::from_secs(1), Position { x: 0., y: 0. }, 10f32.to_radians());
+
+        assert!((object.get_rotation_speed() - 20. / 360.).abs() < 1e-5);
+
+        object.update(Duration::from_secs(1), Position { x: 0., y: 0. }, 350f32.to_radians());
+
+        assert!((object.get_rotation_speed() + 20. / 360.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn rotation_matches_blob() {
+        let mut object = Object::new(0, 0, Position { x: 0., y: 0. }, 0.);
+        let mut blob = Blob::new(0, Position { x: 0., y: 0. }, 0., 0.1, 0.1, 0.01);
+
+        for (delta_time, angle) in [(100, 30f32), (50, 340.), (200, 90.)] {
+            object.update(Duration::from_millis(delta_time), Position { x: 0., y: 0. }, angle.to_radians());
+            blob.update(Duration::from_millis(delta_time), Position { x: 0., y: 0. }, angle.to_radians(), 0.1, 0.1, 0.01);
+
+            assert_eq!(object.get_rotation_speed(), blob.get_rotation_speed());
+            assert_eq!(object.get_rotation_acceleration(), blob.get_rotation_acceleration());
+        }
+    }
+
+    #[test]
+    fn angle_normalization() {
+        assert_eq!(Object::new(0, 1, Position { x: 0.5, y: 0.5 }, 3. * PI), Object::new(0, 1, Position { x: 0.5, y: 0.5 }, PI));
+        assert!((Object::new(0, 1, Position { x: 0.5, y: 0.5 }, 7. * PI).get_angle() - PI).abs() < 1e-5);
+        assert!((Object::new(0, 1, Position { x: 0.5, y: 0.5 }, -PI / 2.).get_angle() - 1.5 * PI).abs() < 1e-6);
+    }
 }
@@ -1,12 +1,15 @@
-use std::{time::{SystemTime}, iter};
+use std::{time::{SystemTime, Duration, UNIX_EPOCH}, iter};
 
 use rosc::{OscBundle, OscPacket, OscMessage, OscType, OscTime};
 
-use crate::{object::Object, cursor::{Cursor, Position, Velocity}, blob::Blob, errors::TuioError};
+use crate::{object::Object, cursor::{Cursor, Lifetime, MotionOrigin, Position, Velocity}, cursor_3d::{Cursor3D, Position3D, Velocity3D}, cursor_25d::Cursor25D, blob::Blob, blob_3d::{Blob3D, Rotation3D}, token::Token, pointer::Pointer, symbol::Symbol, bounds::Bounds, geometry::Geometry, control::{Control, ControlValue}, errors::TuioError};
 
 /// Base trait to implement an OSC encoder
 pub trait EncodeOsc<T> {
     /// Encodes an [Object] collection into an OSC bundle
+    ///
+    /// Each object is sent as a `/tuio/2Dobj set s i x y a X Y A m r` message, with its session ID, class ID, position, angle,
+    /// velocity, rotation speed, acceleration and rotation acceleration
     /// # Arguments
     /// * `object_collection` - an iterable [Object] collection
     /// * `source_name` - the source's name
@@ -26,6 +29,73 @@ pub trait EncodeOsc<T> {
     /// * `source_name` - the source's name
     /// * `frame_id` - the current's frame id
     fn encode_blob_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Blob>;
+
+    /// Encodes an [Cursor3D] collection into an OSC bundle
+    /// # Arguments
+    /// * `cursor_collection` - an iterable [Cursor3D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_cursor_3d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Cursor3D>;
+
+    /// Encodes a [Cursor25D] collection into an OSC bundle
+    /// # Arguments
+    /// * `cursor_collection` - an iterable [Cursor25D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_cursor_25d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Cursor25D>;
+
+    /// Encodes an [Blob3D] collection into an OSC bundle
+    /// # Arguments
+    /// * `blob_collection` - an iterable [Blob3D] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_blob_3d_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Blob3D>;
+
+    /// Encodes an [Token] collection into a TUIO 2.0 OSC bundle
+    /// # Arguments
+    /// * `token_collection` - an iterable [Token] collection
+    /// * `source_name` - the source's name
+    /// * `frame_id` - the current's frame id
+    fn encode_token_bundle<'a, I>(token_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Token>;
+
+    /// Encodes an [Pointer] collection into a TUIO 2.0 OSC bundle
+    /// # Arguments
+    /// * `pointer_collection` - an iterable [Pointer] collection
+    /// * `source_name` - the source name
+    /// * `frame_id` - the frame id
+    fn encode_pointer_bundle<'a, I>(pointer_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Pointer>;
+
+    /// Encodes an [Symbol] collection into a TUIO 2.0 OSC bundle
+    /// # Arguments
+    /// * `symbol_collection` - an iterable [Symbol] collection
+    /// * `source_name` - the source name
+    /// * `frame_id` - the frame id
+    fn encode_symbol_bundle<'a, I>(symbol_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Symbol>;
+
+    /// Encodes an [Bounds] collection into a TUIO 2.0 OSC bundle
+    /// # Arguments
+    /// * `bounds_collection` - an iterable [Bounds] collection
+    /// * `source_name` - the source name
+    /// * `frame_id` - the frame id
+    fn encode_bounds_bundle<'a, I>(bounds_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Bounds>;
+
+    /// Encodes an [Geometry] collection into a TUIO 2.0 OSC bundle
+    ///
+    /// Each contour is sent as a `/tuio2/ocg s x0 y0 ... xN yN` message, with its session ID followed by the coordinates of its points
+    /// # Arguments
+    /// * `geometry_collection` - an iterable [Geometry] collection
+    /// * `source_name` - the source name
+    /// * `frame_id` - the frame id
+    fn encode_geometry_bundle<'a, I>(geometry_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Geometry>;
+
+    /// Encodes a [Control] collection into a TUIO 2.0 OSC bundle
+    ///
+    /// Each control is sent as a `/tuio2/ctl s c0 ... cN` message, with its session ID followed by its boolean and float values
+    /// # Arguments
+    /// * `control_collection` - an iterable [Control] collection
+    /// * `source_name` - the source name
+    /// * `frame_id` - the frame id
+    fn encode_control_bundle<'a, I>(control_collection: I, source_name: String, frame_id: i32) -> T where I: IntoIterator<Item = &'a Control>;
 }
 
 /// An implementation of trait [EncodeOsc] based on [rosc]
@@ -68,7 +138,7 @@ impl EncodeOsc<OscBundle> for OscEncoder {
         
         let alive_message = OscPacket::Message(OscMessage {
             addr: "/tuio/2Dobj".into(),
-            args: vec![OscType::String("alive".into())].into_iter().chain(object_ids.into_iter()).collect()
+            args: vec![OscType::String("alive".into())].into_iter().chain(object_ids).collect()
         });
     
         let frame_message = OscPacket::Message(OscMessage {
@@ -77,12 +147,12 @@ impl EncodeOsc<OscBundle> for OscEncoder {
         });
     
         OscBundle { 
-            timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
+            timetag: encode_time_tag(SystemTime::now()), 
             content: vec![
                 source_message,
                 alive_message
             ].into_iter()
-            .chain(set_messages.into_iter())
+            .chain(set_messages)
             .chain(iter::once(frame_message))
             .collect()
         }
@@ -120,7 +190,7 @@ impl EncodeOsc<OscBundle> for OscEncoder {
         
         let alive_message = OscPacket::Message(OscMessage {
             addr: "/tuio/2Dcur".into(),
-            args: vec![OscType::String("alive".into())].into_iter().chain(cursor_ids.into_iter()).collect()
+            args: vec![OscType::String("alive".into())].into_iter().chain(cursor_ids).collect()
         });
     
         let frame_message = OscPacket::Message(OscMessage {
@@ -129,12 +199,12 @@ impl EncodeOsc<OscBundle> for OscEncoder {
         });
     
         OscBundle { 
-            timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
+            timetag: encode_time_tag(SystemTime::now()), 
             content: vec![
                 source_message,
                 alive_message
             ].into_iter()
-            .chain(set_messages.into_iter())
+            .chain(set_messages)
             .chain(iter::once(frame_message))
             .collect()
         }
@@ -178,7 +248,7 @@ impl EncodeOsc<OscBundle> for OscEncoder {
         
         let alive_message = OscPacket::Message(OscMessage {
             addr: "/tuio/2Dblb".into(),
-            args: vec![OscType::String("alive".into())].into_iter().chain(blob_ids.into_iter()).collect()
+            args: vec![OscType::String("alive".into())].into_iter().chain(blob_ids).collect()
         });
     
         let frame_message = OscPacket::Message(OscMessage {
@@ -187,222 +257,1537 @@ impl EncodeOsc<OscBundle> for OscEncoder {
         });
     
         OscBundle { 
-            timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
+            timetag: encode_time_tag(SystemTime::now()), 
             content: vec![
                 source_message,
                 alive_message
             ].into_iter()
-            .chain(set_messages.into_iter())
+            .chain(set_messages)
             .chain(iter::once(frame_message))
             .collect()
         }
     }
-}
 
-/// An enum of a "set" TUIO message
-pub enum Set {
-    Cursor(Vec<Cursor>),
-    Object(Vec<Object>),
-    Blob(Vec<Blob>),
-}
+    fn encode_cursor_3d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Cursor3D> {
+        let source_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/3Dcur".into(),
+            args: vec![
+                OscType::String("source".into()),
+                OscType::String(source_name)
+            ]
+        });
 
-#[derive(Default)]
-pub enum TuioBundleType {
-    Cursor,
-    Object,
-    Blob,
-    #[default]
-    Unknown
-}
+        let mut set_messages = vec![];
+        let mut cursor_ids: Vec<OscType>= vec![];
 
-/// A struct containing informations of a TUIO bundle
-#[derive(Default)]
-pub struct TuioBundle {
-    pub tuio_type: TuioBundleType,
-    pub source: String,
-    pub alive: Vec<i32>,
-    pub set: Option<Set>,
-    pub fseq: i32
-}
+        for cursor in cursor_collection.into_iter()  {
+            let id = cursor.get_session_id();
+            cursor_ids.push(OscType::Int(id));
 
-/// Base trait to implement an OSC decoder
-pub trait DecodeOsc<T> {
-    fn decode_bundle(bundle: T) -> Result<TuioBundle, TuioError>;
-}
+            set_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio/3Dcur".into(),
+                args: vec![
+                    OscType::String("set".into()),
+                    OscType::Int(id),
+                    OscType::Float(cursor.get_x_position()),
+                    OscType::Float(cursor.get_y_position()),
+                    OscType::Float(cursor.get_z_position()),
+                    OscType::Float(cursor.get_x_velocity()),
+                    OscType::Float(cursor.get_y_velocity()),
+                    OscType::Float(cursor.get_z_velocity()),
+                    OscType::Float(cursor.get_acceleration())
+                ]
+            }));
+        }
 
-/// An implementation of trait [DecodeOsc] based on [rosc]
-pub struct OscDecoder;
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/3Dcur".into(),
+            args: vec![OscType::String("alive".into())].into_iter().chain(cursor_ids).collect()
+        });
 
-fn try_unwrap_source_name(message: &OscMessage) -> Result<String, TuioError> {
-    match message.args.get(1) {
-        Some(arg) => {
-            match arg.clone().string() {
-                Some(source_name) => Ok(source_name),
-                None => Err(TuioError::WrongArgumentType(message.clone(), 1)),
-            }
-        },
-        None => Err(TuioError::MissingSource(message.clone())),
-    }
-}
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/3Dcur".into(),
+            args: vec![OscType::String("fseq".into()), OscType::Int(frame_id)]
+        });
 
-fn try_unwrap_object_args(args: &[OscType]) -> Result<Object, u8> {
-    Ok(Object {
-        session_id: args[1].clone().int().ok_or(1)?,
-        class_id: args[2].clone().int().ok_or(2)?,
-        position: Position {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?},
-        angle: args[5].clone().float().ok_or(5)?,
-        velocity: Velocity {x: args[6].clone().float().ok_or(6)?, y: args[7].clone().float().ok_or(7)?},
-        rotation_speed: args[8].clone().float().ok_or(8)?,
-        acceleration: args[9].clone().float().ok_or(9)?,
-        rotation_acceleration: args[10].clone().float().ok_or(10)?,
-    })
-}
+        OscBundle {
+            timetag: encode_time_tag(SystemTime::now()),
+            content: vec![
+                source_message,
+                alive_message
+            ].into_iter()
+            .chain(set_messages)
+            .chain(iter::once(frame_message))
+            .collect()
+        }
+    }
 
-fn try_unwrap_cursor_args(args: &[OscType]) -> Result<Cursor, u8> {
-    Ok(Cursor {
-        session_id: args[1].clone().int().ok_or(1)?,
-        position: Position {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?},
-        velocity: Velocity {x: args[4].clone().float().ok_or(4)?, y: args[5].clone().float().ok_or(5)?},
-        acceleration: args[6].clone().float().ok_or(6)?,
-    })
-}
+    fn encode_cursor_25d_bundle<'a, I>(cursor_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Cursor25D> {
+        let source_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/25Dcur".into(),
+            args: vec![
+                OscType::String("source".into()),
+                OscType::String(source_name)
+            ]
+        });
 
-fn try_unwrap_blob_args(args: &[OscType]) -> Result<Blob, u8> {
-    Ok(Blob {
-        session_id: args[1].clone().int().ok_or(1)?,
-        position: Position {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?},
-        angle: args[4].clone().float().ok_or(4)?,
-        width: args[5].clone().float().ok_or(5)?,
-        height: args[6].clone().float().ok_or(6)?,
-        area: args[7].clone().float().ok_or(7)?,
-        velocity: Velocity {x: args[8].clone().float().ok_or(8)?, y: args[9].clone().float().ok_or(9)?},
-        rotation_speed: args[10].clone().float().ok_or(10)?,
-        acceleration: args[11].clone().float().ok_or(11)?,
-        rotation_acceleration: args[12].clone().float().ok_or(12)?,
-    })
-}
+        let mut set_messages = vec![];
+        let mut cursor_ids: Vec<OscType>= vec![];
 
-impl DecodeOsc<OscBundle> for OscDecoder {
-    fn decode_bundle(bundle: OscBundle) -> Result<TuioBundle, TuioError> {
-        let mut decoded_bundle = TuioBundle::default();
-        
-        for packet in &bundle.content {
-            if let OscPacket::Message(message) = packet {
-                match message.args.first() {
-                    Some(OscType::String(arg)) => {
-                        match arg.as_str() {
-                            "source" => {
-                                decoded_bundle.tuio_type = match message.addr.as_str() {
-                                    "/tuio/2Dobj" => TuioBundleType::Object,
-                                    "/tuio/2Dcur" => TuioBundleType::Cursor,
-                                    "/tuio/2Dblb" => TuioBundleType::Blob,
-                                    _ => return Err(TuioError::UnknownAddress(message.clone()))
-                                };
+        for cursor in cursor_collection.into_iter()  {
+            let id = cursor.get_session_id();
+            cursor_ids.push(OscType::Int(id));
 
-                                decoded_bundle.source = try_unwrap_source_name(message)?;
-                            },
-                            "alive" => {
-                                decoded_bundle.alive = message.args.iter().skip(1).filter_map(|e| e.clone().int()).collect();
-                            },
-                            "set" => {
-                                match decoded_bundle.tuio_type {
-                                    TuioBundleType::Cursor => {
-                                        if let Set::Cursor(set) = decoded_bundle.set.get_or_insert(Set::Cursor(Vec::new())) {
-                                            if message.args.len() != 7 {
-                                                return Err(TuioError::MissingArguments(message.clone()));
-                                            }
-        
-                                            match try_unwrap_cursor_args(&message.args) {
-                                                Ok(params) => {
-                                                    set.push(params);
-                                                },
-                                                Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
-                                            }
-                                        }
-                                    },
-                                    TuioBundleType::Object => {
-                                        if let Set::Object(set) = decoded_bundle.set.get_or_insert(Set::Object(Vec::new())) {
-                                            if message.args.len() != 11 {
-                                                return Err(TuioError::MissingArguments(message.clone()));
-                                            }
-                                            
-                                            match try_unwrap_object_args(&message.args) {
-                                                Ok(params) => {
-                                                    set.push(params);
-                                                },
-                                                Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
-                                            }
-                                        }
-                                    },
-                                    TuioBundleType::Blob => {
-                                        if let Set::Blob(set) = decoded_bundle.set.get_or_insert(Set::Blob(Vec::new())) {
-                                            if message.args.len() != 13 {
-                                                return Err(TuioError::MissingArguments(message.clone()));
-                                            }
-        
-                                            match try_unwrap_blob_args(&message.args) {
-                                                Ok(params) => {
-                                                    set.push(params);
-                                                },
-                                                Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
-                                            }
-                                        }
-
-                                    },
-                                    TuioBundleType::Unknown => return Err(TuioError::IncompleteBundle(bundle)),
-                                }
-                            },
-                            "fseq" => {
-                                if let Some(OscType::Int(fseq)) = message.args.get(1) {
-                                    decoded_bundle.fseq = *fseq;
-                                }
-                                else {
-                                    return Err(TuioError::MissingArguments(message.clone()))
-                                }
-                            },
-                            _ => return Err(TuioError::UnknownMessageType(message.clone()))
-                        }
-                    },
-                    None => return Err(TuioError::EmptyMessage(message.clone())),
-                    _ => return Err(TuioError::UnknownMessageType(message.clone()))
-                }
-            };
+            set_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio/25Dcur".into(),
+                args: vec![
+                    OscType::String("set".into()),
+                    OscType::Int(id),
+                    OscType::Float(cursor.get_x_position()),
+                    OscType::Float(cursor.get_y_position()),
+                    OscType::Float(cursor.get_z_position()),
+                    OscType::Float(cursor.get_x_velocity()),
+                    OscType::Float(cursor.get_y_velocity()),
+                    OscType::Float(cursor.get_z_velocity()),
+                    OscType::Float(cursor.get_acceleration())
+                ]
+            }));
         }
 
-        Ok(decoded_bundle)
-    }
-}
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/25Dcur".into(),
+            args: vec![OscType::String("alive".into())].into_iter().chain(cursor_ids).collect()
+        });
 
-#[cfg(test)]
-mod tests {
-    use crate::{cursor::{Cursor, Position}, object::Object, blob::Blob, osc_encode_decode::{OscEncoder, EncodeOsc}};
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/25Dcur".into(),
+            args: vec![OscType::String("fseq".into()), OscType::Int(frame_id)]
+        });
 
-    use super::*;
+        OscBundle {
+            timetag: encode_time_tag(SystemTime::now()),
+            content: vec![
+                source_message,
+                alive_message
+            ].into_iter()
+            .chain(set_messages)
+            .chain(iter::once(frame_message))
+            .collect()
+        }
+    }
 
-    #[test]
-    fn encoding_decoding() {
-        let source = "test".to_string();
+    fn encode_blob_3d_bundle<'a, I>(blob_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Blob3D> {
+        let source_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/3Dblb".into(),
+            args: vec![
+                OscType::String("source".into()),
+                OscType::String(source_name)
+            ]
+        });
 
-        let cursors = vec![Cursor::new(0, Position {x: 0., y: 0.}), Cursor::new(1, Position {x: 0.5, y: 0.5})];
-        let objects = vec![Object::new(0, 0, Position {x: 0., y: 0.}, 0.), Object::new(1, 1, Position {x: 0.5, y: 0.5}, 0.)];
-        let blobs = vec![Blob::new(0, Position {x: 0., y: 0.}, 0., 0.3, 0.3, 0.09), Blob::new(1, Position {x: 0.5, y: 0.5}, 0., 0.5, 0.5, 0.25)];
+        let mut set_messages = vec![];
+        let mut blob_ids: Vec<OscType>= vec![];
 
-        let cursor_bundle = OscEncoder::encode_cursor_bundle(&cursors, source.clone(), 0);
-        let object_bundle = OscEncoder::encode_object_bundle(&objects, source.clone(), 0);
-        let blob_bundle = OscEncoder::encode_blob_bundle(&blobs, source, 0);
+        for blob in blob_collection.into_iter() {
+            let id = blob.get_session_id();
+            blob_ids.push(OscType::Int(id));
 
-        match OscDecoder::decode_bundle(cursor_bundle) {
-            Ok(decoded_bundle) => {
-                if let Some(Set::Cursor(decoded_cursors)) = decoded_bundle.set {
-                    assert_eq!(decoded_cursors.len(), 2);
-                    assert_eq!(cursors[0], decoded_cursors[0]);
-                    assert_eq!(cursors[1], decoded_cursors[1]);
-                }
-            },
-            Err(err) => {println!("{err}"); panic!()},
+            set_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio/3Dblb".into(),
+                args: vec![
+                    OscType::String("set".into()),
+                    OscType::Int(id),
+                    OscType::Float(blob.get_x_position()),
+                    OscType::Float(blob.get_y_position()),
+                    OscType::Float(blob.get_z_position()),
+                    OscType::Float(blob.get_angle().roll),
+                    OscType::Float(blob.get_angle().pitch),
+                    OscType::Float(blob.get_angle().yaw),
+                    OscType::Float(blob.get_width()),
+                    OscType::Float(blob.get_height()),
+                    OscType::Float(blob.get_depth()),
+                    OscType::Float(blob.get_volume()),
+                    OscType::Float(blob.get_x_velocity()),
+                    OscType::Float(blob.get_y_velocity()),
+                    OscType::Float(blob.get_z_velocity()),
+                    OscType::Float(blob.get_rotation_speed().roll),
+                    OscType::Float(blob.get_rotation_speed().pitch),
+                    OscType::Float(blob.get_rotation_speed().yaw),
+                    OscType::Float(blob.get_acceleration()),
+                    OscType::Float(blob.get_rotation_acceleration())
+                ]
+            }));
         }
 
-        match OscDecoder::decode_bundle(object_bundle) {
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/3Dblb".into(),
+            args: vec![OscType::String("alive".into())].into_iter().chain(blob_ids).collect()
+        });
+
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio/3Dblb".into(),
+            args: vec![OscType::String("fseq".into()), OscType::Int(frame_id)]
+        });
+
+        OscBundle {
+            timetag: encode_time_tag(SystemTime::now()),
+            content: vec![
+                source_message,
+                alive_message
+            ].into_iter()
+            .chain(set_messages)
+            .chain(iter::once(frame_message))
+            .collect()
+        }
+    }
+
+    fn encode_token_bundle<'a, I>(token_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Token> {
+        let timetag = encode_time_tag(SystemTime::now());
+
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/frm".into(),
+            args: vec![
+                OscType::Int(frame_id),
+                OscType::Time(timetag),
+                OscType::Int(0),
+                OscType::String(source_name)
+            ]
+        });
+
+        let mut token_messages = vec![];
+        let mut token_ids: Vec<OscType> = vec![];
+
+        for token in token_collection.into_iter() {
+            let id = token.get_session_id();
+            token_ids.push(OscType::Int(id));
+
+            token_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio2/tok".into(),
+                args: vec![
+                    OscType::Int(id),
+                    OscType::Int(((token.get_type_id() as u32) << 16 | token.get_user_id() as u32) as i32),
+                    OscType::Int(token.get_component_id()),
+                    OscType::Float(token.get_x_position()),
+                    OscType::Float(token.get_y_position()),
+                    OscType::Float(token.get_angle()),
+                    OscType::Float(token.get_x_velocity()),
+                    OscType::Float(token.get_y_velocity()),
+                    OscType::Float(token.get_rotation_speed()),
+                    OscType::Float(token.get_acceleration()),
+                    OscType::Float(token.get_rotation_acceleration())
+                ]
+            }));
+        }
+
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/alv".into(),
+            args: token_ids
+        });
+
+        OscBundle {
+            timetag,
+            content: iter::once(frame_message)
+            .chain(token_messages)
+            .chain(iter::once(alive_message))
+            .collect()
+        }
+    }
+
+    fn encode_pointer_bundle<'a, I>(pointer_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Pointer> {
+        let timetag = encode_time_tag(SystemTime::now());
+
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/frm".into(),
+            args: vec![
+                OscType::Int(frame_id),
+                OscType::Time(timetag),
+                OscType::Int(0),
+                OscType::String(source_name)
+            ]
+        });
+
+        let mut pointer_messages = vec![];
+        let mut pointer_ids: Vec<OscType> = vec![];
+
+        for pointer in pointer_collection.into_iter() {
+            let id = pointer.get_session_id();
+            pointer_ids.push(OscType::Int(id));
+
+            pointer_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio2/ptr".into(),
+                args: vec![
+                    OscType::Int(id),
+                    OscType::Int(((pointer.get_type_id() as u32) << 16 | pointer.get_user_id() as u32) as i32),
+                    OscType::Int(pointer.get_component_id()),
+                    OscType::Float(pointer.get_x_position()),
+                    OscType::Float(pointer.get_y_position()),
+                    OscType::Float(pointer.get_angle()),
+                    OscType::Float(pointer.get_shear()),
+                    OscType::Float(pointer.get_radius()),
+                    OscType::Float(pointer.get_pressure()),
+                    OscType::Float(pointer.get_x_velocity()),
+                    OscType::Float(pointer.get_y_velocity()),
+                    OscType::Float(pointer.get_pressure_speed()),
+                    OscType::Float(pointer.get_acceleration()),
+                    OscType::Float(pointer.get_pressure_acceleration())
+                ]
+            }));
+        }
+
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/alv".into(),
+            args: pointer_ids
+        });
+
+        OscBundle {
+            timetag,
+            content: iter::once(frame_message)
+            .chain(pointer_messages)
+            .chain(iter::once(alive_message))
+            .collect()
+        }
+    }
+
+    fn encode_symbol_bundle<'a, I>(symbol_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Symbol> {
+        let timetag = encode_time_tag(SystemTime::now());
+
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/frm".into(),
+            args: vec![
+                OscType::Int(frame_id),
+                OscType::Time(timetag),
+                OscType::Int(0),
+                OscType::String(source_name)
+            ]
+        });
+
+        let mut symbol_messages = vec![];
+        let mut symbol_ids: Vec<OscType> = vec![];
+
+        for symbol in symbol_collection.into_iter() {
+            let id = symbol.get_session_id();
+            symbol_ids.push(OscType::Int(id));
+
+            symbol_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio2/sym".into(),
+                args: vec![
+                    OscType::Int(id),
+                    OscType::Int(((symbol.get_type_id() as u32) << 16 | symbol.get_user_id() as u32) as i32),
+                    OscType::Int(symbol.get_symbol_id()),
+                    OscType::String(symbol.get_group().to_string()),
+                    OscType::String(symbol.get_data().to_string())
+                ]
+            }));
+        }
+
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/alv".into(),
+            args: symbol_ids
+        });
+
+        OscBundle {
+            timetag,
+            content: iter::once(frame_message)
+            .chain(symbol_messages)
+            .chain(iter::once(alive_message))
+            .collect()
+        }
+    }
+
+    fn encode_bounds_bundle<'a, I>(bounds_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Bounds> {
+        let timetag = encode_time_tag(SystemTime::now());
+
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/frm".into(),
+            args: vec![
+                OscType::Int(frame_id),
+                OscType::Time(timetag),
+                OscType::Int(0),
+                OscType::String(source_name)
+            ]
+        });
+
+        let mut bounds_messages = vec![];
+        let mut bounds_ids: Vec<OscType> = vec![];
+
+        for bounds in bounds_collection.into_iter() {
+            let id = bounds.get_session_id();
+            bounds_ids.push(OscType::Int(id));
+
+            bounds_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio2/bnd".into(),
+                args: vec![
+                    OscType::Int(id),
+                    OscType::Float(bounds.get_x_position()),
+                    OscType::Float(bounds.get_y_position()),
+                    OscType::Float(bounds.get_angle()),
+                    OscType::Float(bounds.get_width()),
+                    OscType::Float(bounds.get_height()),
+                    OscType::Float(bounds.get_area()),
+                    OscType::Float(bounds.get_x_velocity()),
+                    OscType::Float(bounds.get_y_velocity()),
+                    OscType::Float(bounds.get_rotation_speed()),
+                    OscType::Float(bounds.get_acceleration()),
+                    OscType::Float(bounds.get_rotation_acceleration())
+                ]
+            }));
+        }
+
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/alv".into(),
+            args: bounds_ids
+        });
+
+        OscBundle {
+            timetag,
+            content: iter::once(frame_message)
+            .chain(bounds_messages)
+            .chain(iter::once(alive_message))
+            .collect()
+        }
+    }
+    fn encode_geometry_bundle<'a, I>(geometry_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Geometry> {
+        let timetag = encode_time_tag(SystemTime::now());
+
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/frm".into(),
+            args: vec![
+                OscType::Int(frame_id),
+                OscType::Time(timetag),
+                OscType::Int(0),
+                OscType::String(source_name)
+            ]
+        });
+
+        let mut geometry_messages = vec![];
+        let mut geometry_ids: Vec<OscType> = vec![];
+
+        for geometry in geometry_collection.into_iter() {
+            let id = geometry.get_session_id();
+            geometry_ids.push(OscType::Int(id));
+
+            geometry_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio2/ocg".into(),
+                args: iter::once(OscType::Int(id))
+                    .chain(geometry.get_contour().iter().flat_map(|point| [OscType::Float(point.x), OscType::Float(point.y)]))
+                    .collect()
+            }));
+        }
+
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/alv".into(),
+            args: geometry_ids
+        });
+
+        OscBundle {
+            timetag,
+            content: iter::once(frame_message)
+            .chain(geometry_messages)
+            .chain(iter::once(alive_message))
+            .collect()
+        }
+    }
+    fn encode_control_bundle<'a, I>(control_collection: I, source_name: String, frame_id: i32) -> OscBundle where I: IntoIterator<Item = &'a Control> {
+        let timetag = encode_time_tag(SystemTime::now());
+
+        let frame_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/frm".into(),
+            args: vec![
+                OscType::Int(frame_id),
+                OscType::Time(timetag),
+                OscType::Int(0),
+                OscType::String(source_name)
+            ]
+        });
+
+        let mut control_messages = vec![];
+        let mut control_ids: Vec<OscType> = vec![];
+
+        for control in control_collection.into_iter() {
+            let id = control.get_session_id();
+            control_ids.push(OscType::Int(id));
+
+            control_messages.push(OscPacket::Message(OscMessage {
+                addr: "/tuio2/ctl".into(),
+                args: iter::once(OscType::Int(id))
+                    .chain(control.get_values().iter().map(|value| match value {
+                        ControlValue::Bool(value) => OscType::Bool(*value),
+                        ControlValue::Float(value) => OscType::Float(*value),
+                    }))
+                    .collect()
+            }));
+        }
+
+        let alive_message = OscPacket::Message(OscMessage {
+            addr: "/tuio2/alv".into(),
+            args: control_ids
+        });
+
+        OscBundle {
+            timetag,
+            content: iter::once(frame_message)
+            .chain(control_messages)
+            .chain(iter::once(alive_message))
+            .collect()
+        }
+    }
+}
+
+/// An enum of a "set" TUIO message
+pub enum Set {
+    Cursor(Vec<Cursor>),
+    Object(Vec<Object>),
+    Blob(Vec<Blob>),
+    Cursor3D(Vec<Cursor3D>),
+    Cursor25D(Vec<Cursor25D>),
+    Blob3D(Vec<Blob3D>),
+    Token(Vec<Token>),
+    Pointer(Vec<Pointer>),
+    Symbol(Vec<Symbol>),
+    Bounds(Vec<Bounds>),
+    Geometry(Vec<Geometry>),
+    Control(Vec<Control>),
+}
+
+impl Set {
+    /// Returns the session IDs of the inputs
+    pub(crate) fn session_ids(&self) -> Vec<i32> {
+        match self {
+            Set::Cursor(cursors) => cursors.iter().map(|cursor| cursor.session_id).collect(),
+            Set::Object(objects) => objects.iter().map(|object| object.session_id).collect(),
+            Set::Blob(blobs) => blobs.iter().map(|blob| blob.session_id).collect(),
+            Set::Cursor3D(cursors) => cursors.iter().map(|cursor| cursor.session_id).collect(),
+            Set::Cursor25D(cursors) => cursors.iter().map(|cursor| cursor.session_id).collect(),
+            Set::Blob3D(blobs) => blobs.iter().map(|blob| blob.session_id).collect(),
+            Set::Token(tokens) => tokens.iter().map(|token| token.session_id).collect(),
+            Set::Pointer(pointers) => pointers.iter().map(|pointer| pointer.session_id).collect(),
+            Set::Symbol(symbols) => symbols.iter().map(|symbol| symbol.session_id).collect(),
+            Set::Bounds(bounds) => bounds.iter().map(|bounds| bounds.session_id).collect(),
+            Set::Geometry(geometries) => geometries.iter().map(|geometry| geometry.session_id).collect(),
+            Set::Control(controls) => controls.iter().map(|control| control.session_id).collect(),
+        }
+    }
+
+    /// Keeps the inputs whose session ID matches a predicate
+    pub(crate) fn retain_session_ids(&mut self, keep: impl Fn(i32) -> bool) {
+        match self {
+            Set::Cursor(cursors) => cursors.retain(|cursor| keep(cursor.session_id)),
+            Set::Object(objects) => objects.retain(|object| keep(object.session_id)),
+            Set::Blob(blobs) => blobs.retain(|blob| keep(blob.session_id)),
+            Set::Cursor3D(cursors) => cursors.retain(|cursor| keep(cursor.session_id)),
+            Set::Cursor25D(cursors) => cursors.retain(|cursor| keep(cursor.session_id)),
+            Set::Blob3D(blobs) => blobs.retain(|blob| keep(blob.session_id)),
+            Set::Token(tokens) => tokens.retain(|token| keep(token.session_id)),
+            Set::Pointer(pointers) => pointers.retain(|pointer| keep(pointer.session_id)),
+            Set::Symbol(symbols) => symbols.retain(|symbol| keep(symbol.session_id)),
+            Set::Bounds(bounds) => bounds.retain(|bounds| keep(bounds.session_id)),
+            Set::Geometry(geometries) => geometries.retain(|geometry| keep(geometry.session_id)),
+            Set::Control(controls) => controls.retain(|control| keep(control.session_id)),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TuioBundleType {
+    Cursor,
+    Object,
+    Blob,
+    Cursor3D,
+    Cursor25D,
+    Blob3D,
+    Token,
+    Pointer,
+    Symbol,
+    Bounds,
+    Geometry,
+    Control,
+    #[default]
+    Unknown
+}
+
+impl TuioBundleType {
+    /// Returns the profile of the messages sent to an OSC address, or [None] for the profile-independent TUIO 2.0 frame and alive messages and for unknown addresses
+    /// # Arguments
+    /// * `address` - an OSC address without namespace, such as `/tuio/2Dcur`
+    pub fn from_address(address: &str) -> Option<Self> {
+        match address {
+            "/tuio/2Dcur" => Some(TuioBundleType::Cursor),
+            "/tuio/2Dobj" => Some(TuioBundleType::Object),
+            "/tuio/2Dblb" => Some(TuioBundleType::Blob),
+            "/tuio/3Dcur" => Some(TuioBundleType::Cursor3D),
+            "/tuio/25Dcur" => Some(TuioBundleType::Cursor25D),
+            "/tuio/3Dblb" => Some(TuioBundleType::Blob3D),
+            "/tuio2/tok" => Some(TuioBundleType::Token),
+            "/tuio2/ptr" => Some(TuioBundleType::Pointer),
+            "/tuio2/sym" => Some(TuioBundleType::Symbol),
+            "/tuio2/bnd" => Some(TuioBundleType::Bounds),
+            "/tuio2/ocg" => Some(TuioBundleType::Geometry),
+            "/tuio2/ctl" => Some(TuioBundleType::Control),
+            _ => None,
+        }
+    }
+
+    /// The TUIO 2.0 profiles, whose components share the `/tuio2/frm` and `/tuio2/alv` messages of a frame
+    pub const TUIO2_PROFILES: [TuioBundleType; 6] = [
+        TuioBundleType::Token,
+        TuioBundleType::Pointer,
+        TuioBundleType::Symbol,
+        TuioBundleType::Bounds,
+        TuioBundleType::Geometry,
+        TuioBundleType::Control,
+    ];
+
+    /// Returns true for a TUIO 2.0 profile
+    pub fn is_tuio2(&self) -> bool {
+        Self::TUIO2_PROFILES.contains(self)
+    }
+}
+
+/// Returns the profile of a TUIO bundle from the address of its first profile message, without decoding it
+///
+/// Returns [None] if no message of the bundle belongs to a known profile
+/// # Arguments
+/// * `bundle` - an [OscBundle] without namespace
+pub fn bundle_profile(bundle: &OscBundle) -> Option<TuioBundleType> {
+    bundle.content.iter().find_map(|packet| match packet {
+        OscPacket::Message(message) => TuioBundleType::from_address(&message.addr),
+        OscPacket::Bundle(_) => None,
+    })
+}
+
+/// A struct containing informations of a TUIO bundle
+pub struct TuioBundle {
+    pub tuio_type: TuioBundleType,
+    pub source: String,
+    /// The time at which the source captured the bundle
+    pub time: SystemTime,
+    pub alive: Vec<i32>,
+    pub set: Option<Set>,
+    pub fseq: i32,
+    /// The width and height in pixels of the sensor of a TUIO 2.0 frame, [None] for a TUIO 1.x bundle
+    pub dimension: Option<(u16, u16)>,
+    /// The errors of the malformed `set` messages which were skipped while decoding
+    pub skipped: Vec<TuioError>
+}
+
+/// The number of seconds between the NTP epoch (1900) and the Unix epoch (1970)
+const NTP_UNIX_OFFSET: u64 = 2_208_988_800;
+
+/// Converts a [SystemTime] into an OSC time tag, the NTP 64-bit fixed-point number of seconds since 1900
+///
+/// Times before the Unix epoch are clamped to it
+pub fn encode_time_tag(time: SystemTime) -> OscTime {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    OscTime {
+        seconds: (since_epoch.as_secs() + NTP_UNIX_OFFSET) as u32,
+        fractional: (((since_epoch.subsec_nanos() as u64) << 32) / 1_000_000_000) as u32,
+    }
+}
+
+/// Converts an OSC time tag into a [SystemTime]
+///
+/// Time tags before the Unix epoch, including the immediate time tag, are clamped to it
+pub fn decode_time_tag(timetag: OscTime) -> SystemTime {
+    match (timetag.seconds as u64).checked_sub(NTP_UNIX_OFFSET) {
+        Some(seconds) => UNIX_EPOCH + Duration::from_secs(seconds) + Duration::from_nanos(((timetag.fractional as u64) * 1_000_000_000) >> 32),
+        None => UNIX_EPOCH,
+    }
+}
+
+/// Converts the time tag of a bundle, mapping the immediate time tag and any time before the Unix epoch to now
+pub fn bundle_time(timetag: OscTime) -> SystemTime {
+    if (timetag.seconds as u64) < NTP_UNIX_OFFSET {
+        SystemTime::now()
+    }
+    else {
+        decode_time_tag(timetag)
+    }
+}
+
+/// Normalizes an OSC address namespace to a leading slash without a trailing one, such as `/mytable`
+///
+/// Returns an empty string for the standard namespace
+pub fn normalize_namespace(namespace: &str) -> String {
+    let namespace = namespace.trim_matches('/');
+
+    if namespace.is_empty() {
+        String::new()
+    }
+    else {
+        format!("/{}", namespace)
+    }
+}
+
+/// The size of a bundle without its content, the `#bundle` tag followed by the time tag
+pub(crate) const BUNDLE_HEADER_SIZE: usize = 16;
+
+/// Returns the size of a string or a blob padded to a multiple of 4 bytes
+fn padded_size(size: usize) -> usize {
+    (size + 3) & !3
+}
+
+/// Returns the size of the type tag characters and of the encoded value of an argument
+fn argument_size(argument: &OscType) -> (usize, usize) {
+    match argument {
+        OscType::Int(_) | OscType::Float(_) | OscType::Char(_) | OscType::Color(_) | OscType::Midi(_) => (1, 4),
+        OscType::Long(_) | OscType::Double(_) | OscType::Time(_) => (1, 8),
+        OscType::String(string) => (1, padded_size(string.len() + 1)),
+        OscType::Blob(blob) => (1, 4 + padded_size(blob.len())),
+        OscType::Bool(_) | OscType::Nil | OscType::Inf => (1, 0),
+        OscType::Array(array) => array.content.iter().map(argument_size).fold((2, 0), |(tags, size), (arg_tags, arg_size)| (tags + arg_tags, size + arg_size)),
+    }
+}
+
+/// Returns the size of an encoded packet without encoding it
+pub(crate) fn encoded_size(packet: &OscPacket) -> usize {
+    match packet {
+        OscPacket::Message(message) => {
+            let (tags, size) = message.args.iter().map(argument_size).fold((0, 0), |(tags, size), (arg_tags, arg_size)| (tags + arg_tags, size + arg_size));
+            padded_size(message.addr.len() + 1) + padded_size(tags + 2) + size
+        },
+        OscPacket::Bundle(bundle) => BUNDLE_HEADER_SIZE + bundle.content.iter().map(|packet| 4 + encoded_size(packet)).sum::<usize>(),
+    }
+}
+
+/// Prepends a namespace to the address of every message of a packet, including the ones of nested bundles
+/// # Arguments
+/// * `packet` - the [OscPacket] to prefix
+/// * `namespace` - a namespace normalized by [normalize_namespace]
+pub fn add_namespace(packet: &mut OscPacket, namespace: &str) {
+    if namespace.is_empty() {
+        return;
+    }
+
+    match packet {
+        OscPacket::Message(message) => message.addr.insert_str(0, namespace),
+        OscPacket::Bundle(bundle) => for packet in &mut bundle.content {
+            add_namespace(packet, namespace);
+        },
+    }
+}
+
+/// Removes a namespace from the address of every message of a bundle, dropping the messages outside of the namespace
+///
+/// Returns false if no message of the bundle belongs to the namespace
+/// # Arguments
+/// * `bundle` - the [OscBundle] to strip
+/// * `namespace` - a namespace normalized by [normalize_namespace]
+pub fn strip_namespace(bundle: &mut OscBundle, namespace: &str) -> bool {
+    if namespace.is_empty() {
+        return true;
+    }
+
+    bundle.content.retain_mut(|packet| match packet {
+        OscPacket::Message(message) => match message.addr.strip_prefix(namespace) {
+            Some(addr) if addr.starts_with('/') => {
+                message.addr = addr.to_string();
+                true
+            },
+            _ => false,
+        },
+        OscPacket::Bundle(bundle) => strip_namespace(bundle, namespace),
+    });
+
+    !bundle.content.is_empty()
+}
+
+/// Replaces the nested bundles of a bundle with their messages in order, such as a frame wrapped in parent bundles by OSC routing software
+/// # Arguments
+/// * `bundle` - the [OscBundle] to flatten
+pub fn flatten_bundle(bundle: &mut OscBundle) {
+    if bundle.content.iter().all(|packet| matches!(packet, OscPacket::Message(_))) {
+        return;
+    }
+
+    let mut messages = Vec::with_capacity(bundle.content.len());
+
+    for packet in bundle.content.drain(..) {
+        match packet {
+            OscPacket::Message(_) => messages.push(packet),
+            OscPacket::Bundle(mut nested) => {
+                flatten_bundle(&mut nested);
+                messages.append(&mut nested.content);
+            },
+        }
+    }
+
+    bundle.content = messages;
+}
+
+/// Base trait to implement an OSC decoder
+pub trait DecodeOsc<T> {
+    fn decode_bundle(bundle: T) -> Result<TuioBundle, TuioError>;
+}
+
+/// An implementation of trait [DecodeOsc] based on [rosc]
+pub struct OscDecoder;
+
+fn try_unwrap_source_name(message: &OscMessage) -> Result<String, TuioError> {
+    match message.args.get(1) {
+        Some(arg) => {
+            match arg.clone().string() {
+                Some(source_name) => Ok(source_name),
+                None => Err(TuioError::WrongArgumentType(message.clone(), 1)),
+            }
+        },
+        None => Err(TuioError::MissingSource(message.clone())),
+    }
+}
+
+/// Returns the float arguments following the standard arguments of a `set` message, ignoring the arguments of other types
+fn extra_args(args: &[OscType]) -> Vec<f32> {
+    args.iter().filter_map(|arg| arg.clone().float()).collect()
+}
+
+fn try_unwrap_object_args(args: &[OscType]) -> Result<Object, u8> {
+    Ok(Object {
+        session_id: args[1].clone().int().ok_or(1)?,
+        class_id: args[2].clone().int().ok_or(2)?,
+        position: Position {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?},
+        angle: args[5].clone().float().ok_or(5)?,
+        velocity: Velocity {x: args[6].clone().float().ok_or(6)?, y: args[7].clone().float().ok_or(7)?},
+        rotation_speed: args[8].clone().float().ok_or(8)?,
+        acceleration: args[9].clone().float().ok_or(9)?,
+        rotation_acceleration: args[10].clone().float().ok_or(10)?,
+        extra_args: extra_args(&args[11..]),
+        source: None,
+        lifetime: Lifetime::default(),
+        motion_origin: MotionOrigin::default(),
+    })
+}
+
+fn try_unwrap_cursor_args(args: &[OscType]) -> Result<Cursor, u8> {
+    Ok(Cursor {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?},
+        velocity: Velocity {x: args[4].clone().float().ok_or(4)?, y: args[5].clone().float().ok_or(5)?},
+        acceleration: args[6].clone().float().ok_or(6)?,
+        extra_args: extra_args(&args[7..]),
+        source: None,
+        lifetime: Lifetime::default(),
+        motion_origin: MotionOrigin::default(),
+    })
+}
+
+fn try_unwrap_blob_args(args: &[OscType]) -> Result<Blob, u8> {
+    Ok(Blob {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?},
+        angle: args[4].clone().float().ok_or(4)?,
+        width: args[5].clone().float().ok_or(5)?,
+        height: args[6].clone().float().ok_or(6)?,
+        area: args[7].clone().float().ok_or(7)?,
+        velocity: Velocity {x: args[8].clone().float().ok_or(8)?, y: args[9].clone().float().ok_or(9)?},
+        rotation_speed: args[10].clone().float().ok_or(10)?,
+        acceleration: args[11].clone().float().ok_or(11)?,
+        rotation_acceleration: args[12].clone().float().ok_or(12)?,
+        area_speed: 0f32,
+        extra_args: extra_args(&args[13..]),
+        source: None,
+        lifetime: Lifetime::default(),
+        motion_origin: MotionOrigin::default(),
+    })
+}
+
+fn try_unwrap_cursor_3d_args(args: &[OscType]) -> Result<Cursor3D, u8> {
+    Ok(Cursor3D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position3D {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?, z: args[4].clone().float().ok_or(4)?},
+        velocity: Velocity3D {x: args[5].clone().float().ok_or(5)?, y: args[6].clone().float().ok_or(6)?, z: args[7].clone().float().ok_or(7)?},
+        acceleration: args[8].clone().float().ok_or(8)?,
+        extra_args: extra_args(&args[9..]),
+        motion_origin: MotionOrigin::default(),
+    })
+}
+
+fn try_unwrap_cursor_25d_args(args: &[OscType]) -> Result<Cursor25D, u8> {
+    Ok(Cursor25D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position3D {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?, z: args[4].clone().float().ok_or(4)?},
+        velocity: Velocity3D {x: args[5].clone().float().ok_or(5)?, y: args[6].clone().float().ok_or(6)?, z: args[7].clone().float().ok_or(7)?},
+        acceleration: args[8].clone().float().ok_or(8)?,
+        extra_args: extra_args(&args[9..]),
+        motion_origin: MotionOrigin::default(),
+    })
+}
+
+fn try_unwrap_blob_3d_args(args: &[OscType]) -> Result<Blob3D, u8> {
+    Ok(Blob3D {
+        session_id: args[1].clone().int().ok_or(1)?,
+        position: Position3D {x: args[2].clone().float().ok_or(2)?, y: args[3].clone().float().ok_or(3)?, z: args[4].clone().float().ok_or(4)?},
+        angle: Rotation3D {roll: args[5].clone().float().ok_or(5)?, pitch: args[6].clone().float().ok_or(6)?, yaw: args[7].clone().float().ok_or(7)?},
+        width: args[8].clone().float().ok_or(8)?,
+        height: args[9].clone().float().ok_or(9)?,
+        depth: args[10].clone().float().ok_or(10)?,
+        volume: args[11].clone().float().ok_or(11)?,
+        velocity: Velocity3D {x: args[12].clone().float().ok_or(12)?, y: args[13].clone().float().ok_or(13)?, z: args[14].clone().float().ok_or(14)?},
+        rotation_speed: Rotation3D {roll: args[15].clone().float().ok_or(15)?, pitch: args[16].clone().float().ok_or(16)?, yaw: args[17].clone().float().ok_or(17)?},
+        acceleration: args[18].clone().float().ok_or(18)?,
+        rotation_acceleration: args[19].clone().float().ok_or(19)?,
+        extra_args: extra_args(&args[20..]),
+        motion_origin: MotionOrigin::default(),
+    })
+}
+
+/// Decodes a TUIO 2.0 token message which carries its arguments without a command word
+fn try_unwrap_token_args(args: &[OscType]) -> Result<Token, u8> {
+    let type_user_id = args[1].clone().int().ok_or(1)? as u32;
+
+    let mut token = Token {
+        session_id: args[0].clone().int().ok_or(0)?,
+        type_id: (type_user_id >> 16) as u16,
+        user_id: (type_user_id & 0xFFFF) as u16,
+        component_id: args[2].clone().int().ok_or(2)?,
+        position: Position {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?},
+        angle: args[5].clone().float().ok_or(5)?,
+        ..Default::default()
+    };
+
+    if args.len() == 11 {
+        token.velocity = Velocity {x: args[6].clone().float().ok_or(6)?, y: args[7].clone().float().ok_or(7)?};
+        token.rotation_speed = args[8].clone().float().ok_or(8)?;
+        token.acceleration = args[9].clone().float().ok_or(9)?;
+        token.rotation_acceleration = args[10].clone().float().ok_or(10)?;
+    }
+
+    Ok(token)
+}
+
+/// Decodes a TUIO 2.0 pointer message which carries its arguments without a command word
+fn try_unwrap_pointer_args(args: &[OscType]) -> Result<Pointer, u8> {
+    let type_user_id = args[1].clone().int().ok_or(1)? as u32;
+
+    let mut pointer = Pointer {
+        session_id: args[0].clone().int().ok_or(0)?,
+        type_id: (type_user_id >> 16) as u16,
+        user_id: (type_user_id & 0xFFFF) as u16,
+        component_id: args[2].clone().int().ok_or(2)?,
+        position: Position {x: args[3].clone().float().ok_or(3)?, y: args[4].clone().float().ok_or(4)?},
+        angle: args[5].clone().float().ok_or(5)?,
+        shear: args[6].clone().float().ok_or(6)?,
+        radius: args[7].clone().float().ok_or(7)?,
+        pressure: args[8].clone().float().ok_or(8)?,
+        ..Default::default()
+    };
+
+    if args.len() == 14 {
+        pointer.velocity = Velocity {x: args[9].clone().float().ok_or(9)?, y: args[10].clone().float().ok_or(10)?};
+        pointer.pressure_speed = args[11].clone().float().ok_or(11)?;
+        pointer.acceleration = args[12].clone().float().ok_or(12)?;
+        pointer.pressure_acceleration = args[13].clone().float().ok_or(13)?;
+    }
+
+    Ok(pointer)
+}
+
+/// Decodes a TUIO 2.0 symbol message which carries its arguments without a command word
+fn try_unwrap_symbol_args(args: &[OscType]) -> Result<Symbol, u8> {
+    let type_user_id = args[1].clone().int().ok_or(1)? as u32;
+
+    Ok(Symbol {
+        session_id: args[0].clone().int().ok_or(0)?,
+        type_id: (type_user_id >> 16) as u16,
+        user_id: (type_user_id & 0xFFFF) as u16,
+        symbol_id: args[2].clone().int().ok_or(2)?,
+        group: args[3].clone().string().ok_or(3)?,
+        data: args[4].clone().string().ok_or(4)?,
+    })
+}
+
+/// Decodes a TUIO 2.0 bounds message which carries its arguments without a command word
+fn try_unwrap_bounds_args(args: &[OscType]) -> Result<Bounds, u8> {
+    let mut bounds = Bounds {
+        session_id: args[0].clone().int().ok_or(0)?,
+        position: Position {x: args[1].clone().float().ok_or(1)?, y: args[2].clone().float().ok_or(2)?},
+        angle: args[3].clone().float().ok_or(3)?,
+        width: args[4].clone().float().ok_or(4)?,
+        height: args[5].clone().float().ok_or(5)?,
+        area: args[6].clone().float().ok_or(6)?,
+        ..Default::default()
+    };
+
+    if args.len() == 12 {
+        bounds.velocity = Velocity {x: args[7].clone().float().ok_or(7)?, y: args[8].clone().float().ok_or(8)?};
+        bounds.rotation_speed = args[9].clone().float().ok_or(9)?;
+        bounds.acceleration = args[10].clone().float().ok_or(10)?;
+        bounds.rotation_acceleration = args[11].clone().float().ok_or(11)?;
+    }
+
+    Ok(bounds)
+}
+
+/// Decodes a TUIO 2.0 outer contour geometry message, a session ID followed by the coordinates of a variable number of points
+///
+/// The index of a wrong argument beyond 255 is reported as 255
+fn try_unwrap_geometry_args(args: &[OscType]) -> Result<Geometry, u8> {
+    let argument_index = |index: usize| u8::try_from(index).unwrap_or(u8::MAX);
+
+    let contour = args[1..].chunks_exact(2).enumerate().map(|(point_index, coordinates)| {
+        let index = 1 + point_index * 2;
+
+        Ok(Position {
+            x: coordinates[0].clone().float().ok_or(argument_index(index))?,
+            y: coordinates[1].clone().float().ok_or(argument_index(index + 1))?,
+        })
+    }).collect::<Result<Vec<Position>, u8>>()?;
+
+    Ok(Geometry {
+        session_id: args[0].clone().int().ok_or(0)?,
+        contour,
+    })
+}
+
+/// Decodes a TUIO 2.0 control message, a session ID followed by a variable number of boolean and float values
+///
+/// The index of a wrong argument beyond 255 is reported as 255
+fn try_unwrap_control_args(args: &[OscType]) -> Result<Control, u8> {
+    let values = args[1..].iter().enumerate().map(|(index, value)| match value {
+        OscType::Bool(value) => Ok(ControlValue::Bool(*value)),
+        OscType::Float(value) => Ok(ControlValue::Float(*value)),
+        _ => Err(u8::try_from(1 + index).unwrap_or(u8::MAX)),
+    }).collect::<Result<Vec<ControlValue>, u8>>()?;
+
+    Ok(Control {
+        session_id: args[0].clone().int().ok_or(0)?,
+        values,
+    })
+}
+
+/// Decodes the messages of a TUIO 2.0 bundle, which are identified by their address rather than by a command word
+fn decode_tuio2_message(message: &OscMessage, decoded_bundle: &mut TuioBundle) -> Result<(), TuioError> {
+    match message.addr.as_str() {
+        "/tuio2/frm" => {
+            match message.args.first() {
+                Some(OscType::Int(fseq)) => decoded_bundle.fseq = *fseq,
+                Some(_) => return Err(TuioError::WrongArgumentType(message.clone(), 0)),
+                None => return Err(TuioError::EmptyMessage(message.clone())),
+            }
+
+            if let Some(OscType::Time(timetag)) = message.args.get(1) {
+                decoded_bundle.time = bundle_time(*timetag);
+            }
+
+            // The dimension packs the width in its 16 upper bits and the height in its 16 lower bits
+            match message.args.get(2) {
+                Some(OscType::Int(dimension)) => decoded_bundle.dimension = Some(((*dimension as u32 >> 16) as u16, *dimension as u16)),
+                Some(_) => return Err(TuioError::WrongArgumentType(message.clone(), 2)),
+                None => (),
+            }
+
+            match message.args.get(3) {
+                Some(OscType::String(source_name)) => decoded_bundle.source = source_name.clone(),
+                Some(_) => return Err(TuioError::WrongArgumentType(message.clone(), 3)),
+                None => return Err(TuioError::MissingSource(message.clone())),
+            }
+        },
+        "/tuio2/alv" => {
+            decoded_bundle.alive = message.args.iter().filter_map(|e| e.clone().int()).collect();
+        },
+        "/tuio2/tok" => {
+            decoded_bundle.tuio_type = TuioBundleType::Token;
+
+            if let Set::Token(set) = decoded_bundle.set.get_or_insert(Set::Token(Vec::new())) {
+                if message.args.len() != 6 && message.args.len() != 11 {
+                    decoded_bundle.skipped.push(TuioError::MissingArguments(message.clone()));
+                    return Ok(());
+                }
+
+                match try_unwrap_token_args(&message.args) {
+                    Ok(params) => set.push(params),
+                    Err(index) => decoded_bundle.skipped.push(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        "/tuio2/ptr" => {
+            decoded_bundle.tuio_type = TuioBundleType::Pointer;
+
+            if let Set::Pointer(set) = decoded_bundle.set.get_or_insert(Set::Pointer(Vec::new())) {
+                if message.args.len() != 9 && message.args.len() != 14 {
+                    decoded_bundle.skipped.push(TuioError::MissingArguments(message.clone()));
+                    return Ok(());
+                }
+
+                match try_unwrap_pointer_args(&message.args) {
+                    Ok(params) => set.push(params),
+                    Err(index) => decoded_bundle.skipped.push(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        "/tuio2/sym" => {
+            decoded_bundle.tuio_type = TuioBundleType::Symbol;
+
+            if let Set::Symbol(set) = decoded_bundle.set.get_or_insert(Set::Symbol(Vec::new())) {
+                if message.args.len() != 5 {
+                    decoded_bundle.skipped.push(TuioError::MissingArguments(message.clone()));
+                    return Ok(());
+                }
+
+                match try_unwrap_symbol_args(&message.args) {
+                    Ok(params) => set.push(params),
+                    Err(index) => decoded_bundle.skipped.push(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        "/tuio2/bnd" => {
+            decoded_bundle.tuio_type = TuioBundleType::Bounds;
+
+            if let Set::Bounds(set) = decoded_bundle.set.get_or_insert(Set::Bounds(Vec::new())) {
+                if message.args.len() != 7 && message.args.len() != 12 {
+                    decoded_bundle.skipped.push(TuioError::MissingArguments(message.clone()));
+                    return Ok(());
+                }
+
+                match try_unwrap_bounds_args(&message.args) {
+                    Ok(params) => set.push(params),
+                    Err(index) => decoded_bundle.skipped.push(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        "/tuio2/ocg" => {
+            decoded_bundle.tuio_type = TuioBundleType::Geometry;
+
+            if let Set::Geometry(set) = decoded_bundle.set.get_or_insert(Set::Geometry(Vec::new())) {
+                if message.args.len() < 3 || message.args.len().is_multiple_of(2) {
+                    decoded_bundle.skipped.push(TuioError::MissingArguments(message.clone()));
+                    return Ok(());
+                }
+
+                match try_unwrap_geometry_args(&message.args) {
+                    Ok(params) => set.push(params),
+                    Err(index) => decoded_bundle.skipped.push(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        "/tuio2/ctl" => {
+            decoded_bundle.tuio_type = TuioBundleType::Control;
+
+            if let Set::Control(set) = decoded_bundle.set.get_or_insert(Set::Control(Vec::new())) {
+                if message.args.len() < 2 {
+                    decoded_bundle.skipped.push(TuioError::MissingArguments(message.clone()));
+                    return Ok(());
+                }
+
+                match try_unwrap_control_args(&message.args) {
+                    Ok(params) => set.push(params),
+                    Err(index) => decoded_bundle.skipped.push(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        _ => return Err(TuioError::UnknownAddress(message.clone()))
+    }
+
+    Ok(())
+}
+
+/// Decodes a TUIO 1.1 `set` message into the set of the decoded bundle
+///
+/// Trailing arguments after the standard ones are tolerated, and their float values kept as the extra arguments of the input
+fn decode_set_message(message: &OscMessage, decoded_bundle: &mut TuioBundle) -> Result<(), TuioError> {
+    match decoded_bundle.tuio_type {
+        TuioBundleType::Cursor => {
+            if let Set::Cursor(set) = decoded_bundle.set.get_or_insert(Set::Cursor(Vec::new())) {
+                if message.args.len() < 7 {
+                    return Err(TuioError::MissingArguments(message.clone()));
+                }
+
+                match try_unwrap_cursor_args(&message.args) {
+                    Ok(params) => {
+                        set.push(params);
+                    },
+                    Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        TuioBundleType::Object => {
+            if let Set::Object(set) = decoded_bundle.set.get_or_insert(Set::Object(Vec::new())) {
+                if message.args.len() < 11 {
+                    return Err(TuioError::MissingArguments(message.clone()));
+                }
+                
+                match try_unwrap_object_args(&message.args) {
+                    Ok(params) => {
+                        set.push(params);
+                    },
+                    Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        TuioBundleType::Blob => {
+            if let Set::Blob(set) = decoded_bundle.set.get_or_insert(Set::Blob(Vec::new())) {
+                if message.args.len() < 13 {
+                    return Err(TuioError::MissingArguments(message.clone()));
+                }
+
+                match try_unwrap_blob_args(&message.args) {
+                    Ok(params) => {
+                        set.push(params);
+                    },
+                    Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+
+        },
+        TuioBundleType::Cursor3D => {
+            if let Set::Cursor3D(set) = decoded_bundle.set.get_or_insert(Set::Cursor3D(Vec::new())) {
+                if message.args.len() < 9 {
+                    return Err(TuioError::MissingArguments(message.clone()));
+                }
+
+                match try_unwrap_cursor_3d_args(&message.args) {
+                    Ok(params) => {
+                        set.push(params);
+                    },
+                    Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        TuioBundleType::Cursor25D => {
+            if let Set::Cursor25D(set) = decoded_bundle.set.get_or_insert(Set::Cursor25D(Vec::new())) {
+                if message.args.len() < 9 {
+                    return Err(TuioError::MissingArguments(message.clone()));
+                }
+
+                match try_unwrap_cursor_25d_args(&message.args) {
+                    Ok(params) => {
+                        set.push(params);
+                    },
+                    Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        TuioBundleType::Blob3D => {
+            if let Set::Blob3D(set) = decoded_bundle.set.get_or_insert(Set::Blob3D(Vec::new())) {
+                if message.args.len() < 20 {
+                    return Err(TuioError::MissingArguments(message.clone()));
+                }
+
+                match try_unwrap_blob_3d_args(&message.args) {
+                    Ok(params) => {
+                        set.push(params);
+                    },
+                    Err(index) => return Err(TuioError::WrongArgumentType(message.clone(), index)),
+                }
+            }
+        },
+        TuioBundleType::Token | TuioBundleType::Pointer | TuioBundleType::Symbol | TuioBundleType::Bounds | TuioBundleType::Geometry | TuioBundleType::Control | TuioBundleType::Unknown => return Err(TuioError::UnknownMessageType(message.clone())),
+    }
+
+    Ok(())
+}
+
+/// How strictly the decoder enforces the TUIO 1.1 message ordering of `source`, `alive`, `set` and `fseq`
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// Rejects a bundle with [TuioError::UnexpectedMessageOrder] if a `set` comes before the `alive` message or if any message follows the `fseq` message,
+    /// and with [TuioError::IncompleteBundle] if the `alive` or `fseq` message is missing
+    Strict,
+    /// Decodes the `set` messages wherever they appear in the bundle, then drops those whose session ID is not in the `alive` message.
+    /// A missing `fseq` message is decoded as -1 so the bundle is always processed
+    #[default]
+    Lenient,
+}
+
+/// The version of the TUIO 1.x protocol spoken by a [crate::Server] or a [crate::Client]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuioVersion {
+    /// TUIO 1.0, whose bundles have no `source` message and which has no blob profile
+    V10,
+    /// TUIO 1.1, whose bundles start with a `source` message naming the tracker
+    #[default]
+    V11,
+}
+
+/// Converts a TUIO 1.1 profile bundle to the TUIO 1.0 layout by removing its `source` message
+///
+/// Returns [None] for a bundle of a profile which does not exist in TUIO 1.0, such as the blob profile
+/// # Arguments
+/// * `bundle` - a TUIO 1.1 profile bundle, such as the ones returned by [OscEncoder]
+pub fn to_tuio_10(mut bundle: OscBundle) -> Option<OscBundle> {
+    let is_blob = |packet: &OscPacket| matches!(packet, OscPacket::Message(message) if message.addr == "/tuio/2Dblb" || message.addr == "/tuio/3Dblb");
+
+    if bundle.content.iter().any(is_blob) {
+        return None;
+    }
+
+    bundle.content.retain(|packet| !matches!(packet, OscPacket::Message(message) if matches!(message.args.first(), Some(OscType::String(command)) if command == "source")));
+    Some(bundle)
+}
+
+/// How the normalized coordinates of received inputs outside of the 0 to 1 range are handled
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundsPolicy {
+    /// Clamps every coordinate between 0 and 1
+    Clamp,
+    /// Drops the `set` message of the input, which keeps its previous state if it was already alive
+    Reject,
+    /// Keeps the coordinates as received
+    #[default]
+    Passthrough,
+}
+
+impl BoundsPolicy {
+    /// Applies the policy to normalized coordinates
+    ///
+    /// Returns false if the input must be dropped
+    fn apply(&self, coordinates: &mut [&mut f32]) -> bool {
+        match self {
+            BoundsPolicy::Clamp => {
+                for coordinate in coordinates.iter_mut() {
+                    **coordinate = coordinate.clamp(0., 1.);
+                }
+
+                true
+            },
+            BoundsPolicy::Reject => coordinates.iter().all(|coordinate| (0. ..=1.).contains(*coordinate)),
+            BoundsPolicy::Passthrough => true,
+        }
+    }
+
+    /// Applies the policy to the positions of every input of a decoded [Set]. [Symbol]s and [Control]s have no coordinates and are left untouched
+    pub(crate) fn apply_set(&self, set: &mut Set) {
+        if *self == BoundsPolicy::Passthrough {
+            return;
+        }
+
+        match set {
+            Set::Cursor(cursors) => cursors.retain_mut(|cursor| self.apply(&mut [&mut cursor.position.x, &mut cursor.position.y])),
+            Set::Object(objects) => objects.retain_mut(|object| self.apply(&mut [&mut object.position.x, &mut object.position.y])),
+            Set::Blob(blobs) => blobs.retain_mut(|blob| self.apply(&mut [&mut blob.position.x, &mut blob.position.y])),
+            Set::Cursor3D(cursors) => cursors.retain_mut(|cursor| self.apply(&mut [&mut cursor.position.x, &mut cursor.position.y, &mut cursor.position.z])),
+            Set::Cursor25D(cursors) => cursors.retain_mut(|cursor| self.apply(&mut [&mut cursor.position.x, &mut cursor.position.y, &mut cursor.position.z])),
+            Set::Blob3D(blobs) => blobs.retain_mut(|blob| self.apply(&mut [&mut blob.position.x, &mut blob.position.y, &mut blob.position.z])),
+            Set::Token(tokens) => tokens.retain_mut(|token| self.apply(&mut [&mut token.position.x, &mut token.position.y])),
+            Set::Pointer(pointers) => pointers.retain_mut(|pointer| self.apply(&mut [&mut pointer.position.x, &mut pointer.position.y])),
+            Set::Symbol(_) => (),
+            Set::Bounds(bounds) => bounds.retain_mut(|bounds| self.apply(&mut [&mut bounds.position.x, &mut bounds.position.y])),
+            Set::Geometry(geometries) => geometries.retain_mut(|geometry| {
+                self.apply(&mut geometry.contour.iter_mut().flat_map(|point| [&mut point.x, &mut point.y]).collect::<Vec<_>>())
+            }),
+            Set::Control(_) => (),
+        }
+    }
+}
+
+/// Keeps the inputs of a [Set] whose session ID is alive
+fn retain_alive(set: &mut Set, alive: &[i32]) {
+    let alive: std::collections::HashSet<&i32> = alive.iter().collect();
+    set.retain_session_ids(|session_id| alive.contains(&session_id));
+}
+
+/// Tags the inputs of a [Set] with the name of the source which sent them
+///
+/// Only the TUIO 1.1 profiles carry a source, the TUIO 2.0 ones are left untouched
+fn tag_source(set: &mut Set, source: &str) {
+    match set {
+        Set::Cursor(cursors) => cursors.iter_mut().for_each(|cursor| cursor.source = Some(source.to_string())),
+        Set::Object(objects) => objects.iter_mut().for_each(|object| object.source = Some(source.to_string())),
+        Set::Blob(blobs) => blobs.iter_mut().for_each(|blob| blob.source = Some(source.to_string())),
+        _ => (),
+    }
+}
+
+impl OscDecoder {
+    /// Decodes a TUIO bundle, enforcing the message ordering according to a [Strictness]
+    /// # Arguments
+    /// * `bundle` - the OSC bundle to decode
+    /// * `strictness` - the [Strictness] of the message ordering
+    pub fn decode_bundle_with_strictness(bundle: OscBundle, strictness: Strictness) -> Result<TuioBundle, TuioError> {
+        Self::decode_bundle_with_version(bundle, strictness, TuioVersion::V11)
+    }
+
+    /// Decodes a TUIO 2.0 frame, a bundle whose `/tuio2/alv` message lists the session IDs of the components of every TUIO 2.0 profile
+    ///
+    /// Returns one [TuioBundle] per TUIO 2.0 profile, each with the frame sequence number, time, source and alive session IDs of the frame.
+    /// The [TuioBundle] of a profile without any component in the frame has no set, so its inputs are only reconciled with the alive session IDs
+    /// # Arguments
+    /// * `bundle` - the OSC bundle to decode
+    /// * `strictness` - the [Strictness] of the message ordering
+    pub fn decode_tuio2_frame(bundle: OscBundle, strictness: Strictness) -> Result<Vec<TuioBundle>, TuioError> {
+        for packet in &bundle.content {
+            if let OscPacket::Message(message) = packet {
+                if !matches!(message.addr.as_str(), "/tuio2/frm" | "/tuio2/alv") && !TuioBundleType::from_address(&message.addr).is_some_and(|profile| profile.is_tuio2()) {
+                    return Err(TuioError::UnknownAddress(message.clone()));
+                }
+            }
+        }
+
+        TuioBundleType::TUIO2_PROFILES.iter().map(|profile| {
+            let content = bundle.content.iter()
+                .filter(|packet| matches!(packet, OscPacket::Message(message) if TuioBundleType::from_address(&message.addr).is_none_or(|message_profile| message_profile == *profile)))
+                .cloned()
+                .collect();
+
+            let mut decoded_bundle = Self::decode_bundle_with_strictness(OscBundle { timetag: bundle.timetag, content }, strictness)?;
+            decoded_bundle.tuio_type = *profile;
+            Ok(decoded_bundle)
+        }).collect()
+    }
+
+    /// Decodes a TUIO bundle of a [TuioVersion], enforcing the message ordering according to a [Strictness]
+    ///
+    /// A TUIO 1.0 bundle has no `source` message, so its profile is given by the address of its first message and its source name is empty
+    /// # Arguments
+    /// * `bundle` - the OSC bundle to decode
+    /// * `strictness` - the [Strictness] of the message ordering
+    /// * `version` - the [TuioVersion] of the bundle
+    pub fn decode_bundle_with_version(bundle: OscBundle, strictness: Strictness, version: TuioVersion) -> Result<TuioBundle, TuioError> {
+        let mut decoded_bundle = TuioBundle {
+            tuio_type: TuioBundleType::default(),
+            source: String::new(),
+            time: bundle_time(bundle.timetag),
+            alive: Vec::new(),
+            set: None,
+            fseq: -1,
+            dimension: None,
+            skipped: Vec::new(),
+        };
+
+        let mut has_alive = false;
+        let mut has_fseq = false;
+        let mut set_messages = Vec::new();
+        
+        for packet in &bundle.content {
+            if let OscPacket::Message(message) = packet {
+                if message.addr.starts_with("/tuio2/") {
+                    match message.addr.as_str() {
+                        "/tuio2/frm" => has_fseq = true,
+                        "/tuio2/alv" => has_alive = true,
+                        _ => (),
+                    }
+
+                    decode_tuio2_message(message, &mut decoded_bundle)?;
+                    continue;
+                }
+
+                if strictness == Strictness::Strict && has_fseq {
+                    return Err(TuioError::UnexpectedMessageOrder(message.clone()));
+                }
+
+                if version == TuioVersion::V10 && decoded_bundle.tuio_type == TuioBundleType::Unknown {
+                    decoded_bundle.tuio_type = TuioBundleType::from_address(&message.addr).ok_or_else(|| TuioError::UnknownAddress(message.clone()))?;
+                }
+
+                match message.args.first() {
+                    Some(OscType::String(arg)) => {
+                        match arg.as_str() {
+                            "source" => {
+                                decoded_bundle.tuio_type = match message.addr.as_str() {
+                                    "/tuio/2Dobj" => TuioBundleType::Object,
+                                    "/tuio/2Dcur" => TuioBundleType::Cursor,
+                                    "/tuio/2Dblb" => TuioBundleType::Blob,
+                                    "/tuio/3Dcur" => TuioBundleType::Cursor3D,
+                                    "/tuio/25Dcur" => TuioBundleType::Cursor25D,
+                                    "/tuio/3Dblb" => TuioBundleType::Blob3D,
+                                    _ => return Err(TuioError::UnknownAddress(message.clone()))
+                                };
+
+                                decoded_bundle.source = try_unwrap_source_name(message)?;
+                            },
+                            "alive" => {
+                                decoded_bundle.alive = message.args.iter().skip(1).filter_map(|e| e.clone().int()).collect();
+                                has_alive = true;
+                            },
+                            "set" => {
+                                if strictness == Strictness::Strict && !has_alive {
+                                    return Err(TuioError::UnexpectedMessageOrder(message.clone()));
+                                }
+
+                                set_messages.push(message);
+                            },
+                            "fseq" => {
+                                if let Some(OscType::Int(fseq)) = message.args.get(1) {
+                                    decoded_bundle.fseq = *fseq;
+                                    has_fseq = true;
+                                }
+                                else {
+                                    return Err(TuioError::MissingArguments(message.clone()))
+                                }
+                            },
+                            _ => return Err(TuioError::UnknownMessageType(message.clone()))
+                        }
+                    },
+                    None => return Err(TuioError::EmptyMessage(message.clone())),
+                    _ => return Err(TuioError::UnknownMessageType(message.clone()))
+                }
+            };
+        }
+
+        if !set_messages.is_empty() {
+            if let TuioBundleType::Token | TuioBundleType::Pointer | TuioBundleType::Symbol | TuioBundleType::Bounds | TuioBundleType::Geometry | TuioBundleType::Control | TuioBundleType::Unknown = decoded_bundle.tuio_type {
+                return Err(TuioError::IncompleteBundle(bundle));
+            }
+
+            for message in set_messages {
+                if let Err(err) = decode_set_message(message, &mut decoded_bundle) {
+                    decoded_bundle.skipped.push(err);
+                }
+            }
+        }
+
+        match strictness {
+            Strictness::Strict => if !has_alive || !has_fseq {
+                return Err(TuioError::IncompleteBundle(bundle));
+            },
+            Strictness::Lenient => if let (true, Some(set)) = (has_alive, &mut decoded_bundle.set) {
+                retain_alive(set, &decoded_bundle.alive);
+            },
+        }
+
+        if let (false, Some(set)) = (decoded_bundle.source.is_empty(), &mut decoded_bundle.set) {
+            tag_source(set, &decoded_bundle.source);
+        }
+
+        Ok(decoded_bundle)
+    }
+}
+
+impl DecodeOsc<OscBundle> for OscDecoder {
+    /// Decodes a TUIO bundle with the [Strictness::Lenient] message ordering
+    fn decode_bundle(bundle: OscBundle) -> Result<TuioBundle, TuioError> {
+        Self::decode_bundle_with_strictness(bundle, Strictness::Lenient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cursor::{Cursor, Position}, cursor_3d::{Cursor3D, Position3D}, cursor_25d::Cursor25D, object::Object, blob::Blob, blob_3d::{Blob3D, Rotation3D}, token::Token, pointer::Pointer, symbol::Symbol, bounds::Bounds, geometry::Geometry, control::{Control, ControlValue}, cursor::Velocity, osc_encode_decode::{OscEncoder, EncodeOsc}};
+
+    use super::*;
+
+    #[test]
+    fn encoding_decoding() {
+        let source = "test".to_string();
+
+        let cursors = vec![Cursor::new(0, Position {x: 0., y: 0.}), Cursor::new(1, Position {x: 0.5, y: 0.5})];
+        let objects = vec![Object::new(0, 0, Position {x: 0., y: 0.}, 0.), Object::new(1, 1, Position {x: 0.5, y: 0.5}, 0.)];
+        let blobs = vec![Blob::new(0, Position {x: 0., y: 0.}, 0., 0.3, 0.3, 0.09), Blob::new(1, Position {x: 0.5, y: 0.5}, 0., 0.5, 0.5, 0.25)];
+
+        let cursor_bundle = OscEncoder::encode_cursor_bundle(&cursors, source.clone(), 0);
+        let object_bundle = OscEncoder::encode_object_bundle(&objects, source.clone(), 0);
+        let cursors_3d = vec![Cursor3D::new(0, Position3D {x: 0., y: 0., z: 0.}), Cursor3D::new(1, Position3D {x: 0.5, y: 0.5, z: 0.5})];
+        let blobs_3d = vec![Blob3D::new(0, Position3D {x: 0., y: 0., z: 0.}, Rotation3D::default(), 0.3, 0.3, 0.3, 0.027), Blob3D::new(1, Position3D {x: 0.5, y: 0.5, z: 0.5}, Rotation3D {roll: 0.1, pitch: 0.2, yaw: 0.3}, 0.5, 0.5, 0.5, 0.125)];
+        let tokens = vec![Token::new(0, 3, 1, 0, Position {x: 0., y: 0.}, 0.), Token::new(1, 4, 2, 1, Position {x: 0.5, y: 0.5}, 0.)];
+
+        let blob_bundle = OscEncoder::encode_blob_bundle(&blobs, source.clone(), 0);
+        let cursor_3d_bundle = OscEncoder::encode_cursor_3d_bundle(&cursors_3d, source.clone(), 0);
+        let blob_3d_bundle = OscEncoder::encode_blob_3d_bundle(&blobs_3d, source.clone(), 0);
+        let pointers = vec![Pointer::new(0, 1, 0, 0, Position {x: 0., y: 0.}, 0., 0., 0.01, 0.2), Pointer::new(1, 2, 1, 0, Position {x: 0.5, y: 0.5}, 0.1, 0.2, 0.02, 0.8)];
+        let token_bundle = OscEncoder::encode_token_bundle(&tokens, source.clone(), 0);
+        let pointer_bundle = OscEncoder::encode_pointer_bundle(&pointers, source, 0);
+
+        match OscDecoder::decode_bundle(cursor_bundle) {
+            Ok(decoded_bundle) => {
+                if let Some(Set::Cursor(decoded_cursors)) = decoded_bundle.set {
+                    assert_eq!(decoded_cursors.len(), 2);
+                    assert_eq!(cursors[0], decoded_cursors[0]);
+                    assert_eq!(cursors[1], decoded_cursors[1]);
+                }
+            },
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(object_bundle) {
             Ok(decoded_bundle) => {
                 if let Some(Set::Object(decoded_objects)) = decoded_bundle.set {
                     assert_eq!(decoded_objects.len(), 2);
@@ -423,5 +1808,469 @@ mod tests {
             },
             Err(err) => {println!("{err}"); panic!()},
         }
+
+        match OscDecoder::decode_bundle(cursor_3d_bundle) {
+            Ok(decoded_bundle) => {
+                if let Some(Set::Cursor3D(decoded_cursors)) = decoded_bundle.set {
+                    assert_eq!(decoded_cursors.len(), 2);
+                    assert_eq!(cursors_3d[0], decoded_cursors[0]);
+                    assert_eq!(cursors_3d[1], decoded_cursors[1]);
+                }
+            },
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(blob_3d_bundle) {
+            Ok(decoded_bundle) => {
+                if let Some(Set::Blob3D(decoded_blobs)) = decoded_bundle.set {
+                    assert_eq!(decoded_blobs.len(), 2);
+                    assert_eq!(blobs_3d[0], decoded_blobs[0]);
+                    assert_eq!(blobs_3d[1], decoded_blobs[1]);
+                }
+            },
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(token_bundle) {
+            Ok(decoded_bundle) => {
+                assert_eq!(decoded_bundle.alive, vec![0, 1]);
+                if let Some(Set::Token(decoded_tokens)) = decoded_bundle.set {
+                    assert_eq!(decoded_tokens.len(), 2);
+                    assert_eq!(tokens[0], decoded_tokens[0]);
+                    assert_eq!(tokens[1], decoded_tokens[1]);
+                }
+            },
+            Err(err) => {println!("{err}"); panic!()},
+        }
+
+        match OscDecoder::decode_bundle(pointer_bundle) {
+            Ok(decoded_bundle) => {
+                assert_eq!(decoded_bundle.alive, vec![0, 1]);
+                if let Some(Set::Pointer(decoded_pointers)) = decoded_bundle.set {
+                    assert_eq!(decoded_pointers.len(), 2);
+                    assert_eq!(pointers[0], decoded_pointers[0]);
+                    assert_eq!(pointers[1], decoded_pointers[1]);
+                }
+            },
+            Err(err) => {println!("{err}"); panic!()},
+        }
+    }
+
+    #[test]
+    fn malformed_set_is_skipped() {
+        let cursors = vec![Cursor::new(0, Position {x: 0., y: 0.}), Cursor::new(1, Position {x: 0.5, y: 0.5}), Cursor::new(2, Position {x: 1., y: 1.})];
+        let mut bundle = OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 0);
+
+        if let OscPacket::Message(message) = &mut bundle.content[2] {
+            message.args.pop();
+        }
+
+        if let OscPacket::Message(message) = &mut bundle.content[3] {
+            message.args[2] = OscType::String("x".into());
+        }
+
+        let decoded_bundle = OscDecoder::decode_bundle(bundle).unwrap();
+
+        assert_eq!(decoded_bundle.alive, vec![0, 1, 2]);
+        assert!(matches!(decoded_bundle.set, Some(Set::Cursor(ref decoded_cursors)) if decoded_cursors == &cursors[2..]));
+        assert_eq!(decoded_bundle.skipped.len(), 2);
+        assert!(matches!(decoded_bundle.skipped[0], TuioError::MissingArguments(_)));
+        assert!(matches!(decoded_bundle.skipped[1], TuioError::WrongArgumentType(_, 2)));
+    }
+
+    #[test]
+    fn extra_set_arguments() {
+        let cursors = vec![Cursor::new(0, Position {x: 0.25, y: 0.5}), Cursor::new(1, Position {x: 0.5, y: 0.5})];
+        let mut bundle = OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 0);
+
+        if let OscPacket::Message(message) = &mut bundle.content[2] {
+            message.args.extend([OscType::Float(0.8), OscType::String("vendor".into()), OscType::Float(-1.)]);
+        }
+
+        let decoded_bundle = OscDecoder::decode_bundle_with_strictness(bundle, Strictness::Strict).unwrap();
+
+        assert!(decoded_bundle.skipped.is_empty());
+        assert!(matches!(decoded_bundle.set, Some(Set::Cursor(ref decoded_cursors)) if decoded_cursors == &cursors));
+
+        if let Some(Set::Cursor(decoded_cursors)) = &decoded_bundle.set {
+            assert_eq!(decoded_cursors[0].get_extra_args(), &[0.8, -1.]);
+            assert!(decoded_cursors[1].get_extra_args().is_empty());
+        }
+    }
+
+    #[test]
+    fn object_ids() {
+        let set_args = |session_id: i32, class_id: i32| vec![
+            OscType::String("set".into()), OscType::Int(session_id), OscType::Int(class_id),
+            OscType::Float(0.5), OscType::Float(0.5), OscType::Float(0.),
+            OscType::Float(0.), OscType::Float(0.), OscType::Float(0.), OscType::Float(0.), OscType::Float(0.)
+        ];
+        let message = |args: Vec<OscType>| OscPacket::Message(OscMessage {addr: "/tuio/2Dobj".into(), args});
+
+        let bundle = OscBundle {
+            timetag: OscTime {seconds: 0, fractional: 1},
+            content: vec![
+                message(vec![OscType::String("source".into()), OscType::String("test".into())]),
+                message(vec![OscType::String("alive".into()), OscType::Int(12), OscType::Int(13)]),
+                message(set_args(12, 7)),
+                message(set_args(13, 7)),
+                message(vec![OscType::String("fseq".into()), OscType::Int(1)]),
+            ]
+        };
+
+        match OscDecoder::decode_bundle(bundle).unwrap().set {
+            Some(Set::Object(objects)) => {
+                assert_eq!(objects[0].get_session_id(), 12);
+                assert_eq!(objects[0].get_class_id(), 7);
+                assert_eq!(objects[1].get_session_id(), 13);
+                assert_eq!(objects[1].get_class_id(), 7);
+            },
+            _ => panic!("missing object set"),
+        }
+    }
+
+    #[test]
+    fn time_tag() {
+        let now = SystemTime::now();
+        let immediate = bundle_time(OscTime { seconds: 0, fractional: 1 });
+        assert!(immediate >= now);
+
+        let time = now - std::time::Duration::from_secs(10);
+        let bundle = OscBundle { timetag: encode_time_tag(time), content: vec![] };
+        let decoded = OscDecoder::decode_bundle(bundle).unwrap().time;
+        assert!(decoded.duration_since(time).unwrap_or_else(|err| err.duration()) < std::time::Duration::from_millis(1));
+    }
+
+    #[test]
+    fn time_tag_conversion() {
+        let time = UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789);
+        let timetag = encode_time_tag(time);
+
+        assert_eq!(timetag.seconds, 3_908_988_800);
+        assert_eq!(timetag.fractional, 530_242_871);
+
+        let decoded = decode_time_tag(timetag);
+        assert!(decoded.duration_since(time).unwrap_or_else(|err| err.duration()) < Duration::from_micros(1));
+
+        let now = SystemTime::now();
+        let decoded = decode_time_tag(encode_time_tag(now));
+        assert!(decoded.duration_since(now).unwrap_or_else(|err| err.duration()) < Duration::from_micros(1));
+
+        assert_eq!(decode_time_tag(OscTime { seconds: 0, fractional: 1 }), UNIX_EPOCH);
+        assert_eq!(encode_time_tag(UNIX_EPOCH), OscTime { seconds: 2_208_988_800, fractional: 0 });
+    }
+
+    #[test]
+    fn blob_rotation_round_trip() {
+        let blob = Blob::new(0, Position {x: 0.5, y: 0.5}, 1.5, 0.2, 0.1, 0.02).with_motion(Velocity {x: 0.1, y: -0.2}, 0.25, 0.5, -0.125);
+        let bundle = OscEncoder::encode_blob_bundle(std::iter::once(&blob), "test".into(), 0);
+
+        let bytes = rosc::encoder::encode(&OscPacket::Bundle(bundle)).unwrap();
+        let decoded_bundle = match rosc::decoder::decode_udp(&bytes).unwrap().1 {
+            OscPacket::Bundle(bundle) => OscDecoder::decode_bundle(bundle).unwrap(),
+            packet => panic!("not a bundle: {:?}", packet),
+        };
+
+        match decoded_bundle.set {
+            Some(Set::Blob(decoded_blobs)) => {
+                assert_eq!(decoded_blobs[0].get_angle(), 1.5);
+                assert_eq!(decoded_blobs[0].get_rotation_speed(), 0.25);
+                assert_eq!(decoded_blobs[0].get_rotation_acceleration(), -0.125);
+                assert_eq!(decoded_blobs[0], blob);
+            },
+            _ => panic!("missing blob set"),
+        }
+    }
+
+    #[test]
+    fn source_tagging() {
+        let cursors = [Cursor::new(0, Position {x: 0.5, y: 0.5})];
+        assert_eq!(cursors[0].get_source(), None);
+
+        let decoded_bundle = OscDecoder::decode_bundle(OscEncoder::encode_cursor_bundle(&cursors, "Reactivision".into(), 0)).unwrap();
+
+        match decoded_bundle.set {
+            Some(Set::Cursor(decoded_cursors)) => assert_eq!(decoded_cursors[0].get_source(), Some("Reactivision")),
+            _ => panic!("missing cursor set"),
+        }
+
+        let decoded_bundle = OscDecoder::decode_bundle(OscEncoder::encode_cursor_bundle(&cursors, String::new(), 1)).unwrap();
+
+        match decoded_bundle.set {
+            Some(Set::Cursor(decoded_cursors)) => assert_eq!(decoded_cursors[0].get_source(), None),
+            _ => panic!("missing cursor set"),
+        }
+    }
+
+    #[test]
+    fn cursor_25d_round_trip() {
+        let cursors = vec![Cursor25D::new(0, Position3D {x: 0.5, y: 0.5, z: 0.1}).with_motion(Velocity3D {x: 0.1, y: 0., z: -0.2}, 0.5)];
+        let bundle = OscEncoder::encode_cursor_25d_bundle(&cursors, "test".into(), 3);
+
+        let bytes = rosc::encoder::encode(&OscPacket::Bundle(bundle)).unwrap();
+        let decoded_bundle = match rosc::decoder::decode_udp(&bytes).unwrap().1 {
+            OscPacket::Bundle(bundle) => OscDecoder::decode_bundle_with_strictness(bundle, Strictness::Strict).unwrap(),
+            packet => panic!("not a bundle: {:?}", packet),
+        };
+
+        assert_eq!(decoded_bundle.tuio_type, TuioBundleType::Cursor25D);
+        assert_eq!(decoded_bundle.fseq, 3);
+
+        match decoded_bundle.set {
+            Some(Set::Cursor25D(decoded_cursors)) => {
+                assert_eq!(decoded_cursors, cursors);
+                assert!(decoded_cursors[0].is_approaching());
+            },
+            _ => panic!("missing 2.5D cursor set"),
+        }
+    }
+
+    #[test]
+    fn bounds_policy() {
+        let cursors = || Set::Cursor(vec![Cursor::new(0, Position {x: -0.01, y: 0.5}), Cursor::new(1, Position {x: 0.5, y: 1.02}), Cursor::new(2, Position {x: 0.5, y: 0.5})]);
+        let positions = |set: Set| match set {
+            Set::Cursor(cursors) => cursors.iter().map(|cursor| (cursor.get_x_position(), cursor.get_y_position())).collect::<Vec<_>>(),
+            _ => panic!("not a cursor set"),
+        };
+
+        let mut set = cursors();
+        BoundsPolicy::Passthrough.apply_set(&mut set);
+        assert_eq!(positions(set), vec![(-0.01, 0.5), (0.5, 1.02), (0.5, 0.5)]);
+
+        let mut set = cursors();
+        BoundsPolicy::Clamp.apply_set(&mut set);
+        assert_eq!(positions(set), vec![(0., 0.5), (0.5, 1.), (0.5, 0.5)]);
+
+        let mut set = cursors();
+        BoundsPolicy::Reject.apply_set(&mut set);
+        assert_eq!(positions(set), vec![(0.5, 0.5)]);
+    }
+
+    #[test]
+    fn symbol_round_trip() {
+        let symbols = vec![Symbol::new(0, 1, 2, 3, "qr", "https://example.com/token/7"), Symbol::new(1, 1, 0, 4, "dmtx", "")];
+        let bundle = OscEncoder::encode_symbol_bundle(&symbols, "test".into(), 5);
+
+        let bytes = rosc::encoder::encode(&OscPacket::Bundle(bundle)).unwrap();
+        let decoded_bundle = match rosc::decoder::decode_udp(&bytes).unwrap().1 {
+            OscPacket::Bundle(bundle) => OscDecoder::decode_bundle_with_strictness(bundle, Strictness::Strict).unwrap(),
+            packet => panic!("not a bundle: {:?}", packet),
+        };
+
+        assert_eq!(decoded_bundle.tuio_type, TuioBundleType::Symbol);
+        assert_eq!(decoded_bundle.source, "test");
+        assert_eq!(decoded_bundle.fseq, 5);
+        assert_eq!(decoded_bundle.alive, vec![0, 1]);
+
+        match decoded_bundle.set {
+            Some(Set::Symbol(decoded_symbols)) => {
+                assert_eq!(decoded_symbols[0].get_type_id(), 1);
+                assert_eq!(decoded_symbols[0].get_user_id(), 2);
+                assert_eq!(decoded_symbols[0].get_symbol_id(), 3);
+                assert_eq!(decoded_symbols[0].get_group(), "qr");
+                assert_eq!(decoded_symbols[0].get_data(), "https://example.com/token/7");
+                assert_eq!(decoded_symbols, symbols);
+            },
+            _ => panic!("missing symbol set"),
+        }
+    }
+
+    #[test]
+    fn geometry_round_trip() {
+        let triangle = Geometry::new(0, vec![Position { x: 0.1, y: 0.1 }, Position { x: 0.5, y: 0.1 }, Position { x: 0.3, y: 0.4 }]);
+        let point = Geometry::new(1, vec![Position { x: 0.8, y: 0.8 }]);
+        let mut bundle = OscEncoder::encode_geometry_bundle(&[triangle.clone(), point.clone()], "test".into(), 5);
+
+        bundle.content.insert(1, OscPacket::Message(OscMessage {
+            addr: "/tuio2/ocg".into(),
+            args: vec![OscType::Int(2), OscType::Float(0.5), OscType::Float(0.5), OscType::Float(0.5)]
+        }));
+        bundle.content.insert(1, OscPacket::Message(OscMessage {
+            addr: "/tuio2/ocg".into(),
+            args: vec![OscType::Int(3), OscType::Float(0.5), OscType::Int(1)]
+        }));
+
+        let bytes = rosc::encoder::encode(&OscPacket::Bundle(bundle)).unwrap();
+        let decoded_bundle = match rosc::decoder::decode_udp(&bytes).unwrap().1 {
+            OscPacket::Bundle(bundle) => OscDecoder::decode_bundle_with_strictness(bundle, Strictness::Strict).unwrap(),
+            packet => panic!("not a bundle: {:?}", packet),
+        };
+
+        assert_eq!(decoded_bundle.tuio_type, TuioBundleType::Geometry);
+        assert_eq!(decoded_bundle.alive, vec![0, 1]);
+        assert!(matches!(&decoded_bundle.skipped[..], [TuioError::WrongArgumentType(_, 2), TuioError::MissingArguments(_)]));
+
+        match decoded_bundle.set {
+            Some(Set::Geometry(decoded_geometries)) => assert_eq!(decoded_geometries, vec![triangle, point]),
+            _ => panic!("missing geometry set"),
+        }
+    }
+
+    #[test]
+    fn control_round_trip() {
+        let slider = Control::new(0, vec![ControlValue::Float(0.25), ControlValue::Float(-0.75)]);
+        let buttons = Control::new(1, vec![ControlValue::Bool(true), ControlValue::Bool(false), ControlValue::Float(1.)]);
+        let mut bundle = OscEncoder::encode_control_bundle(&[slider.clone(), buttons.clone()], "test".into(), 5);
+
+        bundle.content.insert(1, OscPacket::Message(OscMessage {
+            addr: "/tuio2/ctl".into(),
+            args: vec![OscType::Int(2)]
+        }));
+        bundle.content.insert(1, OscPacket::Message(OscMessage {
+            addr: "/tuio2/ctl".into(),
+            args: vec![OscType::Int(3), OscType::Float(0.5), OscType::Int(1)]
+        }));
+
+        let bytes = rosc::encoder::encode(&OscPacket::Bundle(bundle)).unwrap();
+        let decoded_bundle = match rosc::decoder::decode_udp(&bytes).unwrap().1 {
+            OscPacket::Bundle(bundle) => OscDecoder::decode_bundle_with_strictness(bundle, Strictness::Strict).unwrap(),
+            packet => panic!("not a bundle: {:?}", packet),
+        };
+
+        assert_eq!(decoded_bundle.tuio_type, TuioBundleType::Control);
+        assert_eq!(decoded_bundle.alive, vec![0, 1]);
+        assert!(matches!(&decoded_bundle.skipped[..], [TuioError::WrongArgumentType(_, 2), TuioError::MissingArguments(_)]));
+
+        match decoded_bundle.set {
+            Some(Set::Control(decoded_controls)) => assert_eq!(decoded_controls, vec![slider, buttons]),
+            _ => panic!("missing control set"),
+        }
+    }
+
+    #[test]
+    fn bounds_round_trip() {
+        let bounds = vec![
+            Bounds::new(0, Position {x: 0.25, y: 0.5}, 0.5, 0.1, 0.2, 0.015),
+            Bounds::new(1, Position {x: 0.75, y: 0.5}, 0., 0.3, 0.3, 0.07).with_motion(Velocity {x: 0.1, y: -0.1}, 0.25, 0.5, -0.5),
+        ];
+        let bundle = OscEncoder::encode_bounds_bundle(&bounds, "test".into(), 3);
+
+        let bytes = rosc::encoder::encode(&OscPacket::Bundle(bundle)).unwrap();
+        let decoded_bundle = match rosc::decoder::decode_udp(&bytes).unwrap().1 {
+            OscPacket::Bundle(bundle) => OscDecoder::decode_bundle_with_strictness(bundle, Strictness::Strict).unwrap(),
+            packet => panic!("not a bundle: {:?}", packet),
+        };
+
+        assert_eq!(decoded_bundle.tuio_type, TuioBundleType::Bounds);
+        assert_eq!(decoded_bundle.fseq, 3);
+        assert_eq!(decoded_bundle.alive, vec![0, 1]);
+        assert!(matches!(decoded_bundle.set, Some(Set::Bounds(decoded_bounds)) if decoded_bounds == bounds));
+    }
+
+    #[test]
+    fn wire_round_trip() {
+        let cursors = vec![
+            Cursor::new(0, Position {x: 0.1, y: 0.9}).with_motion(Velocity {x: 0.5, y: -0.5}, 0.25),
+            Cursor::new(1, Position {x: 0.9, y: 0.1}),
+        ];
+        let objects = vec![
+            Object::new(2, 7, Position {x: 0.25, y: 0.75}, 3.).with_motion(Velocity {x: -0.1, y: 0.2}, 0.5, 0.125, -0.25),
+            Object::new(3, 8, Position {x: 0.75, y: 0.25}, 0.5),
+        ];
+        let blobs = vec![
+            Blob::new(4, Position {x: 0.3, y: 0.6}, 1., 0.2, 0.1, 0.015).with_motion(Velocity {x: 0.3, y: 0.4}, -0.5, 0.75, 0.0625),
+            Blob::new(5, Position {x: 0.6, y: 0.3}, 2., 0.05, 0.4, 0.02),
+        ];
+
+        let bundle = OscPacket::Bundle(OscBundle {
+            timetag: OscTime {seconds: 0, fractional: 1},
+            content: vec![
+                OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 1)),
+                OscPacket::Bundle(OscEncoder::encode_object_bundle(&objects, "test".into(), 1)),
+                OscPacket::Bundle(OscEncoder::encode_blob_bundle(&blobs, "test".into(), 1)),
+            ],
+        });
+
+        let bytes = rosc::encoder::encode(&bundle).unwrap();
+        let (_, packet) = rosc::decoder::decode_udp(&bytes).unwrap();
+
+        let decoded_bundles: Vec<TuioBundle> = match packet {
+            OscPacket::Bundle(bundle) => bundle.content.into_iter().map(|packet| match packet {
+                OscPacket::Bundle(bundle) => OscDecoder::decode_bundle(bundle).unwrap(),
+                packet => panic!("not a bundle: {:?}", packet),
+            }).collect(),
+            packet => panic!("not a bundle: {:?}", packet),
+        };
+
+        assert_eq!(decoded_bundles.len(), 3);
+
+        for decoded_bundle in &decoded_bundles {
+            assert_eq!(decoded_bundle.source, "test");
+            assert_eq!(decoded_bundle.fseq, 1);
+            assert!(decoded_bundle.skipped.is_empty());
+        }
+
+        assert_eq!(decoded_bundles[0].alive, vec![0, 1]);
+        assert_eq!(decoded_bundles[1].alive, vec![2, 3]);
+        assert_eq!(decoded_bundles[2].alive, vec![4, 5]);
+
+        assert!(matches!(&decoded_bundles[0].set, Some(Set::Cursor(decoded)) if decoded == &cursors));
+        assert!(matches!(&decoded_bundles[1].set, Some(Set::Object(decoded)) if decoded == &objects));
+        assert!(matches!(&decoded_bundles[2].set, Some(Set::Blob(decoded)) if decoded == &blobs));
+    }
+
+    #[test]
+    fn message_ordering() {
+        let cursors = vec![Cursor::new(0, Position {x: 0., y: 0.}), Cursor::new(1, Position {x: 0.5, y: 0.5})];
+        let bundle = OscEncoder::encode_cursor_bundle(&cursors, "test".into(), 3);
+
+        // set, source, alive with a single ID, and no fseq
+        let mut reordered = bundle.clone();
+        reordered.content.pop();
+        reordered.content.swap(0, 2);
+        if let OscPacket::Message(message) = &mut reordered.content[1] {
+            message.args.pop();
+        }
+
+        let decoded_bundle = OscDecoder::decode_bundle_with_strictness(reordered.clone(), Strictness::Lenient).unwrap();
+        assert_eq!(decoded_bundle.fseq, -1);
+        assert!(matches!(decoded_bundle.set, Some(Set::Cursor(ref decoded)) if decoded == &cursors[..1]));
+
+        assert!(matches!(OscDecoder::decode_bundle_with_strictness(reordered, Strictness::Strict), Err(TuioError::UnexpectedMessageOrder(_))));
+
+        let mut missing_fseq = bundle.clone();
+        missing_fseq.content.pop();
+        assert!(matches!(OscDecoder::decode_bundle_with_strictness(missing_fseq, Strictness::Strict), Err(TuioError::IncompleteBundle(_))));
+
+        let mut after_fseq = bundle.clone();
+        after_fseq.content.push(after_fseq.content[1].clone());
+        assert!(matches!(OscDecoder::decode_bundle_with_strictness(after_fseq, Strictness::Strict), Err(TuioError::UnexpectedMessageOrder(_))));
+
+        assert_eq!(OscDecoder::decode_bundle_with_strictness(bundle, Strictness::Strict).unwrap().fseq, 3);
+    }
+
+    #[test]
+    fn big_endian_wire_format() {
+        let cursor = Cursor::new(258, Position { x: 0.5, y: 0.25 }).with_motion(Velocity { x: -2., y: 1.5 }, 0.75);
+        let mut bundle = OscEncoder::encode_cursor_bundle(&[cursor], "test".into(), 1);
+        bundle.timetag = OscTime { seconds: 0x01020304, fractional: 0x05060708 };
+
+        let set_message = bundle.content[2].clone();
+        let golden: Vec<u8> = [
+            b"/tuio/2Dcur\0".as_slice(),
+            b",sifffff\0\0\0\0",
+            b"set\0",
+            &[0x00, 0x00, 0x01, 0x02],
+            &[0x3f, 0x00, 0x00, 0x00],
+            &[0x3e, 0x80, 0x00, 0x00],
+            &[0xc0, 0x00, 0x00, 0x00],
+            &[0x3f, 0xc0, 0x00, 0x00],
+            &[0x3f, 0x40, 0x00, 0x00],
+        ].concat();
+
+        assert_eq!(rosc::encoder::encode(&set_message).unwrap(), golden);
+        assert_eq!(rosc::decoder::decode_udp(&golden).unwrap().1, set_message);
+
+        let bytes = rosc::encoder::encode(&OscPacket::Bundle(bundle)).unwrap();
+        assert_eq!(&bytes[..16], b"#bundle\0\x01\x02\x03\x04\x05\x06\x07\x08");
+    }
+
+    #[test]
+    fn encoded_size() {
+        let cursors: Vec<Cursor> = (0..3).map(|session_id| Cursor::new(session_id, Position { x: 0.5, y: 0.5 })).collect();
+        let mut packet = OscPacket::Bundle(OscEncoder::encode_cursor_bundle(&cursors, "source_name".into(), 1));
+        super::add_namespace(&mut packet, "/table");
+
+        assert_eq!(super::encoded_size(&packet), rosc::encoder::encode(&packet).unwrap().len());
     }
 }
@@ -1,14 +1,27 @@
 use std::{
-    net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket, TcpStream, Shutdown},
     sync::{
-        Arc,
-    }, error::Error,
+        Arc, Mutex,
+    }, error::Error, io::Read, time::Duration,
 };
 
 use rosc::{OscPacket};
+use socket2::SockRef;
 
 use crate::errors::OscReceiverError;
 
+/// A callback receiving the undecoded bytes of every OSC packet along with the socket address of its sender
+pub type RawTap = Arc<dyn Fn(SocketAddr, &[u8]) + Send + Sync>;
+
+/// Calls the [RawTap] of a receiver, if any, without holding its lock during the call
+pub(crate) fn call_raw_tap(raw_tap: &Mutex<Option<RawTap>>, address: SocketAddr, bytes: &[u8]) {
+    let raw_tap = raw_tap.lock().unwrap().clone();
+
+    if let Some(raw_tap) = raw_tap {
+        raw_tap(address, bytes);
+    }
+}
+
 /// Base trait to implement receiving OSC over various transport methods
 pub trait OscReceiver<P, E: Error> {
     /// Returns a true if the connection is established
@@ -22,10 +35,46 @@ pub trait OscReceiver<P, E: Error> {
 
     /// Receives a single OSC packet.
     fn recv(&self) -> Result<P, E>;
+
+    /// Receives a single OSC packet, waiting at most for a timeout.
+    /// Receivers which can wait for a timeout return an [std::io::ErrorKind::TimedOut] error if no packet was received in time,
+    /// the others ignore it and block like [OscReceiver::recv]
+    ///
+    /// # Arguments
+    /// * `timeout` - the longest time to wait for a packet
+    #[allow(unused_variables)]
+    fn recv_timeout(&self, timeout: Duration) -> Result<P, E> {
+        self.recv()
+    }
+
+    /// Sets a [RawTap] called with the bytes of every received packet before they are decoded.
+    /// Does nothing for receivers which do not read packets from the wire
+    #[allow(unused_variables)]
+    fn set_raw_tap(&self, raw_tap: RawTap) {}
+
+    /// Returns the local socket address of the receiver, or [None] if it has no bound socket
+    fn local_addr(&self) -> Option<SocketAddr> {
+        None
+    }
+}
+
+/// The largest payload of a UDP datagram, so packets above the MTU are received whole
+pub(crate) const MAX_DATAGRAM_SIZE: usize = 65536;
+
+/// The shortest read timeout, since sockets reject a zero timeout
+const MIN_TIMEOUT: Duration = Duration::from_micros(1);
+
+/// Reports the expiry of a socket read timeout as [std::io::ErrorKind::TimedOut] whatever the platform
+fn timed_out(err: OscReceiverError) -> OscReceiverError {
+    match err {
+        OscReceiverError::Receive(err) if err.kind() == std::io::ErrorKind::WouldBlock => OscReceiverError::Receive(std::io::Error::from(std::io::ErrorKind::TimedOut)),
+        err => err,
+    }
 }
 
 pub struct UdpReceiver {
-    socket: Arc<UdpSocket>
+    socket: Arc<UdpSocket>,
+    raw_tap: Mutex<Option<RawTap>>
 }
 
 impl UdpReceiver {
@@ -36,13 +85,49 @@ impl UdpReceiver {
     
     /// Creates an [UdpReceiver] binded to the provided port
     pub fn from_port(port: u16) -> Result<Self, std::io::Error> {
+        Self::from_address(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
+    }
+
+    /// Creates an [UdpReceiver] binded to the provided socket address
+    ///
+    /// # Arguments
+    /// * `address` - the local socket address, either IPv4 or IPv6
+    pub fn from_address(address: SocketAddr) -> Result<Self, std::io::Error> {
         Ok(Self {
-            socket: Arc::new(UdpSocket::bind(SocketAddr::new(
-                IpAddr::V4(Ipv4Addr::LOCALHOST),
-                port,
-            ))?)
+            socket: Arc::new(UdpSocket::bind(address)?),
+            raw_tap: Mutex::new(None)
         })
     }
+
+    /// Creates an [UdpReceiver] binded to the provided port on every interface and joined to a multicast group
+    ///
+    /// # Arguments
+    /// * `group` - the IPv4 multicast group address
+    /// * `iface` - the address of the local interface joining the group, or [Ipv4Addr::UNSPECIFIED] to let the system choose
+    /// * `port` - the port the TUIO source sends to
+    pub fn from_multicast(group: Ipv4Addr, iface: Ipv4Addr, port: u16) -> Result<Self, std::io::Error> {
+        let socket = UdpSocket::bind(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), port))?;
+        socket.join_multicast_v4(&group, &iface)?;
+
+        Ok(Self {
+            socket: Arc::new(socket),
+            raw_tap: Mutex::new(None)
+        })
+    }
+
+    /// Sets the size of the socket's receive buffer, `SO_RCVBUF`, so bursts of large bundles are not dropped by the OS
+    ///
+    /// The OS may round the size or cap it to a system limit
+    /// # Arguments
+    /// * `size` - the buffer size in bytes
+    pub fn set_recv_buffer_size(&self, size: usize) -> Result<(), std::io::Error> {
+        SockRef::from(self.socket.as_ref()).set_recv_buffer_size(size)
+    }
+
+    /// Returns the size of the socket's receive buffer in bytes, as reported by the OS
+    pub fn recv_buffer_size(&self) -> Result<usize, std::io::Error> {
+        SockRef::from(self.socket.as_ref()).recv_buffer_size()
+    }
 }
 
 pub type RoscReceiver = dyn OscReceiver<OscPacket, OscReceiverError> + Send + Sync;
@@ -52,19 +137,236 @@ impl OscReceiver<OscPacket, OscReceiverError> for UdpReceiver {
         Ok(())
     }
 
-    fn disconnect(&self) {}
+    /// Wakes up a thread blocked in [OscReceiver::recv] by sending an empty datagram to the socket itself
+    fn disconnect(&self) {
+        if let Ok(mut address) = self.socket.local_addr() {
+            if address.ip().is_unspecified() {
+                address.set_ip(match address {
+                    SocketAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    SocketAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+                });
+            }
+
+            let _ = self.socket.send_to(&[], address);
+        }
+    }
 
     /// Always returns true because UDP is connectionless
     fn is_connected(&self) -> bool {
         true
     }
 
+    /// Returns an [std::io::ErrorKind::Interrupted] error for the empty datagram sent by [OscReceiver::disconnect]
     fn recv(&self) -> Result<OscPacket, OscReceiverError> {
-        let mut buf = [0u8; rosc::decoder::MTU];
+        let mut buf = [0u8; MAX_DATAGRAM_SIZE];
+
+        let (size, address) = self.socket.recv_from(&mut buf).map_err(OscReceiverError::Receive)?;
+
+        if size == 0 {
+            return Err(OscReceiverError::Receive(std::io::Error::from(std::io::ErrorKind::Interrupted)));
+        }
+
+        call_raw_tap(&self.raw_tap, address, &buf[..size]);
 
-        let (size, _) = self.socket.recv_from(&mut buf).map_err(OscReceiverError::Receive)?;
         let (_, packet) = rosc::decoder::decode_udp(&buf[..size]).map_err(OscReceiverError::Decode)?;
 
         Ok(packet)
     }
-}
\ No newline at end of file
+
+    fn recv_timeout(&self, timeout: Duration) -> Result<OscPacket, OscReceiverError> {
+        self.socket.set_read_timeout(Some(timeout.max(MIN_TIMEOUT))).map_err(OscReceiverError::Receive)?;
+        let result = self.recv();
+        self.socket.set_read_timeout(None).map_err(OscReceiverError::Receive)?;
+
+        result.map_err(timed_out)
+    }
+
+    fn set_raw_tap(&self, raw_tap: RawTap) {
+        *self.raw_tap.lock().unwrap() = Some(raw_tap);
+    }
+
+    /// Returns the address the socket is bound to, such as the port chosen by the system when binding the port 0
+    fn local_addr(&self) -> Option<SocketAddr> {
+        self.socket.local_addr().ok()
+    }
+}
+
+/// Receives OSC packets from a TUIO source over TCP.
+///
+/// Packets are expected to be framed with a big-endian int32 size prefix as specified by OSC 1.0 for stream transports,
+/// and a packet larger than a UDP datagram is rejected with an [std::io::ErrorKind::InvalidData] error
+pub struct TcpReceiver {
+    address: SocketAddr,
+    stream: Mutex<Option<TcpStream>>,
+    raw_tap: Mutex<Option<RawTap>>
+}
+
+impl TcpReceiver {
+    /// Creates a [TcpReceiver] which connects to the provided socket address
+    pub fn new(address: SocketAddr) -> Self {
+        Self {
+            address,
+            stream: Mutex::new(None),
+            raw_tap: Mutex::new(None)
+        }
+    }
+
+    /// Returns the socket address of the TUIO source
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+}
+
+impl OscReceiver<OscPacket, OscReceiverError> for TcpReceiver {
+    fn connect(&self) -> Result<(), std::io::Error> {
+        let mut stream = self.stream.lock().unwrap();
+
+        if stream.is_none() {
+            *stream = Some(TcpStream::connect(self.address)?);
+        }
+
+        Ok(())
+    }
+
+    fn disconnect(&self) {
+        if let Some(stream) = self.stream.lock().unwrap().take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.lock().unwrap().is_some()
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        let mut stream = match self.stream.lock().unwrap().as_ref() {
+            Some(stream) => stream.try_clone().map_err(OscReceiverError::Receive)?,
+            None => return Err(OscReceiverError::Receive(std::io::Error::from(std::io::ErrorKind::NotConnected))),
+        };
+
+        let mut size = [0u8; 4];
+        stream.read_exact(&mut size).map_err(OscReceiverError::Receive)?;
+
+        let size = u32::from_be_bytes(size) as usize;
+
+        // A corrupt or hostile size prefix must not allocate gigabytes
+        if size > MAX_DATAGRAM_SIZE {
+            return Err(OscReceiverError::Receive(std::io::Error::new(std::io::ErrorKind::InvalidData, format!("OSC packet of {} bytes exceeds {} bytes", size, MAX_DATAGRAM_SIZE))));
+        }
+
+        let mut buf = vec![0u8; size];
+        stream.read_exact(&mut buf).map_err(OscReceiverError::Receive)?;
+        call_raw_tap(&self.raw_tap, self.address, &buf);
+
+        let (_, packet) = rosc::decoder::decode_udp(&buf).map_err(OscReceiverError::Decode)?;
+
+        Ok(packet)
+    }
+
+    /// A timeout in the middle of a packet loses the stream framing, so the receiver should be reconnected after a timeout
+    fn recv_timeout(&self, timeout: Duration) -> Result<OscPacket, OscReceiverError> {
+        let stream = match self.stream.lock().unwrap().as_ref() {
+            Some(stream) => stream.try_clone().map_err(OscReceiverError::Receive)?,
+            None => return Err(OscReceiverError::Receive(std::io::Error::from(std::io::ErrorKind::NotConnected))),
+        };
+
+        stream.set_read_timeout(Some(timeout.max(MIN_TIMEOUT))).map_err(OscReceiverError::Receive)?;
+        let result = self.recv();
+        stream.set_read_timeout(None).map_err(OscReceiverError::Receive)?;
+
+        result.map_err(timed_out)
+    }
+
+    fn set_raw_tap(&self, raw_tap: RawTap) {
+        *self.raw_tap.lock().unwrap() = Some(raw_tap);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{SocketAddr, IpAddr, Ipv4Addr};
+
+    use rosc::{OscPacket, OscMessage, OscType};
+
+    use crate::server::{TcpSender, UdpSender, SendOsc};
+
+    use super::*;
+
+    #[test]
+    fn tcp_transport() {
+        let sender = TcpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        let receiver = TcpReceiver::new(sender.local_addr().unwrap());
+
+        receiver.connect().unwrap();
+        assert!(sender.is_connected());
+
+        let packet = OscPacket::Message(OscMessage {
+            addr: "/tuio/2Dcur".into(),
+            args: vec![OscType::String("alive".into()), OscType::Int(1)]
+        });
+
+        sender.send_osc_packet(&packet).unwrap();
+        sender.send_osc_packet(&packet).unwrap();
+
+        assert_eq!(receiver.recv().unwrap(), packet);
+        assert_eq!(receiver.recv().unwrap(), packet);
+
+        receiver.disconnect();
+        assert!(!receiver.is_connected());
+    }
+
+    #[test]
+    fn tcp_oversized_packet() {
+        use std::io::Write;
+
+        let listener = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let receiver = TcpReceiver::new(listener.local_addr().unwrap());
+        receiver.connect().unwrap();
+
+        let (mut stream, _) = listener.accept().unwrap();
+        stream.write_all(&u32::MAX.to_be_bytes()).unwrap();
+
+        assert!(matches!(receiver.recv(), Err(OscReceiverError::Receive(err)) if err.kind() == std::io::ErrorKind::InvalidData));
+    }
+
+    #[test]
+    fn raw_tap() {
+        let sender = TcpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        let receiver = TcpReceiver::new(sender.local_addr().unwrap());
+        let tapped = Arc::new(Mutex::new(Vec::new()));
+
+        let tap = Arc::clone(&tapped);
+        receiver.set_raw_tap(Arc::new(move |address, bytes| tap.lock().unwrap().push((address, bytes.to_vec()))));
+        receiver.connect().unwrap();
+        assert!(sender.is_connected());
+
+        let packet = OscPacket::Message(OscMessage {
+            addr: "/tuio/2Dcur".into(),
+            args: vec![OscType::String("alive".into()), OscType::Int(1)]
+        });
+
+        sender.send_osc_packet(&packet).unwrap();
+        assert_eq!(receiver.recv().unwrap(), packet);
+
+        let tapped = tapped.lock().unwrap();
+        assert_eq!(tapped.len(), 1);
+        assert_eq!(tapped[0].0, sender.local_addr().unwrap());
+        assert_eq!(tapped[0].1, rosc::encoder::encode(&packet).unwrap());
+    }
+
+    #[test]
+    fn udp_multicast() {
+        let group = Ipv4Addr::new(239, 0, 0, 222);
+        let receiver = UdpReceiver::from_multicast(group, Ipv4Addr::LOCALHOST, 0).unwrap();
+        let port = receiver.local_addr().unwrap().port();
+        let sender = UdpSender::new_multicast(std::net::SocketAddrV4::new(group, port), Ipv4Addr::LOCALHOST).unwrap();
+
+        let packet = OscPacket::Message(OscMessage {
+            addr: "/tuio/2Dcur".into(),
+            args: vec![OscType::String("alive".into()), OscType::Int(1)]
+        });
+
+        sender.send_osc_packet(&packet).unwrap();
+        assert_eq!(receiver.recv().unwrap(), packet);
+    }
+}
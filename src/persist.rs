@@ -0,0 +1,149 @@
+use std::{collections::HashMap, time::{Duration, Instant}};
+
+use crate::client::{TuioEvents, ObjectEvent, ObjectData};
+
+/// Keeps the identity of [crate::Object]s briefly lost by a tracker, such as a fiducial occluded by a hand
+///
+/// A removed object is held back for the maximum gap. If an object of the same class appears within the maximum distance meanwhile,
+/// its new event becomes an update carrying the session ID of the removed object, and so do its following events.
+/// A removed object which does not reappear within the maximum gap is removed once the gap elapses
+pub(crate) struct ObjectPersistence {
+    max_distance: f32,
+    max_gap: Duration,
+    removed: Vec<(ObjectData, Instant)>,
+    aliases: HashMap<(String, i32), i32>,
+}
+
+impl ObjectPersistence {
+    /// Creates an [ObjectPersistence] reconciling the objects reappearing within a distance and a time window
+    /// # Arguments
+    /// * `max_distance` - the largest normalized distance between a removed object and its reappearance
+    /// * `max_gap` - the longest [Duration] between a removal and a reappearance
+    pub(crate) fn new(max_distance: f32, max_gap: Duration) -> Self {
+        Self {
+            max_distance,
+            max_gap,
+            removed: Vec::new(),
+            aliases: HashMap::new(),
+        }
+    }
+
+    /// Holds back the remove events of [TuioEvents], turns the new events of reappearing objects into updates
+    /// and adds the remove events held back for longer than the maximum gap
+    ///
+    /// Returns true if any event remains to be delivered
+    /// # Arguments
+    /// * `events` - the [TuioEvents] to reconcile
+    /// * `now` - the delivery [Instant]
+    pub(crate) fn apply(&mut self, events: &mut TuioEvents, now: Instant) -> bool {
+        for event in std::mem::take(&mut events.object_events) {
+            let event = match event {
+                ObjectEvent::New(mut data) => match self.take_removed(&data, now) {
+                    Some(session_id) => {
+                        self.aliases.insert((data.source_name.clone(), data.object.session_id), session_id);
+                        data.object.session_id = session_id;
+                        ObjectEvent::Update(data)
+                    },
+                    None => ObjectEvent::New(data),
+                },
+                ObjectEvent::Update(mut data) => {
+                    if let Some(session_id) = self.aliases.get(&(data.source_name.clone(), data.object.session_id)) {
+                        data.object.session_id = *session_id;
+                    }
+
+                    ObjectEvent::Update(data)
+                },
+                ObjectEvent::Remove(mut data) => {
+                    if let Some(session_id) = self.aliases.remove(&(data.source_name.clone(), data.object.session_id)) {
+                        data.object.session_id = session_id;
+                    }
+
+                    self.removed.push((data, now));
+                    continue;
+                },
+            };
+
+            events.object_events.push(event);
+        }
+
+        let (expired, removed) = std::mem::take(&mut self.removed).into_iter()
+            .partition(|(_, removed_at)| now.saturating_duration_since(*removed_at) > self.max_gap);
+
+        self.removed = removed;
+        events.object_events.extend(expired.into_iter().map(|(data, _)| ObjectEvent::Remove(data)));
+
+        !events.is_empty()
+    }
+
+    /// Returns the session ID of the nearest held back object matching a new object, no longer holding it back
+    /// # Arguments
+    /// * `data` - the [ObjectData] of the new object
+    /// * `now` - the delivery [Instant]
+    fn take_removed(&mut self, data: &ObjectData, now: Instant) -> Option<i32> {
+        let (index, _) = self.removed.iter().enumerate()
+            .filter(|(_, (removed, removed_at))| removed.source_name == data.source_name
+                && removed.object.class_id == data.object.class_id
+                && now.saturating_duration_since(*removed_at) <= self.max_gap)
+            .map(|(index, (removed, _))| (index, removed.object.position.distance_from(&data.object.position)))
+            .filter(|(_, distance)| *distance <= self.max_distance)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+        Some(self.removed.remove(index).0.object.session_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use crate::{cursor::Position, Object};
+
+    use super::*;
+
+    fn object_event(event: fn(ObjectData) -> ObjectEvent, session_id: i32, class_id: i32, x: f32) -> TuioEvents {
+        TuioEvents {
+            object_events: vec![event(ObjectData { source_name: "test".into(), time: SystemTime::now(), fseq: 1, object: Object::new(session_id, class_id, Position { x, y: 0.5 }, 0.) })],
+            ..Default::default()
+        }
+    }
+
+    fn steps(events: &TuioEvents) -> Vec<(&'static str, i32)> {
+        events.object_events.iter().map(|event| match event {
+            ObjectEvent::New(data) => ("new", data.object.session_id),
+            ObjectEvent::Update(data) => ("update", data.object.session_id),
+            ObjectEvent::Remove(data) => ("remove", data.object.session_id),
+        }).collect()
+    }
+
+    #[test]
+    fn occluded_object() {
+        let mut persistence = ObjectPersistence::new(0.05, Duration::from_millis(500));
+        let start = Instant::now();
+
+        let mut events = object_event(ObjectEvent::Remove, 1, 7, 0.5);
+        assert!(!persistence.apply(&mut events, start));
+
+        let mut events = object_event(ObjectEvent::New, 2, 8, 0.5);
+        assert!(persistence.apply(&mut events, start + Duration::from_millis(100)));
+        assert_eq!(steps(&events), vec![("new", 2)]);
+
+        let mut events = object_event(ObjectEvent::New, 3, 7, 0.52);
+        assert!(persistence.apply(&mut events, start + Duration::from_millis(200)));
+        assert_eq!(steps(&events), vec![("update", 1)]);
+
+        let mut events = object_event(ObjectEvent::Update, 3, 7, 0.6);
+        persistence.apply(&mut events, start + Duration::from_millis(300));
+        assert_eq!(steps(&events), vec![("update", 1)]);
+
+        let mut events = object_event(ObjectEvent::Remove, 3, 7, 0.6);
+        assert!(!persistence.apply(&mut events, start + Duration::from_millis(400)));
+
+        let mut events = object_event(ObjectEvent::New, 4, 7, 0.9);
+        persistence.apply(&mut events, start + Duration::from_millis(500));
+        assert_eq!(steps(&events), vec![("new", 4)]);
+
+        let mut events = TuioEvents::default();
+        assert!(persistence.apply(&mut events, start + Duration::from_millis(901)));
+        assert_eq!(steps(&events), vec![("remove", 1)]);
+    }
+}
@@ -0,0 +1,260 @@
+use std::time::Duration;
+
+use crate::cursor::{Position, Velocity, linear_motion, smooth, MotionOrigin, DEFAULT_MIN_DELTA_TIME};
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pointer {
+    pub(crate) session_id: i32,
+    pub(crate) type_id: u16,
+    pub(crate) user_id: u16,
+    pub(crate) component_id: i32,
+    pub(crate) position: Position,
+    pub(crate) angle: f32,
+    pub(crate) shear: f32,
+    pub(crate) radius: f32,
+    pub(crate) pressure: f32,
+    pub(crate) velocity: Velocity,
+    pub(crate) pressure_speed: f32,
+    pub(crate) acceleration: f32,
+    pub(crate) pressure_acceleration: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<(Position, f32)>,
+}
+
+impl Pointer {
+    /// Creates a new [Pointer]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `type_id` - the pointer's type ID, such as a finger or a stylus
+    /// * `user_id` - the ID of the user owning the pointer
+    /// * `component_id` - the pointer's component ID
+    /// * `position` - a normalized [Position]
+    /// * `angle` - an angle in radians
+    /// * `shear` - a shear angle in radians
+    /// * `radius` - a normalized radius
+    /// * `pressure` - a normalized pressure
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        session_id: i32,
+        type_id: u16,
+        user_id: u16,
+        component_id: i32,
+        position: Position,
+        angle: f32,
+        shear: f32,
+        radius: f32,
+        pressure: f32,
+    ) -> Self {
+        Self {
+            session_id,
+            type_id,
+            user_id,
+            component_id,
+            position,
+            angle,
+            shear,
+            radius,
+            pressure,
+            velocity: Velocity::default(),
+            pressure_speed: 0f32,
+            acceleration: 0f32,
+            pressure_acceleration: 0f32,
+            motion_origin: MotionOrigin::default(),
+        }
+    }
+
+    /// Returns this [Pointer] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity]
+    /// * `pressure_speed` - a pressure change rate per second
+    /// * `acceleration` - a normalized acceleration
+    /// * `pressure_acceleration` - a pressure change rate per second squared
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity,
+        pressure_speed: f32,
+        acceleration: f32,
+        pressure_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.pressure_speed = pressure_speed;
+        self.acceleration = acceleration;
+        self.pressure_acceleration = pressure_acceleration;
+        self
+    }
+
+    /// Updates the [Pointer], computing its velocity, acceleration, pressure speed and pressure acceleration once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    /// * `shear` - the new shear angle
+    /// * `radius` - the new radius
+    /// * `pressure` - the new pressure
+    pub fn update(&mut self, delta_time: Duration, position: Position, angle: f32, shear: f32, radius: f32, pressure: f32) {
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position, angle, shear, radius, pressure);
+    }
+
+    /// Updates the [Pointer], computing its velocity, acceleration, pressure speed and pressure acceleration once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// Closer updates keep the previous motion instead of producing a spike, while the other values are still updated
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    /// * `shear` - the new shear angle
+    /// * `radius` - the new radius
+    /// * `pressure` - the new pressure
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_with_min_delta_time(&mut self, delta_time: Duration, min_delta_time: Duration, position: Position, angle: f32, shear: f32, radius: f32, pressure: f32) {
+        if let Some(((previous_position, previous_pressure), delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &(self.position, self.pressure)) {
+            (self.velocity, self.acceleration) = linear_motion(&position, &previous_position, &self.velocity, delta_time);
+
+            let pressure_speed = (pressure - previous_pressure) / delta_time;
+
+            self.pressure_acceleration = (pressure_speed - self.pressure_speed) / delta_time;
+            self.pressure_speed = pressure_speed;
+        }
+
+        self.position = position;
+        self.pressure = pressure;
+        self.angle = angle;
+        self.shear = shear;
+        self.radius = radius;
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_type_id(&self) -> u16 {
+        self.type_id
+    }
+
+    pub fn get_user_id(&self) -> u16 {
+        self.user_id
+    }
+
+    pub fn get_component_id(&self) -> i32 {
+        self.component_id
+    }
+
+    pub fn get_position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_velocity(&self) -> &Velocity {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns the angle in radians
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Returns the shear angle in radians
+    pub fn get_shear(&self) -> f32 {
+        self.shear
+    }
+
+    /// Returns the normalized radius
+    pub fn get_radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Returns the normalized pressure
+    pub fn get_pressure(&self) -> f32 {
+        self.pressure
+    }
+
+    /// Returns the pressure change rate per second
+    pub fn get_pressure_speed(&self) -> f32 {
+        self.pressure_speed
+    }
+
+    /// Returns the pressure change rate per second squared
+    pub fn get_pressure_acceleration(&self) -> f32 {
+        self.pressure_acceleration
+    }
+
+    /// Low-pass filters the velocity, pressure speed and accelerations of this [Pointer] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Pointer]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Pointer, alpha: f32) {
+        self.velocity = self.velocity.smooth(&previous.velocity, alpha);
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+        self.pressure_speed = smooth(self.pressure_speed, previous.pressure_speed, alpha);
+        self.pressure_acceleration = smooth(self.pressure_acceleration, previous.pressure_acceleration, alpha);
+    }
+}
+
+impl PartialEq for Pointer {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.type_id == other.type_id
+            && self.user_id == other.user_id
+            && self.component_id == other.component_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.angle == other.angle
+            && self.shear == other.shear
+            && self.radius == other.radius
+            && self.pressure == other.pressure
+            && self.velocity == other.velocity
+            && self.pressure_speed == other.pressure_speed
+            && self.acceleration == other.acceleration
+            && self.pressure_acceleration == other.pressure_acceleration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::SQRT_2, time::Duration};
+
+    use crate::{cursor::Position, pointer::Pointer};
+
+    #[test]
+    fn pointer_update() {
+        let mut pointer = Pointer::new(0, 1, 0, 0, Position { x: 0., y: 0. }, 0., 0., 0.01, 0.);
+
+        pointer.update(Duration::from_secs(1), Position { x: 1., y: 1. }, 0., 0., 0.02, 0.5);
+
+        assert_eq!(pointer.get_x_position(), 1.);
+        assert_eq!(pointer.get_y_position(), 1.);
+        assert_eq!(pointer.get_x_velocity(), 1.);
+        assert_eq!(pointer.get_y_velocity(), 1.);
+        assert_eq!(pointer.get_acceleration(), SQRT_2);
+        assert_eq!(pointer.get_radius(), 0.02);
+        assert_eq!(pointer.get_pressure(), 0.5);
+        assert_eq!(pointer.get_pressure_speed(), 0.5);
+        assert_eq!(pointer.get_pressure_acceleration(), 0.5);
+
+        pointer.update(Duration::from_secs(1), Position { x: 1., y: 1. }, 0., 0., 0.02, 0.75);
+
+        assert_eq!(pointer.get_pressure_speed(), 0.25);
+        assert_eq!(pointer.get_pressure_acceleration(), -0.25);
+    }
+}
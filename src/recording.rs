@@ -0,0 +1,335 @@
+use std::{
+    fs::File,
+    net::SocketAddr,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use rosc::OscPacket;
+
+use crate::{errors::OscReceiverError, osc_receiver::{OscReceiver, RawTap, RoscReceiver}};
+
+struct RecordOutput {
+    writer: Box<dyn Write + Send>,
+    start: Instant,
+}
+
+/// Writes a single record: a big-endian uint64 of microseconds since the start of the recording,
+/// followed by the OSC packet framed with a big-endian int32 size prefix
+fn write_record(writer: &mut dyn Write, offset: Duration, packet: &OscPacket) -> Result<(), io::Error> {
+    let bytes = rosc::encoder::encode(packet).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    writer.write_all(&(offset.as_micros() as u64).to_be_bytes())?;
+    writer.write_all(&(bytes.len() as u32).to_be_bytes())?;
+    writer.write_all(&bytes)?;
+    writer.flush()
+}
+
+/// Reads a single record, returning [None] at the end of the recording
+fn read_record(reader: &mut dyn Read) -> Result<Option<(Duration, OscPacket)>, io::Error> {
+    let mut offset = [0u8; 8];
+
+    match reader.read_exact(&mut offset) {
+        Ok(()) => (),
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    let mut size = [0u8; 4];
+    reader.read_exact(&mut size)?;
+
+    let mut buf = vec![0u8; u32::from_be_bytes(size) as usize];
+    reader.read_exact(&mut buf)?;
+
+    let (_, packet) = rosc::decoder::decode_udp(&buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    Ok(Some((Duration::from_micros(u64::from_be_bytes(offset)), packet)))
+}
+
+/// Wraps an [OscReceiver] and writes every received packet with its arrival time to a recording
+pub struct Recorder {
+    receiver: Arc<RoscReceiver>,
+    output: Arc<Mutex<RecordOutput>>,
+}
+
+impl Recorder {
+    /// Creates a [Recorder] which records the packets received by `receiver`
+    ///
+    /// # Arguments
+    /// * `receiver` - the [OscReceiver] to record
+    /// * `writer` - where to write the recording
+    pub fn new(receiver: Arc<RoscReceiver>, writer: impl Write + Send + 'static) -> Self {
+        Self {
+            receiver,
+            output: Arc::new(Mutex::new(RecordOutput {
+                writer: Box::new(writer),
+                start: Instant::now(),
+            })),
+        }
+    }
+
+    /// Creates a [Recorder] which records the packets received by `receiver` into a new file
+    ///
+    /// # Arguments
+    /// * `receiver` - the [OscReceiver] to record
+    /// * `path` - the path of the file to create
+    pub fn create(receiver: Arc<RoscReceiver>, path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        Ok(Self::new(receiver, BufWriter::new(File::create(path)?)))
+    }
+
+    /// Writes a received packet to the recording and returns it
+    fn record(&self, packet: OscPacket) -> Result<OscPacket, OscReceiverError> {
+        let mut output = self.output.lock().unwrap();
+        let offset = output.start.elapsed();
+        write_record(&mut output.writer, offset, &packet).map_err(OscReceiverError::Receive)?;
+
+        Ok(packet)
+    }
+
+    /// Creates a [Recorder] writing to the same recording as this one
+    pub(crate) fn share(&self, receiver: Arc<RoscReceiver>) -> Self {
+        Self {
+            receiver,
+            output: Arc::clone(&self.output),
+        }
+    }
+}
+
+impl OscReceiver<OscPacket, OscReceiverError> for Recorder {
+    fn connect(&self) -> Result<(), io::Error> {
+        self.receiver.connect()
+    }
+
+    fn disconnect(&self) {
+        self.receiver.disconnect();
+    }
+
+    fn is_connected(&self) -> bool {
+        self.receiver.is_connected()
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        self.record(self.receiver.recv()?)
+    }
+
+    fn recv_timeout(&self, timeout: Duration) -> Result<OscPacket, OscReceiverError> {
+        self.record(self.receiver.recv_timeout(timeout)?)
+    }
+
+    fn set_raw_tap(&self, raw_tap: RawTap) {
+        self.receiver.set_raw_tap(raw_tap);
+    }
+
+    fn local_addr(&self) -> Option<SocketAddr> {
+        self.receiver.local_addr()
+    }
+}
+
+struct PlayerState {
+    index: usize,
+    start: Option<Instant>,
+}
+
+/// Replays a recording made by a [Recorder], honoring the original timing between packets
+///
+/// Once the recording is over and looping is disabled, [OscReceiver::recv] fails with [io::ErrorKind::UnexpectedEof]
+pub struct Player {
+    records: Vec<(Duration, OscPacket)>,
+    speed: f32,
+    looping: bool,
+    state: Mutex<PlayerState>,
+}
+
+impl Player {
+    /// Creates a [Player] from a recording
+    ///
+    /// # Arguments
+    /// * `reader` - where to read the recording from
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, io::Error> {
+        let mut records = Vec::new();
+
+        while let Some(record) = read_record(&mut reader)? {
+            records.push(record);
+        }
+
+        // Playback starts with the first packet rather than with the start of the recording
+        let first_offset = records.first().map(|(offset, _)| *offset).unwrap_or_default();
+
+        for (offset, _) in &mut records {
+            *offset = offset.saturating_sub(first_offset);
+        }
+
+        Ok(Self {
+            records,
+            speed: 1.,
+            looping: false,
+            state: Mutex::new(PlayerState { index: 0, start: None }),
+        })
+    }
+
+    /// Creates a [Player] from a recording file
+    ///
+    /// # Arguments
+    /// * `path` - the path of the recording
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Returns this [Player] with a playback speed multiplier
+    ///
+    /// # Arguments
+    /// * `speed` - a strictly positive multiplier, 2 plays twice as fast
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(f32::EPSILON);
+        self
+    }
+
+    /// Returns this [Player] restarting from the beginning once the recording is over
+    ///
+    /// # Arguments
+    /// * `looping` - true to loop the recording
+    pub fn with_loop(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Returns the number of recorded packets
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if the recording contains no packets
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the duration of the recording at normal speed
+    pub fn duration(&self) -> Duration {
+        self.records.last().map(|(offset, _)| *offset).unwrap_or_default()
+    }
+}
+
+impl OscReceiver<OscPacket, OscReceiverError> for Player {
+    fn connect(&self) -> Result<(), io::Error> {
+        *self.state.lock().unwrap() = PlayerState { index: 0, start: None };
+        Ok(())
+    }
+
+    fn disconnect(&self) {}
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        let (offset, packet, start) = {
+            let mut state = self.state.lock().unwrap();
+
+            if state.index >= self.records.len() {
+                if !self.looping || self.records.is_empty() {
+                    return Err(OscReceiverError::Receive(io::Error::from(io::ErrorKind::UnexpectedEof)));
+                }
+
+                state.index = 0;
+                state.start = None;
+            }
+
+            let start = *state.start.get_or_insert_with(Instant::now);
+            let (offset, packet) = &self.records[state.index];
+            state.index += 1;
+
+            (*offset, packet.clone(), start)
+        };
+
+        let target = start + offset.div_f32(self.speed);
+        thread::sleep(target.saturating_duration_since(Instant::now()));
+
+        Ok(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::{Arc, Mutex}, io::{self, Write}, time::{Duration, Instant}};
+
+    use rosc::{OscPacket, OscMessage, OscType};
+
+    use crate::{osc_receiver::OscReceiver, errors::OscReceiverError};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct FixedReceiver(OscPacket);
+
+    impl OscReceiver<OscPacket, OscReceiverError> for FixedReceiver {
+        fn connect(&self) -> Result<(), io::Error> {
+            Ok(())
+        }
+
+        fn disconnect(&self) {}
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn packet(id: i32) -> OscPacket {
+        OscPacket::Message(OscMessage {
+            addr: "/tuio/2Dcur".into(),
+            args: vec![OscType::String("alive".into()), OscType::Int(id)]
+        })
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let buffer = SharedBuffer::default();
+        let recorder = Recorder::new(Arc::new(FixedReceiver(packet(1))), buffer.clone());
+
+        assert_eq!(recorder.recv().unwrap(), packet(1));
+        assert_eq!(recorder.recv().unwrap(), packet(1));
+
+        let player = Player::from_reader(buffer.0.lock().unwrap().as_slice()).unwrap();
+
+        assert_eq!(player.len(), 2);
+        assert_eq!(player.recv().unwrap(), packet(1));
+        assert_eq!(player.recv().unwrap(), packet(1));
+        assert!(player.recv().is_err());
+    }
+
+    #[test]
+    fn replay_timing() {
+        let mut recording = Vec::new();
+        write_record(&mut recording, Duration::from_millis(1000), &packet(1)).unwrap();
+        write_record(&mut recording, Duration::from_millis(1100), &packet(2)).unwrap();
+
+        let player = Player::from_reader(recording.as_slice()).unwrap().with_speed(2.).with_loop(true);
+        assert_eq!(player.duration(), Duration::from_millis(100));
+
+        let instant = Instant::now();
+        assert_eq!(player.recv().unwrap(), packet(1));
+        assert_eq!(player.recv().unwrap(), packet(2));
+        assert!(instant.elapsed() >= Duration::from_millis(50));
+        assert!(instant.elapsed() < Duration::from_millis(500));
+
+        assert_eq!(player.recv().unwrap(), packet(1));
+    }
+}
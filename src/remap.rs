@@ -0,0 +1,184 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{client::{TuioEvents, CursorEvent, ObjectEvent, BlobEvent, Cursor3DEvent, Cursor25DEvent, Blob3DEvent, TokenEvent, PointerEvent, SymbolEvent, BoundsEvent, GeometryEvent, ControlEvent}, osc_encode_decode::TuioBundleType};
+
+/// Maps the session IDs of several TUIO sources to a single space, so inputs of different trackers sharing a session ID stay distinct
+///
+/// Each session ID of a source and a profile is given the lowest free global ID when it first appears,
+/// and the global ID is recycled once the input is removed
+#[derive(Debug, Default)]
+pub struct SessionIdRemapper {
+    session_ids: HashMap<(String, TuioBundleType, i32), i32>,
+    next_session_id: i32,
+    free_session_ids: BTreeSet<i32>,
+}
+
+impl SessionIdRemapper {
+    /// Creates a [SessionIdRemapper] without any mapped session ID
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the global session ID of an input, allocating one if the input is not mapped yet
+    /// # Arguments
+    /// * `source_name` - the name of the source of the input
+    /// * `tuio_type` - the profile of the input
+    /// * `session_id` - the session ID sent by the source
+    pub fn remap(&mut self, source_name: &str, tuio_type: TuioBundleType, session_id: i32) -> i32 {
+        if let Some(global_id) = self.get(source_name, tuio_type, session_id) {
+            return global_id;
+        }
+
+        let global_id = match self.free_session_ids.pop_first() {
+            Some(global_id) => global_id,
+            None => {
+                let global_id = self.next_session_id;
+                self.next_session_id = self.next_session_id.wrapping_add(1);
+                global_id
+            }
+        };
+
+        self.session_ids.insert((source_name.to_string(), tuio_type, session_id), global_id);
+        global_id
+    }
+
+    /// Returns the global session ID of an input, or [None] if it is not mapped
+    /// # Arguments
+    /// * `source_name` - the name of the source of the input
+    /// * `tuio_type` - the profile of the input
+    /// * `session_id` - the session ID sent by the source
+    pub fn get(&self, source_name: &str, tuio_type: TuioBundleType, session_id: i32) -> Option<i32> {
+        self.session_ids.get(&(source_name.to_string(), tuio_type, session_id)).copied()
+    }
+
+    /// Unmaps an input and frees its global session ID for the next new input
+    ///
+    /// Returns the freed global session ID, or [None] if the input was not mapped
+    /// # Arguments
+    /// * `source_name` - the name of the source of the input
+    /// * `tuio_type` - the profile of the input
+    /// * `session_id` - the session ID sent by the source
+    pub fn release(&mut self, source_name: &str, tuio_type: TuioBundleType, session_id: i32) -> Option<i32> {
+        let global_id = self.session_ids.remove(&(source_name.to_string(), tuio_type, session_id))?;
+        self.free_session_ids.insert(global_id);
+        Some(global_id)
+    }
+
+    /// Returns the number of mapped inputs
+    pub fn len(&self) -> usize {
+        self.session_ids.len()
+    }
+
+    /// Returns true if no input is mapped
+    pub fn is_empty(&self) -> bool {
+        self.session_ids.is_empty()
+    }
+
+    /// Replaces the session IDs of the inputs of [TuioEvents] by their global session IDs, in the order of the events
+    ///
+    /// New and updated inputs are mapped, removed inputs are unmapped after their event is rewritten
+    /// # Arguments
+    /// * `events` - the [TuioEvents] to rewrite
+    pub fn apply(&mut self, events: &mut TuioEvents) {
+        self.apply_events(TuioBundleType::Cursor, &mut events.cursor_events, |event| match event {
+            CursorEvent::New(data) | CursorEvent::Update(data) => (false, &data.source_name, &mut data.cursor.session_id),
+            CursorEvent::Remove(data) => (true, &data.source_name, &mut data.cursor.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Object, &mut events.object_events, |event| match event {
+            ObjectEvent::New(data) | ObjectEvent::Update(data) => (false, &data.source_name, &mut data.object.session_id),
+            ObjectEvent::Remove(data) => (true, &data.source_name, &mut data.object.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Blob, &mut events.blob_events, |event| match event {
+            BlobEvent::New(data) | BlobEvent::Update(data) => (false, &data.source_name, &mut data.blob.session_id),
+            BlobEvent::Remove(data) => (true, &data.source_name, &mut data.blob.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Cursor3D, &mut events.cursor_3d_events, |event| match event {
+            Cursor3DEvent::New(data) | Cursor3DEvent::Update(data) => (false, &data.source_name, &mut data.cursor.session_id),
+            Cursor3DEvent::Remove(data) => (true, &data.source_name, &mut data.cursor.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Cursor25D, &mut events.cursor_25d_events, |event| match event {
+            Cursor25DEvent::New(data) | Cursor25DEvent::Update(data) => (false, &data.source_name, &mut data.cursor.session_id),
+            Cursor25DEvent::Remove(data) => (true, &data.source_name, &mut data.cursor.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Blob3D, &mut events.blob_3d_events, |event| match event {
+            Blob3DEvent::New(data) | Blob3DEvent::Update(data) => (false, &data.source_name, &mut data.blob.session_id),
+            Blob3DEvent::Remove(data) => (true, &data.source_name, &mut data.blob.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Token, &mut events.token_events, |event| match event {
+            TokenEvent::New(data) | TokenEvent::Update(data) => (false, &data.source_name, &mut data.token.session_id),
+            TokenEvent::Remove(data) => (true, &data.source_name, &mut data.token.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Pointer, &mut events.pointer_events, |event| match event {
+            PointerEvent::New(data) | PointerEvent::Update(data) => (false, &data.source_name, &mut data.pointer.session_id),
+            PointerEvent::Remove(data) => (true, &data.source_name, &mut data.pointer.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Symbol, &mut events.symbol_events, |event| match event {
+            SymbolEvent::New(data) | SymbolEvent::Update(data) => (false, &data.source_name, &mut data.symbol.session_id),
+            SymbolEvent::Remove(data) => (true, &data.source_name, &mut data.symbol.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Bounds, &mut events.bounds_events, |event| match event {
+            BoundsEvent::New(data) | BoundsEvent::Update(data) => (false, &data.source_name, &mut data.bounds.session_id),
+            BoundsEvent::Remove(data) => (true, &data.source_name, &mut data.bounds.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Geometry, &mut events.geometry_events, |event| match event {
+            GeometryEvent::New(data) | GeometryEvent::Update(data) => (false, &data.source_name, &mut data.geometry.session_id),
+            GeometryEvent::Remove(data) => (true, &data.source_name, &mut data.geometry.session_id),
+        });
+
+        self.apply_events(TuioBundleType::Control, &mut events.control_events, |event| match event {
+            ControlEvent::New(data) | ControlEvent::Update(data) => (false, &data.source_name, &mut data.control.session_id),
+            ControlEvent::Remove(data) => (true, &data.source_name, &mut data.control.session_id),
+        });
+    }
+
+    /// Rewrites the session ID of each event of a profile
+    /// # Arguments
+    /// * `tuio_type` - the profile of the events
+    /// * `events` - the events to rewrite
+    /// * `parts` - returns whether an event is a removal, the name of its source and its session ID
+    fn apply_events<E>(&mut self, tuio_type: TuioBundleType, events: &mut [E], parts: impl Fn(&mut E) -> (bool, &String, &mut i32)) {
+        for event in events {
+            let (removed, source_name, session_id) = parts(event);
+
+            *session_id = if removed {
+                self.release(source_name, tuio_type, *session_id).unwrap_or(*session_id)
+            }
+            else {
+                self.remap(source_name, tuio_type, *session_id)
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remap_and_recycle() {
+        let mut remapper = SessionIdRemapper::new();
+
+        assert_eq!(remapper.remap("left@local", TuioBundleType::Cursor, 5), 0);
+        assert_eq!(remapper.remap("right@local", TuioBundleType::Cursor, 5), 1);
+        assert_eq!(remapper.remap("left@local", TuioBundleType::Object, 5), 2);
+        assert_eq!(remapper.remap("left@local", TuioBundleType::Cursor, 5), 0);
+
+        assert_eq!(remapper.release("left@local", TuioBundleType::Cursor, 5), Some(0));
+        assert_eq!(remapper.release("left@local", TuioBundleType::Cursor, 5), None);
+        assert_eq!(remapper.get("left@local", TuioBundleType::Cursor, 5), None);
+
+        assert_eq!(remapper.remap("left@local", TuioBundleType::Cursor, 6), 0);
+        assert_eq!(remapper.remap("left@local", TuioBundleType::Cursor, 7), 3);
+        assert_eq!(remapper.len(), 4);
+    }
+}
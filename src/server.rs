@@ -1,16 +1,16 @@
-use std::{net::{SocketAddr, UdpSocket, Ipv4Addr, Ipv6Addr, IpAddr}, sync::atomic::{AtomicI32, Ordering}, time::{SystemTime, Instant, Duration}, error::Error, num::Wrapping};
-use rosc::{OscPacket, OscMessage, OscBundle, OscTime, OscError};
+use std::{collections::{HashSet, BTreeSet}, net::{SocketAddr, SocketAddrV4, ToSocketAddrs, UdpSocket, Ipv4Addr, Ipv6Addr, IpAddr, TcpListener, TcpStream}, io::Write, sync::{Arc, Mutex, Weak, atomic::{AtomicBool, AtomicI32, Ordering}}, time::{SystemTime, Instant, Duration}, error::Error, num::Wrapping, thread::{self, JoinHandle}};
+use rosc::{OscPacket, OscMessage, OscBundle, OscTime};
 use rosc::encoder;
 use rosc::OscType;
 use local_ip_address::local_ip;
 use indexmap::{IndexMap};
 
-use crate::{cursor::{Position}, osc_encode_decode::{EncodeOsc, OscEncoder}, Object, Cursor, Blob}; 
+use crate::{clock::{Clock, SystemClock}, cursor::{Lifetime, Position, Velocity, DEFAULT_MIN_DELTA_TIME}, osc_encode_decode::{self, EncodeOsc, OscEncoder, TuioVersion, BUNDLE_HEADER_SIZE, encoded_size}, errors::TuioError, Object, Cursor, Blob}; 
 
 /// Base trait to implement sending OSC over various transport methods
 pub trait SendOsc<P, E> where E: Error {
     /// Sends an OSC packet.
-    /// Returns an [Error] if packet's encoding fails
+    /// Returns an [Error] if packet's encoding or sending fails
     ///
     /// # Arguments
     /// * `packet` - a reference to an OSC packet
@@ -21,6 +21,11 @@ pub trait SendOsc<P, E> where E: Error {
 
     /// Returns true if the target is a loopback address
     fn is_local(&self) -> bool;
+
+    /// Returns the local socket address of the sender, or [None] if it has no socket
+    fn local_addr(&self) -> Option<SocketAddr> {
+        None
+    }
 }
 
 pub struct UdpSender {
@@ -29,25 +34,43 @@ pub struct UdpSender {
 }
 
 impl UdpSender {
-    /// Creates an [UdpSender] binded on localhost
+    /// Creates an [UdpSender] binded on the address family of the target, on localhost if the target is a loopback address
     ///
     /// # Arguments
-    /// * `target` - the target socket address
+    /// * `target` - the target socket address, either IPv4 or IPv6
     pub fn new(target: SocketAddr) -> Result<Self, std::io::Error> {
-        let ip_address: IpAddr = if target.is_ipv4() {IpAddr::V4(Ipv4Addr::LOCALHOST)} else {IpAddr::V6(Ipv6Addr::LOCALHOST)};
+        let ip_address: IpAddr = match (target.is_ipv4(), target.ip().is_loopback()) {
+            (true, true) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            (true, false) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            (false, true) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            (false, false) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        };
         Ok(Self {socket: UdpSocket::bind(SocketAddr::new(ip_address, 0))?, address: target})
     }
+
+    /// Creates an [UdpSender] sending to an IPv4 multicast group through a provided interface
+    ///
+    /// The socket is binded on the interface address so the system routes the packets through it on multi-homed machines.
+    /// Packets are also looped back to the receivers of the sending host
+    /// # Arguments
+    /// * `group` - the multicast group socket address
+    /// * `iface` - the address of the local interface to send from, or [Ipv4Addr::UNSPECIFIED] to let the system choose
+    pub fn new_multicast(group: SocketAddrV4, iface: Ipv4Addr) -> Result<Self, std::io::Error> {
+        let socket = UdpSocket::bind(SocketAddr::new(IpAddr::V4(iface), 0))?;
+        socket.set_multicast_loop_v4(true)?;
+        Ok(Self {socket, address: SocketAddr::V4(group)})
+    }
 }
 
-impl SendOsc<OscPacket, OscError> for UdpSender {
+impl SendOsc<OscPacket, TuioError> for UdpSender {
     /// Sends an [OscPacket] over UDP.
-    /// Returns an [OscError] if packet's encoding fails
+    /// Returns a [TuioError] if packet's encoding or sending fails
     ///
     /// # Arguments
     /// * `packet` - a reference to an [OscPacket]
-    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), OscError> {
-        let buffer = encoder::encode(packet)?;
-        self.socket.send_to(&buffer, self.address).unwrap();
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), TuioError> {
+        let buffer = encoder::encode(packet).map_err(TuioError::Encode)?;
+        self.socket.send_to(&buffer, self.address)?;
         Ok(())
     }
 
@@ -60,13 +83,329 @@ impl SendOsc<OscPacket, OscError> for UdpSender {
     fn is_local(&self) -> bool {
         self.address.ip().is_loopback()
     }
+
+    /// Returns the address the socket sends from
+    fn local_addr(&self) -> Option<SocketAddr> {
+        self.socket.local_addr().ok()
+    }
+}
+
+/// Sends OSC packets over TCP to every connected client.
+///
+/// Packets are framed with a big-endian int32 size prefix as specified by OSC 1.0 for stream transports.
+/// A client which does not read its packets within the write timeout is dropped, so it cannot stall the frames of the others
+pub struct TcpSender {
+    listener: TcpListener,
+    streams: Mutex<Vec<TcpStream>>
+}
+
+impl TcpSender {
+    /// Creates a [TcpSender] listening for clients on the provided socket address
+    ///
+    /// # Arguments
+    /// * `address` - the socket address to listen on
+    pub fn new(address: SocketAddr) -> Result<Self, std::io::Error> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+        Ok(Self {listener, streams: Mutex::new(Vec::new())})
+    }
+
+    /// Returns the socket address this [TcpSender] is listening on
+    pub fn local_addr(&self) -> Result<SocketAddr, std::io::Error> {
+        self.listener.local_addr()
+    }
+
+    /// Accepts the clients waiting for a connection
+    fn accept_pending(&self) {
+        let mut streams = self.streams.lock().unwrap();
+
+        while let Ok((stream, _)) = self.listener.accept() {
+            if stream.set_nonblocking(false).and_then(|_| stream.set_write_timeout(Some(TCP_WRITE_TIMEOUT))).is_ok() {
+                streams.push(stream);
+            }
+        }
+    }
+}
+
+/// The longest time a [TcpSender] waits for a client to read a packet before dropping it
+const TCP_WRITE_TIMEOUT: Duration = Duration::from_millis(100);
+
+impl SendOsc<OscPacket, TuioError> for TcpSender {
+    /// Sends an [OscPacket] to every connected client, dropping the clients that disconnected.
+    /// Returns a [TuioError] if packet's encoding fails
+    ///
+    /// # Arguments
+    /// * `packet` - a reference to an [OscPacket]
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), TuioError> {
+        let buffer = encoder::encode(packet).map_err(TuioError::Encode)?;
+        let mut frame = (buffer.len() as u32).to_be_bytes().to_vec();
+        frame.extend(buffer);
+
+        self.accept_pending();
+        self.streams.lock().unwrap().retain_mut(|stream| stream.write_all(&frame).is_ok());
+        Ok(())
+    }
+
+    /// Returns true if at least one client is connected
+    fn is_connected(&self) -> bool {
+        self.accept_pending();
+        !self.streams.lock().unwrap().is_empty()
+    }
+
+    /// Return true if listening on a loopback address
+    fn is_local(&self) -> bool {
+        self.listener.local_addr().map(|address| address.ip().is_loopback()).unwrap_or(false)
+    }
+
+    /// Returns the address clients connect to
+    fn local_addr(&self) -> Option<SocketAddr> {
+        self.listener.local_addr().ok()
+    }
+}
+
+/// Replaces the time tag of a bundle and of its nested bundles with the immediate time tag
+fn set_immediate_time_tag(packet: &mut OscPacket) {
+    if let OscPacket::Bundle(bundle) = packet {
+        bundle.timetag = OscTime { seconds: 0, fractional: 1 };
+
+        for packet in &mut bundle.content {
+            set_immediate_time_tag(packet);
+        }
+    }
+}
+
+/// The largest payload of an IPv4 UDP datagram, the default maximum size of the packets sent by a [Server]
+const MAX_UDP_PAYLOAD_SIZE: usize = 65507;
+
+/// Returns true if a packet is a TUIO `set` message
+fn is_set_message(packet: &OscPacket) -> bool {
+    matches!(packet, OscPacket::Message(message) if matches!(message.args.first(), Some(OscType::String(command)) if command == "set"))
+}
+
+/// Replaces the session IDs of the `alive` message of a profile bundle, so a bundle setting only the updated inputs still lists every alive one
+fn set_alive_ids(bundle: &mut OscBundle, alive_ids: impl Iterator<Item = i32>) {
+    let alive_message = bundle.content.iter_mut().find_map(|packet| match packet {
+        OscPacket::Message(message) if matches!(message.args.first(), Some(OscType::String(command)) if command == "alive") => Some(message),
+        _ => None,
+    });
+
+    if let Some(message) = alive_message {
+        message.args.truncate(1);
+        message.args.extend(alive_ids.map(OscType::Int));
+    }
+}
+
+/// Splits a profile bundle larger than `max_size` into bundles sharing its time tag, source, alive and fseq messages
+///
+/// The set messages are kept in order and each bundle takes as many of them as fit, at least one,
+/// so the same bundle is always split the same way. Clients assemble the parts as one frame since they carry the same fseq
+fn split_profile_bundle(bundle: OscBundle, max_size: usize) -> Vec<OscBundle> {
+    let (set_messages, mut frame_messages): (Vec<OscPacket>, Vec<OscPacket>) = bundle.content.into_iter().partition(is_set_message);
+    let fseq_message = frame_messages.pop();
+
+    let element_size = |packet: &OscPacket| 4 + encoded_size(packet);
+    let header_size = BUNDLE_HEADER_SIZE + frame_messages.iter().chain(&fseq_message).map(element_size).sum::<usize>();
+
+    let mut parts = Vec::new();
+    let mut content = frame_messages.clone();
+    let mut size = header_size;
+
+    for message in set_messages {
+        let message_size = element_size(&message);
+
+        if content.len() > frame_messages.len() && size + message_size > max_size {
+            content.extend(fseq_message.clone());
+            parts.push(OscBundle { timetag: bundle.timetag, content });
+            content = frame_messages.clone();
+            size = header_size;
+        }
+
+        size += message_size;
+        content.push(message);
+    }
+
+    content.extend(fseq_message);
+    parts.push(OscBundle { timetag: bundle.timetag, content });
+    parts
+}
+
+/// Returns the local host name, or an empty string if it can't be determined
+fn host_name() -> String {
+    std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .or_else(|_| std::fs::read_to_string("/etc/hostname"))
+        .map(|name| name.trim().to_string())
+        .unwrap_or_default()
+}
+
+/// A builder to configure and create a TUIO [Server]
+pub struct ServerBuilder {
+    target: SocketAddr,
+    source_name: Option<String>,
+    update_interval: Option<Duration>,
+    namespace: String,
+    min_delta_time: Duration,
+}
+
+impl Default for ServerBuilder {
+    fn default() -> Self {
+        Self {
+            target: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3333),
+            source_name: None,
+            update_interval: None,
+            namespace: String::new(),
+            min_delta_time: DEFAULT_MIN_DELTA_TIME,
+        }
+    }
+}
+
+impl ServerBuilder {
+    /// Creates a [ServerBuilder] targeting 127.0.0.1:3333
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the socket address the TUIO messages are sent to
+    ///
+    /// # Arguments
+    /// * `target` - a socket address [SocketAddr]
+    pub fn target(mut self, target: SocketAddr) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets the source name sent through the TUIO source message. Defaults to the host name
+    ///
+    /// # Arguments
+    /// * `source_name` - the source name
+    pub fn source_name(mut self, source_name: &str) -> Self {
+        self.source_name = Some(source_name.to_string());
+        self
+    }
+
+    /// Enables the periodic full update of all currently active TUIO inputs
+    ///
+    /// # Arguments
+    /// * `interval` - the interval between two full updates. Notes that the minimum interval will always be 10 milliseconds
+    pub fn periodic_message(mut self, interval: Duration) -> Self {
+        self.update_interval = Some(interval);
+        self
+    }
+
+    /// Sets the OSC address namespace prepended to every TUIO message, such as `/mytable` for `/mytable/tuio/2Dcur`.
+    /// Defaults to the standard empty namespace
+    ///
+    /// # Arguments
+    /// * `namespace` - the address prefix
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.namespace = namespace.to_string();
+        self
+    }
+
+    /// Sets the shortest frame duration for which the motion of the updated inputs is computed. Defaults to [DEFAULT_MIN_DELTA_TIME]
+    ///
+    /// # Arguments
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    pub fn min_delta_time(mut self, min_delta_time: Duration) -> Self {
+        self.min_delta_time = min_delta_time;
+        self
+    }
+
+    /// Creates the configured [Server]
+    ///
+    /// Returns a [TuioError::Socket] of kind [std::io::ErrorKind::InvalidInput] if the target address is unspecified or has no port
+    pub fn build(self) -> Result<Server, TuioError> {
+        if self.target.ip().is_unspecified() || self.target.port() == 0 {
+            return Err(TuioError::Socket(std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("invalid target address: {}", self.target))));
+        }
+
+        let mut server = Server::from_socket_addr(self.target)?;
+        server.set_source_name(&self.source_name.unwrap_or_else(host_name));
+        server.set_namespace(&self.namespace);
+        server.set_min_delta_time(self.min_delta_time);
+
+        if let Some(interval) = self.update_interval {
+            server.enable_periodic_message(Some(interval));
+        }
+
+        Ok(server)
+    }
+}
+
+/// The alive session IDs of the last sent frame and their difference with the previous frame
+#[derive(Default)]
+struct AliveState {
+    alive: Vec<i32>,
+    added: Vec<i32>,
+    removed: Vec<i32>,
+}
+
+/// The rate limit of [Server::send_frame] and the latest frame held back by it
+struct Throttle {
+    min_interval: Duration,
+    last_sent: Option<Instant>,
+    pending: Option<(Vec<Cursor>, Vec<Object>, Vec<Blob>)>,
+}
+
+impl Throttle {
+    fn is_ready(&self, now: Instant) -> bool {
+        self.last_sent.is_none_or(|last_sent| now.duration_since(last_sent) >= self.min_interval)
+    }
+}
+
+/// The interval between two checks of a [Keepalive] thread
+const KEEPALIVE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The last frame sent through [Server::send_frame], resent while no newer frame is sent
+struct KeepaliveState {
+    interval: Duration,
+    last_sent: Instant,
+    frame: Option<(Vec<Cursor>, Vec<Object>, Vec<Blob>)>,
+}
+
+/// A thread resending the last frame of a [Server] while it is idle, see [Server::spawn_keepalive]
+///
+/// The thread is stopped and joined by [Keepalive::stop], when the [Keepalive] is dropped or when the [Server] is dropped
+pub struct Keepalive {
+    running: Arc<AtomicBool>,
+    last_error: Arc<Mutex<Option<TuioError>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Keepalive {
+    /// Returns true until the thread is stopped
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    /// Returns the error of the last failed resend since the previous call, or [None] if every resend succeeded
+    pub fn take_last_error(&self) -> Option<TuioError> {
+        self.last_error.lock().unwrap().take()
+    }
+
+    /// Stops the thread and waits for it to finish
+    pub fn stop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Keepalive {
+    fn drop(&mut self) {
+        self.stop();
+    }
 }
 
 /// TUIO Server which keeps track of all TUIO elements and which send TUIO messages over the network
 pub struct Server {
-    sender_list: Vec<Box<dyn SendOsc<OscPacket, OscError>>>,
+    sender_list: Vec<Box<dyn SendOsc<OscPacket, TuioError> + Send + Sync>>,
     source_name: String,
     session_id: i32,
+    allocated_session_ids: HashSet<i32>,
+    free_session_ids: BTreeSet<i32>,
     object_map: IndexMap<i32, Object>,
     object_updated: bool,
     frame_cursor_ids: Vec<i32>,
@@ -76,11 +415,13 @@ pub struct Server {
     cursor_updated: bool,
     blob_map: IndexMap<i32, Blob>,
     blob_updated: bool,
-    instant: Instant,
+    clock: Box<dyn Clock>,
     last_frame_instant: Instant,
     frame_duration: Duration,
+    min_delta_time: Duration,
     last_frame_id: AtomicI32,
-    /// Enables the full update of all currently active and inactive [Object]s, [Cursor]s and [Blob]s
+    /// Sends the `set` messages of every alive [Object], [Cursor] and [Blob] in each frame instead of only the updated ones.
+    /// The `alive` messages always list every alive input
     pub full_update: bool,
     periodic_messaging: bool,
     update_interval: Duration,
@@ -90,6 +431,13 @@ pub struct Server {
     cursor_update_time: Instant,
     pub blob_profiling: bool,
     blob_update_time: Instant,
+    alive_state: Mutex<AliveState>,
+    namespace: String,
+    throttle: Mutex<Option<Throttle>>,
+    keepalive: Mutex<Option<KeepaliveState>>,
+    immediate_time_tag: bool,
+    tuio_version: TuioVersion,
+    max_packet_size: usize,
 }
 
 impl Server {
@@ -97,17 +445,59 @@ impl Server {
     ///
     /// # Arguments
     /// * `source_name` - the source name
-    pub fn new(source_name: &str) -> Result<Self, std::io::Error> {
+    pub fn new(source_name: &str) -> Result<Self, TuioError> {
         let mut server = Self::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3333))?;
         server.set_source_name(source_name);
         Ok(server)
     }
     
+    /// Creates a TUIO [Server] with a [TcpSender] listening for clients on a provided socket address
+    ///
+    /// TCP is another [SendOsc], the transport abstraction shared with UDP and multicast, rather than a separate `Transport` trait.
+    /// Packets are framed with the big-endian int32 size prefix specified by OSC 1.0 for stream transports, as TUIO 1.1 clients expect,
+    /// instead of the SLIP framing of OSC 1.1
+    ///
+    /// # Arguments
+    /// * `source_name` - the source name
+    /// * `address` - the socket address to listen on
+    pub fn new_tcp(source_name: &str, address: SocketAddr) -> Result<Self, TuioError> {
+        let mut server = Self::from_osc_sender(TcpSender::new(address)?);
+        server.set_source_name(source_name);
+        Ok(server)
+    }
+
+    /// Creates a TUIO [Server] with a [TcpSender] listening for clients on a provided address
+    ///
+    /// The port 0 picks a free port, which [Server::local_addr] reports so clients can be told where to connect
+    /// # Arguments
+    /// * `address` - the address to listen on, such as `"0.0.0.0:0"`
+    pub fn bind(address: impl ToSocketAddrs) -> Result<Self, TuioError> {
+        let address = address.to_socket_addrs()?.next().ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        Ok(Self::from_osc_sender(TcpSender::new(address)?))
+    }
+
+    /// Creates a TUIO [Server] with a [UdpSender] sending to a multicast group on the port 3333
+    ///
+    /// # Arguments
+    /// * `source_name` - the source name
+    /// * `group` - the IPv4 multicast group address
+    /// * `iface` - the address of the local interface to send from, or [Ipv4Addr::UNSPECIFIED] to let the system choose
+    pub fn new_multicast(source_name: &str, group: Ipv4Addr, iface: Ipv4Addr) -> Result<Self, TuioError> {
+        let mut server = Self::from_osc_sender(UdpSender::new_multicast(SocketAddrV4::new(group, 3333), iface)?);
+        server.set_source_name(source_name);
+        Ok(server)
+    }
+
+    /// Returns a [ServerBuilder] to configure a TUIO [Server]
+    pub fn builder() -> ServerBuilder {
+        ServerBuilder::new()
+    }
+
     /// Creates a TUIO [Server] with a [UdpSender] configured from a provided socket address
     ///
     /// # Arguments
     /// * `socket_addr` - a socket address [SocketAddr]
-    pub fn from_socket_addr(socket_addr: SocketAddr) -> Result<Self, std::io::Error> {
+    pub fn from_socket_addr(socket_addr: SocketAddr) -> Result<Self, TuioError> {
         Ok(Self::from_osc_sender(UdpSender::new(socket_addr)?))
     }
 
@@ -115,20 +505,23 @@ impl Server {
     ///
     /// # Arguments
     /// * `osc_sender` - a sender implementing [OscSender]
-    pub fn from_osc_sender(osc_sender: impl SendOsc<OscPacket, OscError> + 'static) -> Self {
+    pub fn from_osc_sender(osc_sender: impl SendOsc<OscPacket, TuioError> + Send + Sync + 'static) -> Self {
         Self {
             sender_list: vec![Box::new(osc_sender)],
             source_name: String::new(),
             session_id: -1,
+            allocated_session_ids: HashSet::new(),
+            free_session_ids: BTreeSet::new(),
             object_map: IndexMap::new(),
             object_updated: false,
             cursor_map: IndexMap::new(),
             cursor_updated: false,
             blob_map: IndexMap::new(),
             blob_updated: false,
-            instant: Instant::now(),
+            clock: Box::new(SystemClock),
             last_frame_instant: Instant::now(),
             frame_duration: Duration::default(),
+            min_delta_time: DEFAULT_MIN_DELTA_TIME,
             last_frame_id: AtomicI32::new(0),
             full_update: false,
             periodic_messaging: false,
@@ -139,17 +532,30 @@ impl Server {
             cursor_update_time: Instant::now(),
             blob_profiling: true,
             blob_update_time: Instant::now(),
+            alive_state: Mutex::default(),
+            namespace: String::new(),
+            throttle: Mutex::new(None),
+            keepalive: Mutex::new(None),
+            immediate_time_tag: false,
+            tuio_version: TuioVersion::default(),
+            max_packet_size: MAX_UDP_PAYLOAD_SIZE,
             frame_cursor_ids: Vec::new(),
             frame_object_ids: Vec::new(),
             frame_blob_ids: Vec::new(),
         }
     }
 
+    /// Returns the local socket address of the first sender having one, such as the port a [TcpSender] listens on
+    /// or the port an [UdpSender] sends from, or [None] if no sender has a socket
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.sender_list.iter().find_map(|sender| sender.local_addr())
+    }
+
     /// Adds an OSC sender implementing [OscSender] trait
     ///
     /// # Arguments
     /// * `osc_sender` - a sender implementing [OscSender]
-    pub fn add_osc_sender(&mut self, osc_sender: impl SendOsc<OscPacket, OscError> + 'static) {
+    pub fn add_osc_sender(&mut self, osc_sender: impl SendOsc<OscPacket, TuioError> + Send + Sync + 'static) {
         self.sender_list.push(Box::new(osc_sender));
     }
 
@@ -168,6 +574,60 @@ impl Server {
         self.source_name = format!("{}@{}", name, source);
     }
 
+    /// Sets the OSC address namespace prepended to every TUIO message, such as `/mytable` for `/mytable/tuio/2Dcur`.
+    /// Defaults to the standard empty namespace
+    ///
+    /// # Arguments
+    /// * `namespace` - the address prefix
+    pub fn set_namespace(&mut self, namespace: &str) {
+        self.namespace = osc_encode_decode::normalize_namespace(namespace);
+    }
+
+    /// Stamps the sent bundles with the immediate time tag instead of their sending time. Disabled by default
+    ///
+    /// # Arguments
+    /// * `immediate` - true to send the immediate time tag
+    pub fn set_immediate_time_tag(&mut self, immediate: bool) {
+        self.immediate_time_tag = immediate;
+    }
+
+    /// Sets the version of the TUIO 1.x protocol of the sent bundles. Defaults to [TuioVersion::V11]
+    ///
+    /// With [TuioVersion::V10], the bundles have no `source` message, the [Blob]s are not sent since TUIO 1.0 has no blob profile,
+    /// and each profile bundle is sent as its own packet for the receivers which do not support nested bundles
+    /// # Arguments
+    /// * `version` - the [TuioVersion] expected by the receivers
+    pub fn set_tuio_version(&mut self, version: TuioVersion) {
+        self.tuio_version = version;
+    }
+
+    /// Sets the [Clock] timing the frames, whose durations give the velocities and accelerations of the updated inputs,
+    /// the rate limit of [Server::send_frame] and the keepalive. Defaults to [SystemClock]
+    ///
+    /// # Arguments
+    /// * `clock` - a [Clock], such as a [crate::clock::TestClock] to compute exact velocities in tests
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        let now = clock.now();
+        self.clock = Box::new(clock);
+        self.last_frame_instant = now;
+        self.object_update_time = now;
+        self.cursor_update_time = now;
+        self.blob_update_time = now;
+
+        if let Some(keepalive) = self.keepalive.lock().unwrap().as_mut() {
+            keepalive.last_sent = now;
+        }
+    }
+
+    /// Sets the shortest frame duration for which the motion of the updated [Object]s, [Cursor]s and [Blob]s is computed. Defaults to [DEFAULT_MIN_DELTA_TIME]
+    ///
+    /// Inputs updated in closer frames move but keep their previous motion, which is then measured over the whole time since it was last computed
+    /// # Arguments
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    pub fn set_min_delta_time(&mut self, min_delta_time: Duration) {
+        self.min_delta_time = min_delta_time;
+    }
+
     /// Enables the periodic full update of all currently active TUIO [Object]s, [Cursor]s and [Blob]s
     ///
     /// # Arguments
@@ -186,8 +646,41 @@ impl Server {
     }
 
     fn get_session_id(&mut self) -> i32 {
-        self.session_id = (Wrapping(self.session_id) + Wrapping(1)).0;
-        self.session_id
+        loop {
+            self.session_id = (Wrapping(self.session_id) + Wrapping(1)).0;
+
+            let session_id = self.session_id;
+            let alive = self.cursor_map.contains_key(&session_id) || self.object_map.contains_key(&session_id) || self.blob_map.contains_key(&session_id);
+
+            if !alive && !self.allocated_session_ids.contains(&session_id) {
+                self.free_session_ids.remove(&self.session_id);
+                return self.session_id;
+            }
+        }
+    }
+
+    /// Allocates a session id unique among the ids of the [Server]'s TUIO elements and the allocated ids which are not freed yet
+    ///
+    /// The lowest freed id is reused first, otherwise ids are allocated in increasing order like reacTIVision's
+    pub fn alloc_session_id(&mut self) -> i32 {
+        let session_id = match self.free_session_ids.pop_first() {
+            Some(session_id) => session_id,
+            None => self.get_session_id()
+        };
+
+        self.allocated_session_ids.insert(session_id);
+        session_id
+    }
+
+    /// Frees a session id allocated by [Server::alloc_session_id] so it can be reused.
+    /// Does nothing if the id is not allocated
+    ///
+    /// # Arguments
+    /// * `session_id` - the allocated session id
+    pub fn free_session_id(&mut self, session_id: i32) {
+        if self.allocated_session_ids.remove(&session_id) {
+            self.free_session_ids.insert(session_id);
+        }
     }
 
     /// Creates a TUIO [Object] and returns its session_id
@@ -200,7 +693,7 @@ impl Server {
     pub fn create_object(&mut self, class_id: i32, x: f32, y: f32, angle: f32) -> i32 {
         let session_id = self.get_session_id();
         
-        let object = Object::new(session_id, class_id, Position{x, y}, angle);
+        let object = Object { lifetime: Lifetime::new(self.clock.now()), ..Object::new(session_id, class_id, Position{x, y}, angle) };
         self.object_map.insert(session_id, object);
         self.frame_object_ids.push(session_id);
         self.object_updated = true;
@@ -216,8 +709,8 @@ impl Server {
     /// * `angle` - the new object's angle
     pub fn update_object(&mut self, session_id: i32, x: f32, y: f32, angle: f32) {
         if let Some(object) = self.object_map.get_mut(&session_id) {
-            object.update(self.frame_duration, Position{x, y}, angle);
-            self.frame_object_ids.push(session_id);
+            object.update_with_min_delta_time(self.frame_duration, self.min_delta_time, Position{x, y}, angle);
+            object.lifetime = object.lifetime.seen(self.clock.now());
             self.frame_object_ids.push(session_id);
             self.object_updated = true;
         }
@@ -241,7 +734,7 @@ impl Server {
     pub fn create_cursor(&mut self, x: f32, y: f32) -> i32 {
         let session_id = self.get_session_id();
         
-        let cursor = Cursor::new(session_id, Position{x, y});
+        let cursor = Cursor { lifetime: Lifetime::new(self.clock.now()), ..Cursor::new(session_id, Position{x, y}) };
         self.cursor_map.insert(session_id, cursor);
         self.frame_cursor_ids.push(session_id);
         self.cursor_updated = true;
@@ -256,12 +749,38 @@ impl Server {
     /// * `y` - the new cursor's y position
     pub fn update_cursor(&mut self, session_id: i32, x: f32, y: f32) {
         if let Some(cursor) = self.cursor_map.get_mut(&session_id) {
-            cursor.update(self.frame_duration, Position{x, y});
+            cursor.update_with_min_delta_time(self.frame_duration, self.min_delta_time, Position{x, y});
+            cursor.lifetime = cursor.lifetime.seen(self.clock.now());
             self.frame_cursor_ids.push(session_id);
             self.cursor_updated = true;
         }
     }
 
+    /// Sets the position and motion of a TUIO [Cursor], sending the given velocity and acceleration verbatim
+    /// instead of computing them from the previous position
+    ///
+    /// Creates the [Cursor] if no cursor has this session id, such as an id from [Server::alloc_session_id]
+    ///
+    /// # Arguments
+    /// * `session_id` - the cursor's session id
+    /// * `position` - the new cursor's normalized [Position]
+    /// * `velocity` - the new cursor's normalized [Velocity]
+    /// * `acceleration` - the new cursor's normalized acceleration
+    pub fn set_cursor_with_velocity(&mut self, session_id: i32, position: Position, velocity: Velocity, acceleration: f32) {
+        let now = self.clock.now();
+        let cursor = Cursor { lifetime: Lifetime::new(now), ..Cursor::new(session_id, position).with_motion(velocity, acceleration) };
+
+        match self.cursor_map.get_mut(&session_id) {
+            Some(previous) => *previous = Cursor { lifetime: previous.lifetime.updated().seen(now), ..cursor },
+            None => {
+                self.cursor_map.insert(session_id, cursor);
+            }
+        }
+
+        self.frame_cursor_ids.push(session_id);
+        self.cursor_updated = true;
+    }
+
     /// Removes a TUIO [Cursor]
     ///
     /// # Arguments
@@ -284,7 +803,7 @@ impl Server {
     pub fn create_blob(&mut self, x: f32, y: f32, angle: f32, width: f32, height: f32, area: f32) -> i32 {
         let session_id = self.get_session_id();
         
-        let blob = Blob::new(session_id, Position{x, y}, angle, width, height, area);
+        let blob = Blob { lifetime: Lifetime::new(self.clock.now()), ..Blob::new(session_id, Position{x, y}, angle, width, height, area) };
         self.blob_map.insert(session_id, blob);
         self.frame_blob_ids.push(session_id);
         self.blob_updated = true;
@@ -304,8 +823,8 @@ impl Server {
     /// * `area` - the new blob's area
     pub fn update_blob(&mut self, session_id: i32, x: f32, y: f32, angle: f32, width: f32, height: f32, area: f32) {
         if let Some(blob) = self.blob_map.get_mut(&session_id) {
-            blob.update(self.frame_duration, Position{x, y}, angle, width, height, area);
-            self.frame_blob_ids.push(session_id);
+            blob.update_with_min_delta_time(self.frame_duration, self.min_delta_time, Position{x, y}, angle, width, height, area);
+            blob.lifetime = blob.lifetime.seen(self.clock.now());
             self.frame_blob_ids.push(session_id);
             self.blob_updated = true;
         }
@@ -321,25 +840,68 @@ impl Server {
         }
     }
 
+    /// Removes a TUIO [Object], [Cursor] or [Blob] and immediately sends a frame of its profile without it
+    ///
+    /// The frame carries every remaining input of the profile, including the ones updated since the last committed frame.
+    /// Removing an unknown session id sends nothing
+    ///
+    /// # Arguments
+    /// * `session_id` - the session id of the input to remove
+    pub fn remove(&mut self, session_id: i32) -> Result<(), TuioError> {
+        if !self.object_map.contains_key(&session_id) && !self.cursor_map.contains_key(&session_id) && !self.blob_map.contains_key(&session_id) {
+            return Ok(());
+        }
+
+        self.init_frame();
+        let frame_id = self.last_frame_id.load(Ordering::SeqCst);
+
+        let bundle = if self.object_map.remove(&session_id).is_some() {
+            self.frame_object_ids.clear();
+            self.object_update_time = self.last_frame_instant;
+            self.object_updated = false;
+            OscEncoder::encode_object_bundle(self.object_map.values(), self.source_name.clone(), frame_id)
+        } else if self.cursor_map.remove(&session_id).is_some() {
+            self.frame_cursor_ids.clear();
+            self.cursor_update_time = self.last_frame_instant;
+            self.cursor_updated = false;
+            OscEncoder::encode_cursor_bundle(self.cursor_map.values(), self.source_name.clone(), frame_id)
+        } else {
+            self.blob_map.remove(&session_id);
+            self.frame_blob_ids.clear();
+            self.blob_update_time = self.last_frame_instant;
+            self.blob_updated = false;
+            OscEncoder::encode_blob_bundle(self.blob_map.values(), self.source_name.clone(), frame_id)
+        };
+
+        self.track_alive(self.object_map.keys().chain(self.cursor_map.keys()).chain(self.blob_map.keys()).copied().collect());
+        self.deliver_frame_bundles(vec![bundle])
+    }
+
     /// Initializes a new frame.
     pub fn init_frame(&mut self) {
-        self.frame_duration = self.instant.duration_since(self.last_frame_instant);
-        self.last_frame_instant = Instant::now();
+        let now = self.clock.now();
+        self.frame_duration = now.duration_since(self.last_frame_instant);
+        self.last_frame_instant = now;
         self.last_frame_id.fetch_add(1, Ordering::SeqCst);
     }
 
     /// Commits the current frame.
     /// 
     /// Generates and sends TUIO messages of all currently active and updated [Object]s, [Cursor]s and [Blob]s
-    pub fn commit_frame(&mut self) {
+    pub fn commit_frame(&mut self) -> Result<(), TuioError> {
+        let frame_id = self.last_frame_id.load(Ordering::SeqCst);
+        let mut bundles = Vec::new();
+
         if self.object_updated || (self.periodic_messaging && self.object_profiling && self.object_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
-            if self.full_update {
+            if !self.full_update {
                 let object_collection = self.frame_object_ids.iter().map(|id| self.object_map.get(id).unwrap());
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(object_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
+                let mut bundle = OscEncoder::encode_object_bundle(object_collection, self.source_name.clone(), frame_id);
+                set_alive_ids(&mut bundle, self.object_map.keys().copied());
+                bundles.push(bundle);
             }
             else {
                 let object_collection = self.object_map.values();
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(object_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
+                bundles.push(OscEncoder::encode_object_bundle(object_collection, self.source_name.clone(), frame_id));
             }
             
             self.frame_object_ids.clear();
@@ -350,10 +912,12 @@ impl Server {
         if self.cursor_updated || (self.periodic_messaging && self.cursor_profiling && self.cursor_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
             if !self.full_update {
                 let cursor_collection = self.frame_cursor_ids.iter().map(|id| self.cursor_map.get(id).unwrap());
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(cursor_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
+                let mut bundle = OscEncoder::encode_cursor_bundle(cursor_collection, self.source_name.clone(), frame_id);
+                set_alive_ids(&mut bundle, self.cursor_map.keys().copied());
+                bundles.push(bundle);
             } else {
                 let cursor_collection = self.cursor_map.iter().map(|(_, cursor)| cursor);
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(cursor_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
+                bundles.push(OscEncoder::encode_cursor_bundle(cursor_collection, self.source_name.clone(), frame_id));
             };
 
             self.frame_cursor_ids.clear();
@@ -364,34 +928,288 @@ impl Server {
         if self.blob_updated || (self.periodic_messaging && self.blob_profiling && self.blob_update_time.duration_since(self.last_frame_instant) >= self.update_interval) {
             if !self.full_update {
                 let blob_collection = self.frame_blob_ids.iter().map(|id| self.blob_map.get(id).unwrap());
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(blob_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
+                let mut bundle = OscEncoder::encode_blob_bundle(blob_collection, self.source_name.clone(), frame_id);
+                set_alive_ids(&mut bundle, self.blob_map.keys().copied());
+                bundles.push(bundle);
             } else {
                 let blob_collection = self.blob_map.values();
-                self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(blob_collection, self.source_name.clone(), self.last_frame_id.load(Ordering::SeqCst))));
+                bundles.push(OscEncoder::encode_blob_bundle(blob_collection, self.source_name.clone(), frame_id));
             };
             
             self.frame_blob_ids.clear();
             self.blob_update_time = self.last_frame_instant;
             self.blob_updated = false;
         }
+
+        self.track_alive(self.object_map.keys().chain(self.cursor_map.keys()).chain(self.blob_map.keys()).copied().collect());
+        self.deliver_frame_bundles(bundles)
     }
 
-    pub fn send_full_messages(&self) {
+    pub fn send_full_messages(&self) -> Result<(), TuioError> {
         let frame_id = self.last_frame_id.load(Ordering::SeqCst);
-        self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_object_bundle(self.object_map.values(), self.source_name.clone(), frame_id)));
-        self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_cursor_bundle(self.cursor_map.values(), self.source_name.clone(), frame_id)));
-        self.deliver_osc_packet(OscPacket::Bundle(OscEncoder::encode_blob_bundle(self.blob_map.values(), self.source_name.clone(), frame_id)));
+        self.deliver_frame_bundles(vec![
+            OscEncoder::encode_object_bundle(self.object_map.values(), self.source_name.clone(), frame_id),
+            OscEncoder::encode_cursor_bundle(self.cursor_map.values(), self.source_name.clone(), frame_id),
+            OscEncoder::encode_blob_bundle(self.blob_map.values(), self.source_name.clone(), frame_id),
+        ])
+    }
+
+    /// Sets the size above which an encoded frame is split into several packets. Defaults to the largest UDP payload, 65507 bytes
+    ///
+    /// A frame is first split into one packet per profile, then the set messages of a profile are spread over several bundles
+    /// carrying the same source, alive and fseq messages, which clients assemble back into one frame.
+    /// Lower it to the path MTU minus the IP and UDP headers, such as 1472 bytes on Ethernet, to avoid IP fragmentation
+    /// # Arguments
+    /// * `max_packet_size` - the maximum size of an encoded packet in bytes
+    pub fn set_max_packet_size(&mut self, max_packet_size: usize) {
+        self.max_packet_size = max_packet_size;
     }
     
-    fn deliver_osc_packet(&self, packet: OscPacket) {
+    /// Sends a complete frame of [Cursor]s, [Object]s and [Blob]s as a single OSC bundle, incrementing the frame sequence number once
+    ///
+    /// The bundle holds one nested bundle per profile, each with its source, alive, set and fseq messages in order.
+    /// Empty collections still send an empty alive message so clients remove every input of that profile
+    /// # Arguments
+    /// * `cursors` - the alive [Cursor]s
+    /// * `objects` - the alive [Object]s
+    /// * `blobs` - the alive [Blob]s
+    pub fn send_frame(&self, cursors: &[Cursor], objects: &[Object], blobs: &[Blob]) -> Result<(), TuioError> {
+        if let Some(throttle) = self.throttle.lock().unwrap().as_mut() {
+            let now = self.clock.now();
+
+            if !throttle.is_ready(now) {
+                throttle.pending = Some((cursors.to_vec(), objects.to_vec(), blobs.to_vec()));
+                return Ok(());
+            }
+
+            throttle.last_sent = Some(now);
+            throttle.pending = None;
+        }
+
+        self.deliver_frame(cursors, objects, blobs)
+    }
+
+    /// Sends the latest frame held back by the rate limit of [Server::send_frame], if its slot is reached
+    ///
+    /// Returns true if a frame was sent. Call it regularly while no new frame is sent so the last state is not left pending
+    pub fn send_pending_frame(&self) -> Result<bool, TuioError> {
+        let pending = match self.throttle.lock().unwrap().as_mut() {
+            Some(throttle) if throttle.pending.is_some() && throttle.is_ready(self.clock.now()) => {
+                throttle.last_sent = Some(self.clock.now());
+                throttle.pending.take()
+            },
+            _ => None,
+        };
+
+        match pending {
+            Some((cursors, objects, blobs)) => {
+                self.deliver_frame(&cursors, &objects, &blobs)?;
+                Ok(true)
+            },
+            None => Ok(false),
+        }
+    }
+
+    /// Limits the rate of the frames sent through [Server::send_frame]
+    ///
+    /// A frame sent too soon after the previous one is held back and replaced by any newer frame, to be sent by the next allowed [Server::send_frame] or [Server::send_pending_frame].
+    /// The frames of [Server::commit_frame] and the periodic full updates are not limited
+    /// # Arguments
+    /// * `max_rate` - the maximum number of frames per second, or [None] to send every frame
+    pub fn set_max_rate(&mut self, max_rate: Option<f32>) {
+        *self.throttle.lock().unwrap() = max_rate.filter(|max_rate| *max_rate > 0.).map(|max_rate| Throttle {
+            min_interval: Duration::from_secs_f32(1. / max_rate),
+            last_sent: None,
+            pending: None,
+        });
+    }
+
+    /// Resends the last frame sent through [Server::send_frame] with the redundant frame sequence number -1,
+    /// if no frame was sent during the keepalive interval
+    ///
+    /// Returns true if the frame was resent. Does nothing until a keepalive interval is set by [Server::set_keepalive]
+    pub fn send_keepalive(&self) -> Result<bool, TuioError> {
+        let frame = match self.keepalive.lock().unwrap().as_mut() {
+            Some(keepalive) if keepalive.frame.is_some() && self.clock.now().saturating_duration_since(keepalive.last_sent) >= keepalive.interval => {
+                keepalive.last_sent = self.clock.now();
+                keepalive.frame.clone()
+            },
+            _ => None,
+        };
+
+        match frame {
+            Some((cursors, objects, blobs)) => {
+                self.deliver_frame_bundle(&cursors, &objects, &blobs, -1)?;
+                Ok(true)
+            },
+            None => Ok(false),
+        }
+    }
+
+    /// Sets the interval after which [Server::send_keepalive] resends the last frame, so late-joining clients and lossy links stay in sync
+    ///
+    /// # Arguments
+    /// * `interval` - the idle interval, or [None] to disable the keepalive
+    pub fn set_keepalive(&self, interval: Option<Duration>) {
+        *self.keepalive.lock().unwrap() = interval.map(|interval| KeepaliveState {
+            interval,
+            last_sent: self.clock.now(),
+            frame: None,
+        });
+    }
+
+    /// Sets the keepalive interval of a shared [Server] and spawns a thread calling [Server::send_keepalive]
+    ///
+    /// The thread only holds a weak reference and stops once the [Server] is dropped.
+    /// A failed resend does not stop it, its error is kept for [Keepalive::take_last_error]
+    /// # Arguments
+    /// * `server` - the shared [Server]
+    /// * `interval` - the idle interval after which the last frame is resent
+    pub fn spawn_keepalive(server: &Arc<Server>, interval: Duration) -> Keepalive {
+        server.set_keepalive(Some(interval));
+
+        let running = Arc::new(AtomicBool::new(true));
+        let last_error = Arc::new(Mutex::new(None));
+
+        let thread = {
+            let server = Arc::downgrade(server);
+            let running = Arc::clone(&running);
+            let last_error = Arc::clone(&last_error);
+
+            thread::spawn(move || {
+                while running.load(Ordering::Relaxed) {
+                    match Weak::upgrade(&server) {
+                        Some(server) => if let Err(err) = server.send_keepalive() {
+                            #[cfg(feature = "tracing")]
+                            tracing::warn!(%err, "error sending keepalive frame");
+
+                            *last_error.lock().unwrap() = Some(err);
+                        },
+                        None => break,
+                    }
+
+                    thread::sleep(KEEPALIVE_POLL_INTERVAL.min(interval));
+                }
+
+                running.store(false, Ordering::Relaxed);
+            })
+        };
+
+        Keepalive { running, last_error, thread: Some(thread) }
+    }
+
+    fn deliver_frame(&self, cursors: &[Cursor], objects: &[Object], blobs: &[Blob]) -> Result<(), TuioError> {
+        let frame_id = self.last_frame_id.fetch_add(1, Ordering::SeqCst).wrapping_add(1);
+
+        self.track_alive(
+            cursors.iter().map(|cursor| cursor.get_session_id())
+                .chain(objects.iter().map(|object| object.get_session_id()))
+                .chain(blobs.iter().map(|blob| blob.get_session_id()))
+                .collect()
+        );
+
+        if let Some(keepalive) = self.keepalive.lock().unwrap().as_mut() {
+            keepalive.last_sent = self.clock.now();
+            keepalive.frame = Some((cursors.to_vec(), objects.to_vec(), blobs.to_vec()));
+        }
+
+        self.deliver_frame_bundle(cursors, objects, blobs, frame_id)
+    }
+
+    fn deliver_frame_bundle(&self, cursors: &[Cursor], objects: &[Object], blobs: &[Blob], frame_id: i32) -> Result<(), TuioError> {
+        self.deliver_frame_bundles(vec![
+            OscEncoder::encode_cursor_bundle(cursors, self.source_name.clone(), frame_id),
+            OscEncoder::encode_object_bundle(objects, self.source_name.clone(), frame_id),
+            OscEncoder::encode_blob_bundle(blobs, self.source_name.clone(), frame_id),
+        ])
+    }
+
+    /// Sends the profile bundles of a frame nested in a single bundle, so a frame costs one send per sender
+    ///
+    /// A frame larger than the maximum packet size is sent as one packet per profile, in order,
+    /// and a profile bundle still too large is split by [split_profile_bundle]
+    fn deliver_frame_bundles(&self, bundles: Vec<OscBundle>) -> Result<(), TuioError> {
+        let bundles: Vec<OscBundle> = match self.tuio_version {
+            TuioVersion::V10 => bundles.into_iter().filter_map(osc_encode_decode::to_tuio_10).collect(),
+            TuioVersion::V11 => bundles,
+        };
+
+        if bundles.is_empty() {
+            return Ok(());
+        }
+
+        let mut frame = OscPacket::Bundle(OscBundle {
+            timetag: osc_encode_decode::encode_time_tag(SystemTime::now()),
+            content: bundles.into_iter().map(OscPacket::Bundle).collect()
+        });
+
+        self.prepare_osc_packet(&mut frame);
+
+        if self.tuio_version == TuioVersion::V11 && encoded_size(&frame) <= self.max_packet_size {
+            return self.send_osc_packet(&frame);
+        }
+
+        let mut result = Ok(());
+
+        if let OscPacket::Bundle(frame) = frame {
+            for packet in frame.content {
+                if let OscPacket::Bundle(bundle) = packet {
+                    for part in split_profile_bundle(bundle, self.max_packet_size) {
+                        result = result.and(self.send_osc_packet(&OscPacket::Bundle(part)));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the session IDs which became alive and the ones which were removed in the last sent frame, compared to the previous one
+    pub fn alive_diff(&self) -> (Vec<i32>, Vec<i32>) {
+        let state = self.alive_state.lock().unwrap();
+        (state.added.clone(), state.removed.clone())
+    }
+
+    fn track_alive(&self, alive: Vec<i32>) {
+        let mut state = self.alive_state.lock().unwrap();
+
+        let previous: HashSet<i32> = state.alive.iter().copied().collect();
+        let current: HashSet<i32> = alive.iter().copied().collect();
+
+        state.added = alive.iter().copied().filter(|id| !previous.contains(id)).collect();
+        state.removed = state.alive.iter().copied().filter(|id| !current.contains(id)).collect();
+        state.alive = alive;
+    }
+
+    /// Prepends the namespace to the addresses of a packet and replaces its time tags if the immediate time tag is enabled
+    fn prepare_osc_packet(&self, packet: &mut OscPacket) {
+        osc_encode_decode::add_namespace(packet, &self.namespace);
+
+        if self.immediate_time_tag {
+            set_immediate_time_tag(packet);
+        }
+    }
+
+    /// Sends a packet through every sender, even if one of them fails
+    ///
+    /// Returns the error of the first failing sender
+    fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), TuioError> {
+        let mut result = Ok(());
+
         for sender in &self.sender_list {
-            sender.send_osc_packet(&packet).expect("invalid packet")
+            if let Err(err) = sender.send_osc_packet(packet) {
+                result = result.and(Err(err));
+            }
         }
+
+        result
     }
 }
 
 impl Drop for Server {
     fn drop(&mut self) {
+        let mut bundles = Vec::with_capacity(3);
+
         let source_message = OscPacket::Message(OscMessage {
             addr: "/tuio/2Dobj".into(),
             args: vec![
@@ -410,8 +1228,8 @@ impl Drop for Server {
             args: vec![OscType::String("fseq".into()), OscType::Int(-1)]
         });
     
-        let packet = OscPacket::Bundle(OscBundle { 
-           timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
+        bundles.push(OscBundle { 
+           timetag: osc_encode_decode::encode_time_tag(SystemTime::now()), 
            content: vec![
                source_message,
                alive_message,
@@ -419,8 +1237,6 @@ impl Drop for Server {
            ]
         });
 
-        self.deliver_osc_packet(packet);
-
         let source_message = OscPacket::Message(OscMessage {
             addr: "/tuio/2Dcur".into(),
             args: vec![
@@ -439,8 +1255,8 @@ impl Drop for Server {
             args: vec![OscType::String("fseq".into()), OscType::Int(-1)]
         });
     
-        let packet = OscPacket::Bundle(OscBundle { 
-           timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
+        bundles.push(OscBundle { 
+           timetag: osc_encode_decode::encode_time_tag(SystemTime::now()), 
            content: vec![
                source_message,
                alive_message,
@@ -448,8 +1264,6 @@ impl Drop for Server {
            ]
         });
 
-        self.deliver_osc_packet(packet);
-
         let source_message = OscPacket::Message(OscMessage {
             addr: "/tuio/2Dblb".into(),
             args: vec![
@@ -468,8 +1282,8 @@ impl Drop for Server {
             args: vec![OscType::String("fseq".into()), OscType::Int(-1)]
         });
     
-        let packet = OscPacket::Bundle(OscBundle { 
-           timetag: OscTime::try_from(SystemTime::now()).expect("failed with system time conversion"), 
+        bundles.push(OscBundle { 
+           timetag: osc_encode_decode::encode_time_tag(SystemTime::now()), 
            content: vec![
                source_message,
                alive_message,
@@ -477,18 +1291,515 @@ impl Drop for Server {
            ]
         });
 
-        self.deliver_osc_packet(packet);
+        let _ = self.deliver_frame_bundles(bundles);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use crate::{osc_encode_decode::{OscDecoder, DecodeOsc, Set}, loopback::LoopbackTransport, clock::TestClock, Client};
+
     use super::*;
 
+    #[derive(Clone, Default)]
+    struct PacketCollector(Arc<Mutex<Vec<OscPacket>>>);
+
+    impl SendOsc<OscPacket, TuioError> for PacketCollector {
+        fn send_osc_packet(&self, packet: &OscPacket) -> Result<(), TuioError> {
+            self.0.lock().unwrap().push(packet.clone());
+            Ok(())
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        fn is_local(&self) -> bool {
+            true
+        }
+    }
+
+    struct FailingSender;
+
+    impl SendOsc<OscPacket, TuioError> for FailingSender {
+        fn send_osc_packet(&self, _: &OscPacket) -> Result<(), TuioError> {
+            Err(TuioError::Socket(std::io::Error::from(std::io::ErrorKind::ConnectionRefused)))
+        }
+
+        fn is_connected(&self) -> bool {
+            false
+        }
+
+        fn is_local(&self) -> bool {
+            true
+        }
+    }
+
+    fn decode_frame(packet: &OscPacket) -> Vec<crate::osc_encode_decode::TuioBundle> {
+        match packet {
+            OscPacket::Bundle(bundle) => bundle.content.iter().map(|packet| match packet {
+                OscPacket::Bundle(bundle) => OscDecoder::decode_bundle(bundle.clone()).unwrap(),
+                packet => panic!("not a bundle: {:?}", packet),
+            }).collect(),
+            packet => panic!("not a bundle: {:?}", packet),
+        }
+    }
+
+    #[test]
+    fn stalled_tcp_client() {
+        let sender = TcpSender::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+        let _stream = TcpStream::connect(sender.local_addr().unwrap()).unwrap();
+        assert!(sender.is_connected());
+
+        let packet = OscPacket::Message(OscMessage { addr: "/test".into(), args: vec![OscType::Blob(vec![0; 60000])] });
+        let start = Instant::now();
+
+        for _ in 0..1000 {
+            sender.send_osc_packet(&packet).unwrap();
+
+            if !sender.is_connected() {
+                break;
+            }
+        }
+
+        assert!(!sender.is_connected());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
     #[test]
     fn id_wrapping() {
         let mut server = Server::new("source_name").unwrap();
         server.session_id = i32::MAX;
         assert_eq!(server.get_session_id(), i32::MIN);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn session_id_pool() {
+        let mut server = Server::from_osc_sender(PacketCollector::default());
+
+        let first = server.alloc_session_id();
+        let second = server.alloc_session_id();
+        let cursor_id = server.create_cursor(0., 0.);
+        assert_eq!((first, second, cursor_id), (0, 1, 2));
+
+        server.free_session_id(first);
+        server.free_session_id(first);
+        server.free_session_id(cursor_id);
+        assert_eq!(server.create_cursor(0., 0.), 3);
+        assert_eq!(server.alloc_session_id(), first);
+        assert_eq!(server.alloc_session_id(), 4);
+
+        server.session_id = -1;
+        assert_eq!(server.create_cursor(0., 0.), 5);
+    }
+
+    #[test]
+    fn cursor_velocity_override() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+
+        server.init_frame();
+        let session_id = server.alloc_session_id();
+        server.set_cursor_with_velocity(session_id, Position { x: 0.5, y: 0.5 }, Velocity { x: 2., y: -1. }, 0.5);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(client.cursors()[0].get_session_id(), session_id);
+        assert_eq!(client.cursors()[0].get_velocity(), &Velocity { x: 2., y: -1. });
+        assert_eq!(client.cursors()[0].get_acceleration(), 0.5);
+
+        server.init_frame();
+        server.set_cursor_with_velocity(session_id, Position { x: 0.5, y: 0.5 }, Velocity { x: 0.25, y: 0. }, 0.);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(server.cursor_map[&session_id].update_count(), 1);
+        assert_eq!(client.cursors()[0].get_velocity(), &Velocity { x: 0.25, y: 0. });
+        assert_eq!(client.cursors()[0].get_acceleration(), 0.);
+    }
+
+    #[test]
+    fn clock_velocity() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+        let clock = TestClock::new();
+        server.set_clock(clock.clone());
+
+        server.init_frame();
+        let session_id = server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+
+        clock.advance(Duration::from_millis(250));
+        server.init_frame();
+        server.update_cursor(session_id, 0.75, 0.5);
+        server.commit_frame().unwrap();
+
+        clock.advance(Duration::from_millis(500));
+        server.init_frame();
+        server.update_cursor(session_id, 0.75, 0.25);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(client.cursors()[0].get_velocity(), &Velocity { x: 0., y: -0.5 });
+        assert_eq!(client.cursors()[0].get_acceleration(), -1.);
+    }
+
+    #[test]
+    fn min_delta_time() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+        let clock = TestClock::new();
+        server.set_clock(clock.clone());
+        server.set_min_delta_time(Duration::from_millis(100));
+
+        server.init_frame();
+        let session_id = server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+
+        clock.advance(Duration::from_millis(50));
+        server.init_frame();
+        server.update_cursor(session_id, 0.75, 0.5);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(client.cursors()[0].get_position(), &Position { x: 0.75, y: 0.5 });
+        assert_eq!(client.cursors()[0].get_velocity(), &Velocity::default());
+
+        clock.advance(Duration::from_millis(50));
+        server.init_frame();
+        server.update_cursor(session_id, 0.75, 0.5);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(client.cursors()[0].get_velocity(), &Velocity { x: 2.5, y: 0. });
+    }
+
+    #[test]
+    fn ephemeral_port() {
+        let server = Server::bind("127.0.0.1:0").unwrap();
+        let address = server.local_addr().unwrap();
+        assert_ne!(address.port(), 0);
+
+        let client = Client::new_tcp(address);
+        assert!(client.recv_once(Duration::from_millis(10)).is_err());
+
+        server.send_frame(&[Cursor::new(1, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        assert_eq!(client.recv_once(Duration::from_secs(1)).unwrap().len(), 1);
+
+        let udp_server = Server::from_socket_addr(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3333)).unwrap();
+        assert_ne!(udp_server.local_addr().unwrap().port(), 0);
+        assert_eq!(Server::from_osc_sender(PacketCollector::default()).local_addr(), None);
+    }
+
+    #[test]
+    fn builder() {
+        let server = Server::builder()
+            .target(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 3334))
+            .source_name("source_name")
+            .periodic_message(Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        assert_eq!(server.source_name, "source_name@local");
+        assert!(server.periodic_messaging);
+        assert_eq!(server.update_interval, Duration::from_millis(10));
+
+        let unspecified = Server::builder().target(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 3333)).build();
+        assert!(matches!(unspecified, Err(TuioError::Socket(err)) if err.kind() == std::io::ErrorKind::InvalidInput));
+
+        let no_port = Server::builder().target(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).build();
+        assert!(matches!(no_port, Err(TuioError::Socket(err)) if err.kind() == std::io::ErrorKind::InvalidInput));
+    }
+
+    #[test]
+    fn send_frame() {
+        let collector = PacketCollector::default();
+        let server = Server::from_osc_sender(collector.clone());
+
+        let cursors = vec![Cursor::new(0, Position { x: 0.5, y: 0.5 })];
+        let blobs = vec![Blob::new(1, Position { x: 0.5, y: 0.5 }, 0., 0.1, 0.1, 0.01), Blob::new(2, Position { x: 0.2, y: 0.2 }, 0., 0.1, 0.1, 0.01)];
+
+        server.send_frame(&cursors, &[], &blobs).unwrap();
+        server.send_frame(&[], &[], &[]).unwrap();
+
+        let packets = collector.0.lock().unwrap();
+        assert_eq!(packets.len(), 2);
+
+        let frame = decode_frame(&packets[0]);
+        assert_eq!(frame.len(), 3);
+        assert!(frame.iter().all(|bundle| bundle.fseq == 1));
+        assert_eq!(frame[0].alive, vec![0]);
+        assert!(frame[1].alive.is_empty());
+        assert_eq!(frame[2].alive, vec![1, 2]);
+        assert!(matches!(&frame[2].set, Some(Set::Blob(decoded)) if decoded == &blobs));
+
+        let empty_frame = decode_frame(&packets[1]);
+        assert!(empty_frame.iter().all(|bundle| bundle.fseq == 2 && bundle.alive.is_empty() && bundle.set.is_none()));
+    }
+
+    #[test]
+    fn sender_error() {
+        let collector = PacketCollector::default();
+        let mut server = Server::from_osc_sender(FailingSender);
+        server.add_osc_sender(collector.clone());
+
+        let result = server.send_frame(&[], &[], &[]);
+        assert!(matches!(result, Err(TuioError::Socket(err)) if err.kind() == std::io::ErrorKind::ConnectionRefused));
+        assert_eq!(collector.0.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn alive_diff() {
+        let mut server = Server::from_osc_sender(PacketCollector::default());
+
+        server.init_frame();
+        let cursor_id = server.create_cursor(0., 0.);
+        let object_id = server.create_object(1, 0., 0., 0.);
+        server.commit_frame().unwrap();
+
+        assert_eq!(server.alive_diff(), (vec![object_id, cursor_id], vec![]));
+
+        server.init_frame();
+        server.remove_cursor(cursor_id);
+        let blob_id = server.create_blob(0., 0., 0., 0.1, 0.1, 0.01);
+        server.commit_frame().unwrap();
+
+        assert_eq!(server.alive_diff(), (vec![blob_id], vec![cursor_id]));
+
+        server.send_frame(&[], &[], &[]).unwrap();
+        assert_eq!(server.alive_diff(), (vec![], vec![object_id, blob_id]));
+    }
+
+    #[test]
+    fn remove() {
+        let collector = PacketCollector::default();
+        let mut server = Server::from_osc_sender(collector.clone());
+
+        server.init_frame();
+        let first_id = server.create_cursor(0.25, 0.25);
+        let second_id = server.create_cursor(0.75, 0.75);
+        server.commit_frame().unwrap();
+
+        server.remove(first_id).unwrap();
+        server.remove(first_id).unwrap();
+
+        let packets = collector.0.lock().unwrap();
+        assert_eq!(packets.len(), 2);
+
+        let frame = decode_frame(&packets[1]);
+        assert_eq!(frame.len(), 1);
+        assert_eq!(frame[0].fseq, 2);
+        assert_eq!(frame[0].alive, vec![second_id]);
+        assert_eq!(server.alive_diff(), (vec![], vec![first_id]));
+    }
+
+    #[test]
+    fn time_tag() {
+        let collector = PacketCollector::default();
+        let mut server = Server::from_osc_sender(collector.clone());
+
+        let before = SystemTime::now();
+        server.send_frame(&[], &[], &[]).unwrap();
+        server.set_immediate_time_tag(true);
+        server.send_frame(&[], &[], &[]).unwrap();
+
+        let packets = collector.0.lock().unwrap();
+
+        match &packets[0] {
+            OscPacket::Bundle(bundle) => {
+                let time = osc_encode_decode::decode_time_tag(bundle.timetag);
+                assert!(time.duration_since(before).unwrap() < Duration::from_secs(1));
+            },
+            packet => panic!("not a bundle: {:?}", packet),
+        }
+
+        match &packets[1] {
+            OscPacket::Bundle(bundle) => {
+                assert_eq!(bundle.timetag, OscTime { seconds: 0, fractional: 1 });
+                assert!(bundle.content.iter().all(|packet| matches!(packet, OscPacket::Bundle(bundle) if bundle.timetag == OscTime { seconds: 0, fractional: 1 })));
+            },
+            packet => panic!("not a bundle: {:?}", packet),
+        }
+    }
+
+    #[test]
+    fn keepalive() {
+        let collector = PacketCollector::default();
+        let clock = TestClock::new();
+        let mut server = Server::from_osc_sender(collector.clone());
+        server.set_clock(clock.clone());
+        server.set_keepalive(Some(Duration::from_millis(20)));
+
+        assert!(!server.send_keepalive().unwrap());
+        server.send_frame(&[Cursor::new(0, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        assert!(!server.send_keepalive().unwrap());
+
+        clock.advance(Duration::from_millis(19));
+        assert!(!server.send_keepalive().unwrap());
+
+        clock.advance(Duration::from_millis(1));
+        assert!(server.send_keepalive().unwrap());
+        assert!(!server.send_keepalive().unwrap());
+
+        let packets = collector.0.lock().unwrap();
+        assert_eq!(packets.len(), 2);
+        assert!(decode_frame(&packets[1]).iter().all(|bundle| bundle.fseq == -1));
+        assert_eq!(decode_frame(&packets[1])[0].alive, vec![0]);
+        assert_eq!(server.alive_diff(), (vec![0], vec![]));
+    }
+
+    #[test]
+    fn keepalive_thread() {
+        let collector = PacketCollector::default();
+        let server = Arc::new(Server::from_osc_sender(collector.clone()));
+        let mut keepalive = Server::spawn_keepalive(&server, Duration::from_millis(5));
+
+        server.send_frame(&[], &[], &[]).unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        keepalive.stop();
+
+        assert!(!keepalive.is_running());
+        assert!(collector.0.lock().unwrap().len() > 2);
+        assert!(keepalive.take_last_error().is_none());
+    }
+
+    #[test]
+    fn keepalive_thread_error() {
+        let server = Arc::new(Server::from_osc_sender(FailingSender));
+        let mut keepalive = Server::spawn_keepalive(&server, Duration::from_millis(5));
+
+        assert!(server.send_frame(&[], &[], &[]).is_err());
+        std::thread::sleep(Duration::from_millis(50));
+
+        assert!(keepalive.is_running());
+        assert!(matches!(keepalive.take_last_error(), Some(TuioError::Socket(err)) if err.kind() == std::io::ErrorKind::ConnectionRefused));
+
+        keepalive.stop();
+    }
+
+    #[test]
+    fn max_rate() {
+        let collector = PacketCollector::default();
+        let clock = TestClock::new();
+        let mut server = Server::from_osc_sender(collector.clone());
+        server.set_clock(clock.clone());
+        server.set_max_rate(Some(8.));
+
+        for session_id in 0..3 {
+            server.send_frame(&[Cursor::new(session_id, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+        }
+
+        assert_eq!(collector.0.lock().unwrap().len(), 1);
+        assert!(!server.send_pending_frame().unwrap());
+
+        clock.advance(Duration::from_millis(124));
+        assert!(!server.send_pending_frame().unwrap());
+
+        clock.advance(Duration::from_millis(1));
+        assert!(server.send_pending_frame().unwrap());
+        assert!(!server.send_pending_frame().unwrap());
+
+        let packets = collector.0.lock().unwrap();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(decode_frame(&packets[0])[0].alive, vec![0]);
+        assert_eq!(decode_frame(&packets[1])[0].alive, vec![2]);
+    }
+
+    #[test]
+    fn single_packet_frame() {
+        let collector = PacketCollector::default();
+        let mut server = Server::from_osc_sender(collector.clone());
+
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+        server.create_object(1, 0.5, 0.5, 0.);
+        server.create_blob(0.5, 0.5, 0., 0.1, 0.1, 0.01);
+        server.commit_frame().unwrap();
+        server.send_full_messages().unwrap();
+
+        let packets = collector.0.lock().unwrap();
+        assert_eq!(packets.len(), 2);
+        assert!(packets.iter().all(|packet| decode_frame(packet).len() == 3));
+    }
+
+    #[test]
+    fn tuio_10_frames() {
+        let collector = PacketCollector::default();
+        let legacy_transport = LoopbackTransport::new();
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(collector.clone());
+        server.add_osc_sender(legacy_transport.clone());
+        server.add_osc_sender(transport.clone());
+        server.set_source_name("legacy");
+        server.set_tuio_version(TuioVersion::V10);
+
+        let mut legacy_client = Client::from_loopback(&legacy_transport);
+        legacy_client.set_tuio_version(TuioVersion::V10);
+        let client = Client::from_loopback(&transport);
+
+        server.init_frame();
+        server.create_cursor(0.5, 0.5);
+        server.create_object(1, 0.25, 0.25, 0.);
+        server.create_blob(0.75, 0.75, 0., 0.1, 0.1, 0.01);
+        server.commit_frame().unwrap();
+
+        let packets = collector.0.lock().unwrap().clone();
+        assert_eq!(packets.len(), 2);
+
+        for packet in &packets {
+            let OscPacket::Bundle(bundle) = packet else {
+                panic!("not a bundle: {:?}", packet);
+            };
+
+            assert!(bundle.content.iter().all(|packet| matches!(packet, OscPacket::Message(message)
+                if message.addr != "/tuio/2Dblb" && message.args.first() != Some(&OscType::String("source".into())))));
+        }
+
+        legacy_client.refresh().unwrap();
+        assert_eq!(legacy_client.cursors().len(), 1);
+        assert_eq!(legacy_client.objects().len(), 1);
+        assert!(legacy_client.blobs().is_empty());
+        assert!(legacy_client.source_list.borrow().contains_key(""));
+
+        client.refresh().unwrap();
+        assert!(client.cursors().is_empty());
+    }
+
+    #[test]
+    fn split_frame() {
+        let transport = LoopbackTransport::new();
+        let collector = PacketCollector::default();
+        let mut server = Server::from_osc_sender(transport.clone());
+        server.add_osc_sender(collector.clone());
+        server.set_max_packet_size(512);
+        let client = Client::from_loopback(&transport);
+
+        let cursors: Vec<Cursor> = (0..40).map(|session_id| Cursor::new(session_id, Position { x: 0.5, y: 0.5 })).collect();
+        server.send_frame(&cursors, &[], &[]).unwrap();
+
+        let packets = collector.0.lock().unwrap().clone();
+        assert!(packets.len() > 3);
+        assert!(packets.iter().all(|packet| encoder::encode(packet).unwrap().len() <= 512));
+
+        let bundles: Vec<_> = packets.into_iter().map(|packet| match packet {
+            OscPacket::Bundle(bundle) => OscDecoder::decode_bundle(bundle).unwrap(),
+            packet => panic!("not a bundle: {:?}", packet),
+        }).collect();
+
+        assert!(bundles.iter().all(|bundle| bundle.fseq == 1));
+
+        let sent_cursors: Vec<Cursor> = bundles.into_iter().filter_map(|bundle| match bundle.set {
+            Some(Set::Cursor(cursors)) => Some(cursors),
+            _ => None,
+        }).flatten().collect();
+        assert_eq!(sent_cursors, cursors);
+
+        client.refresh().unwrap();
+        assert_eq!(client.cursors().len(), 40);
+    }
+}
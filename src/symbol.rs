@@ -0,0 +1,64 @@
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Symbol {
+    pub(crate) session_id: i32,
+    pub(crate) type_id: u16,
+    pub(crate) user_id: u16,
+    pub(crate) symbol_id: i32,
+    pub(crate) group: String,
+    pub(crate) data: String,
+}
+
+impl Symbol {
+    /// Creates a new [Symbol]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `type_id` - the symbol's type ID
+    /// * `user_id` - the ID of the user owning the symbol
+    /// * `symbol_id` - the symbol's component ID
+    /// * `group` - the symbol's group, such as `qr` or `dmtx`
+    /// * `data` - the decoded payload of the symbol
+    pub fn new(
+        session_id: i32,
+        type_id: u16,
+        user_id: u16,
+        symbol_id: i32,
+        group: &str,
+        data: &str,
+    ) -> Self {
+        Self {
+            session_id,
+            type_id,
+            user_id,
+            symbol_id,
+            group: group.to_string(),
+            data: data.to_string(),
+        }
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_type_id(&self) -> u16 {
+        self.type_id
+    }
+
+    pub fn get_user_id(&self) -> u16 {
+        self.user_id
+    }
+
+    pub fn get_symbol_id(&self) -> i32 {
+        self.symbol_id
+    }
+
+    /// Returns the symbol's group, such as `qr` or `dmtx`
+    pub fn get_group(&self) -> &str {
+        &self.group
+    }
+
+    /// Returns the decoded payload of the symbol
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+}
@@ -0,0 +1,311 @@
+use std::sync::Mutex;
+
+use crate::{client::Listener, Cursor, Cursor3D, Cursor25D, Object, Blob, Blob3D, Token, Pointer, Symbol, Bounds, Geometry, Control};
+
+/// The kind of a [Listener] callback
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Added,
+    Updated,
+    Removed,
+}
+
+/// A snapshot of the TUIO input passed to a [Listener] callback
+#[derive(Debug, Clone)]
+pub enum RecordedEntity {
+    Cursor(Cursor),
+    Object(Object),
+    Blob(Blob),
+    Cursor3D(Cursor3D),
+    Cursor25D(Cursor25D),
+    Blob3D(Blob3D),
+    Token(Token),
+    Pointer(Pointer),
+    Symbol(Symbol),
+    Bounds(Bounds),
+    Geometry(Geometry),
+    Control(Control),
+}
+
+impl RecordedEntity {
+    /// Returns the session ID of the input
+    pub fn get_session_id(&self) -> i32 {
+        match self {
+            RecordedEntity::Cursor(cursor) => cursor.get_session_id(),
+            RecordedEntity::Object(object) => object.get_session_id(),
+            RecordedEntity::Blob(blob) => blob.get_session_id(),
+            RecordedEntity::Cursor3D(cursor) => cursor.get_session_id(),
+            RecordedEntity::Cursor25D(cursor) => cursor.get_session_id(),
+            RecordedEntity::Blob3D(blob) => blob.get_session_id(),
+            RecordedEntity::Token(token) => token.get_session_id(),
+            RecordedEntity::Pointer(pointer) => pointer.get_session_id(),
+            RecordedEntity::Symbol(symbol) => symbol.get_session_id(),
+            RecordedEntity::Bounds(bounds) => bounds.get_session_id(),
+            RecordedEntity::Geometry(geometry) => geometry.get_session_id(),
+            RecordedEntity::Control(control) => control.get_session_id(),
+        }
+    }
+}
+
+/// A [Listener] callback recorded by a [RecordingListener]
+#[derive(Debug, Clone)]
+pub struct RecordedEvent {
+    /// The position of the callback among every callback recorded, starting at 0
+    pub sequence: usize,
+    pub source_name: String,
+    pub kind: EventKind,
+    pub entity: RecordedEntity,
+}
+
+/// A [Listener] recording every callback, to assert the events received by downstream code in tests
+///
+/// ```
+/// use tuio_rs::{Client, Server, Cursor, cursor::Position, loopback::LoopbackTransport, testing::{RecordingListener, EventKind}};
+///
+/// let transport = LoopbackTransport::new();
+/// let server = Server::from_osc_sender(transport.clone());
+/// let client = Client::from_loopback(&transport);
+/// let listener = RecordingListener::new();
+///
+/// server.send_frame(&[Cursor::new(3, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+/// client.refresh().unwrap().unwrap().dispatch(&listener);
+/// server.send_frame(&[], &[], &[]).unwrap();
+/// client.refresh().unwrap().unwrap().dispatch(&listener);
+///
+/// assert_eq!(listener.history(3), vec![EventKind::Added, EventKind::Removed]);
+/// ```
+#[derive(Default)]
+pub struct RecordingListener {
+    events: Mutex<Vec<RecordedEvent>>,
+}
+
+impl RecordingListener {
+    /// Creates a [RecordingListener] without any recorded event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, source_name: &str, kind: EventKind, entity: RecordedEntity) {
+        let mut events = self.events.lock().unwrap();
+        let sequence = events.len();
+
+        events.push(RecordedEvent { sequence, source_name: source_name.to_string(), kind, entity });
+    }
+
+    /// Returns a copy of every recorded event, in the order of the callbacks
+    pub fn events(&self) -> Vec<RecordedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Returns the kinds of the events recorded for a session ID, in the order of the callbacks
+    ///
+    /// Session IDs are only unique within a profile and a source, so inputs of several profiles or sources may share a history
+    /// # Arguments
+    /// * `session_id` - the session ID of the input
+    pub fn history(&self, session_id: i32) -> Vec<EventKind> {
+        self.events.lock().unwrap().iter()
+            .filter(|event| event.entity.get_session_id() == session_id)
+            .map(|event| event.kind)
+            .collect()
+    }
+
+    /// Returns the number of recorded events
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    /// Returns true if no event was recorded
+    pub fn is_empty(&self) -> bool {
+        self.events.lock().unwrap().is_empty()
+    }
+
+    /// Forgets every recorded event and restarts the sequence numbers at 0
+    pub fn clear(&self) {
+        self.events.lock().unwrap().clear();
+    }
+}
+
+impl Listener for RecordingListener {
+    fn cursor_added(&self, source_name: &str, cursor: &Cursor) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Cursor(cursor.clone()));
+    }
+
+    fn cursor_updated(&self, source_name: &str, cursor: &Cursor) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Cursor(cursor.clone()));
+    }
+
+    fn cursor_removed(&self, source_name: &str, cursor: &Cursor) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Cursor(cursor.clone()));
+    }
+
+    fn object_added(&self, source_name: &str, object: &Object) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Object(object.clone()));
+    }
+
+    fn object_updated(&self, source_name: &str, object: &Object) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Object(object.clone()));
+    }
+
+    fn object_removed(&self, source_name: &str, object: &Object) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Object(object.clone()));
+    }
+
+    fn blob_added(&self, source_name: &str, blob: &Blob) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Blob(blob.clone()));
+    }
+
+    fn blob_updated(&self, source_name: &str, blob: &Blob) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Blob(blob.clone()));
+    }
+
+    fn blob_removed(&self, source_name: &str, blob: &Blob) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Blob(blob.clone()));
+    }
+
+    fn cursor_3d_added(&self, source_name: &str, cursor: &Cursor3D) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Cursor3D(cursor.clone()));
+    }
+
+    fn cursor_3d_updated(&self, source_name: &str, cursor: &Cursor3D) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Cursor3D(cursor.clone()));
+    }
+
+    fn cursor_3d_removed(&self, source_name: &str, cursor: &Cursor3D) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Cursor3D(cursor.clone()));
+    }
+
+    fn cursor_25d_added(&self, source_name: &str, cursor: &Cursor25D) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Cursor25D(cursor.clone()));
+    }
+
+    fn cursor_25d_updated(&self, source_name: &str, cursor: &Cursor25D) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Cursor25D(cursor.clone()));
+    }
+
+    fn cursor_25d_removed(&self, source_name: &str, cursor: &Cursor25D) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Cursor25D(cursor.clone()));
+    }
+
+    fn blob_3d_added(&self, source_name: &str, blob: &Blob3D) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Blob3D(blob.clone()));
+    }
+
+    fn blob_3d_updated(&self, source_name: &str, blob: &Blob3D) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Blob3D(blob.clone()));
+    }
+
+    fn blob_3d_removed(&self, source_name: &str, blob: &Blob3D) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Blob3D(blob.clone()));
+    }
+
+    fn token_added(&self, source_name: &str, token: &Token) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Token(token.clone()));
+    }
+
+    fn token_updated(&self, source_name: &str, token: &Token) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Token(token.clone()));
+    }
+
+    fn token_removed(&self, source_name: &str, token: &Token) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Token(token.clone()));
+    }
+
+    fn pointer_added(&self, source_name: &str, pointer: &Pointer) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Pointer(pointer.clone()));
+    }
+
+    fn pointer_updated(&self, source_name: &str, pointer: &Pointer) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Pointer(pointer.clone()));
+    }
+
+    fn pointer_removed(&self, source_name: &str, pointer: &Pointer) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Pointer(pointer.clone()));
+    }
+
+    fn symbol_added(&self, source_name: &str, symbol: &Symbol) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Symbol(symbol.clone()));
+    }
+
+    fn symbol_updated(&self, source_name: &str, symbol: &Symbol) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Symbol(symbol.clone()));
+    }
+
+    fn symbol_removed(&self, source_name: &str, symbol: &Symbol) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Symbol(symbol.clone()));
+    }
+
+    fn bounds_added(&self, source_name: &str, bounds: &Bounds) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Bounds(bounds.clone()));
+    }
+
+    fn bounds_updated(&self, source_name: &str, bounds: &Bounds) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Bounds(bounds.clone()));
+    }
+
+    fn bounds_removed(&self, source_name: &str, bounds: &Bounds) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Bounds(bounds.clone()));
+    }
+
+    fn geometry_added(&self, source_name: &str, geometry: &Geometry) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Geometry(geometry.clone()));
+    }
+
+    fn geometry_updated(&self, source_name: &str, geometry: &Geometry) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Geometry(geometry.clone()));
+    }
+
+    fn geometry_removed(&self, source_name: &str, geometry: &Geometry) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Geometry(geometry.clone()));
+    }
+
+    fn control_added(&self, source_name: &str, control: &Control) {
+        self.record(source_name, EventKind::Added, RecordedEntity::Control(control.clone()));
+    }
+
+    fn control_updated(&self, source_name: &str, control: &Control) {
+        self.record(source_name, EventKind::Updated, RecordedEntity::Control(control.clone()));
+    }
+
+    fn control_removed(&self, source_name: &str, control: &Control) {
+        self.record(source_name, EventKind::Removed, RecordedEntity::Control(control.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{loopback::LoopbackTransport, Client, Server};
+
+    use super::*;
+
+    #[test]
+    fn recording_listener() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+        let listener = RecordingListener::new();
+
+        server.init_frame();
+        let cursor_id = server.create_cursor(0.5, 0.5);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap().unwrap().dispatch(&listener);
+
+        server.init_frame();
+        server.update_cursor(cursor_id, 0.6, 0.5);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap().unwrap().dispatch(&listener);
+
+        server.init_frame();
+        server.remove_cursor(cursor_id);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap().unwrap().dispatch(&listener);
+
+        assert_eq!(listener.history(cursor_id), vec![EventKind::Added, EventKind::Updated, EventKind::Removed]);
+
+        let events = listener.events();
+        assert_eq!(events.iter().map(|event| event.sequence).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert!(matches!(&events[1].entity, RecordedEntity::Cursor(cursor) if cursor.get_x_position() == 0.6));
+
+        listener.clear();
+        assert!(listener.is_empty());
+    }
+}
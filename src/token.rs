@@ -0,0 +1,216 @@
+use std::time::Duration;
+
+use crate::cursor::{Position, Velocity, linear_motion, smooth, rotation_motion, MotionOrigin, DEFAULT_MIN_DELTA_TIME};
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Token {
+    pub(crate) session_id: i32,
+    pub(crate) type_id: u16,
+    pub(crate) user_id: u16,
+    pub(crate) component_id: i32,
+    pub(crate) position: Position,
+    pub(crate) angle: f32,
+    pub(crate) velocity: Velocity,
+    pub(crate) rotation_speed: f32,
+    pub(crate) acceleration: f32,
+    pub(crate) rotation_acceleration: f32,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) motion_origin: MotionOrigin<(Position, f32)>,
+}
+
+impl Token {
+    /// Creates a new [Token]
+    /// # Arguments
+    /// * `session_id` - a unique session ID
+    /// * `type_id` - the token's type ID
+    /// * `user_id` - the ID of the user owning the token
+    /// * `component_id` - the token's component ID
+    /// * `position` - a normalized [Position]
+    /// * `angle` - an angle in radians
+    pub fn new(
+        session_id: i32,
+        type_id: u16,
+        user_id: u16,
+        component_id: i32,
+        position: Position,
+        angle: f32,
+    ) -> Self {
+        Self {
+            session_id,
+            type_id,
+            user_id,
+            component_id,
+            position,
+            velocity: Velocity::default(),
+            acceleration: 0f32,
+            angle,
+            rotation_speed: 0f32,
+            rotation_acceleration: 0f32,
+            motion_origin: MotionOrigin::default(),
+        }
+    }
+
+    /// Returns this [Token] with motion
+    /// # Arguments
+    /// * `velocity` - a normalized [Velocity]
+    /// * `rotation_speed` - a rotation speed in turns per second
+    /// * `acceleration` - a normalized acceleration
+    /// * `rotation_acceleration` - a rotation acceleration in turns per second squared
+    pub fn with_motion(
+        mut self,
+        velocity: Velocity,
+        rotation_speed: f32,
+        acceleration: f32,
+        rotation_acceleration: f32,
+    ) -> Self {
+        self.velocity = velocity;
+        self.rotation_speed = rotation_speed;
+        self.acceleration = acceleration;
+        self.rotation_acceleration = rotation_acceleration;
+        self
+    }
+
+    /// Updates the [Token], computing its velocity, acceleration, rotation speed and rotation acceleration once [DEFAULT_MIN_DELTA_TIME] elapsed since the last update which computed them
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    pub fn update(&mut self, delta_time: Duration, position: Position, angle: f32) {
+        self.update_with_min_delta_time(delta_time, DEFAULT_MIN_DELTA_TIME, position, angle);
+    }
+
+    /// Updates the [Token], computing its velocity, acceleration, rotation speed and rotation acceleration once a minimum delta time elapsed since the last update which computed them
+    ///
+    /// Closer updates keep the previous motion instead of producing a spike, while the other values are still updated
+    /// # Arguments
+    /// * `delta_time` - the [Duration] since last update
+    /// * `min_delta_time` - the shortest [Duration] between two updates computing motion
+    /// * `position` - the new [Position]
+    /// * `angle` - the new angle
+    pub fn update_with_min_delta_time(&mut self, delta_time: Duration, min_delta_time: Duration, position: Position, angle: f32) {
+        if let Some(((previous_position, previous_angle), delta_time)) = self.motion_origin.advance(delta_time, min_delta_time, &(self.position, self.angle)) {
+            (self.velocity, self.acceleration) = linear_motion(&position, &previous_position, &self.velocity, delta_time);
+            (self.rotation_speed, self.rotation_acceleration) = rotation_motion(angle, previous_angle, self.rotation_speed, delta_time);
+        }
+
+        self.position = position;
+        self.angle = angle;
+    }
+
+    pub fn get_session_id(&self) -> i32 {
+        self.session_id
+    }
+
+    pub fn get_type_id(&self) -> u16 {
+        self.type_id
+    }
+
+    pub fn get_user_id(&self) -> u16 {
+        self.user_id
+    }
+
+    pub fn get_component_id(&self) -> i32 {
+        self.component_id
+    }
+
+    pub fn get_position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn get_x_position(&self) -> f32 {
+        self.position.x
+    }
+
+    pub fn get_y_position(&self) -> f32 {
+        self.position.y
+    }
+
+    pub fn get_velocity(&self) -> &Velocity {
+        &self.velocity
+    }
+
+    pub fn get_x_velocity(&self) -> f32 {
+        self.velocity.x
+    }
+
+    pub fn get_y_velocity(&self) -> f32 {
+        self.velocity.y
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    /// Returns the angle in radians
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Returns the rotation speed in turn per seconds
+    pub fn get_rotation_speed(&self) -> f32 {
+        self.rotation_speed
+    }
+
+    /// Returns the rotation acceleration in turn per seconds squared
+    pub fn get_rotation_acceleration(&self) -> f32 {
+        self.rotation_acceleration
+    }
+
+    /// Low-pass filters the velocity, rotation speed and accelerations of this [Token] against its previous state
+    /// # Arguments
+    /// * `previous` - the previous state of this [Token]
+    /// * `alpha` - the smoothing factor, 1 keeps the new values unfiltered
+    pub(crate) fn smooth_motion(&mut self, previous: &Token, alpha: f32) {
+        self.velocity = self.velocity.smooth(&previous.velocity, alpha);
+        self.acceleration = smooth(self.acceleration, previous.acceleration, alpha);
+        self.rotation_speed = smooth(self.rotation_speed, previous.rotation_speed, alpha);
+        self.rotation_acceleration = smooth(self.rotation_acceleration, previous.rotation_acceleration, alpha);
+    }
+}
+
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_id == other.session_id
+            && self.type_id == other.type_id
+            && self.user_id == other.user_id
+            && self.component_id == other.component_id
+            && self.get_x_position() == other.get_x_position()
+            && self.get_y_position() == other.get_y_position()
+            && self.angle == other.angle
+            && self.velocity == other.velocity
+            && self.rotation_speed == other.rotation_speed
+            && self.acceleration == other.acceleration
+            && self.rotation_acceleration == other.rotation_acceleration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::SQRT_2, time::Duration};
+
+    use crate::{cursor::Position, token::Token};
+
+    #[test]
+    fn token_update() {
+        let mut token = Token::new(0, 1, 2, 3, Position { x: 0., y: 0. }, 0.);
+
+        token.update(
+            Duration::from_secs(1),
+            Position { x: 1., y: 1. },
+            90f32.to_radians(),
+        );
+
+        assert_eq!(token.get_type_id(), 1);
+        assert_eq!(token.get_user_id(), 2);
+        assert_eq!(token.get_component_id(), 3);
+        assert_eq!(token.get_x_position(), 1.);
+        assert_eq!(token.get_y_position(), 1.);
+        assert_eq!(token.get_x_velocity(), 1.);
+        assert_eq!(token.get_y_velocity(), 1.);
+        assert_eq!(token.get_acceleration(), SQRT_2);
+        assert_eq!(token.get_angle(), 90f32.to_radians());
+        assert_eq!(token.get_rotation_speed(), 0.25);
+        assert_eq!(token.get_rotation_acceleration(), 0.25);
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use winit::{dpi::PhysicalSize, event::{Touch, TouchPhase}};
+
+use crate::Server;
+
+/// Maps the touch events of a [winit] window to the [crate::Cursor]s of a [Server], so a regular touchscreen can act as a TUIO source
+///
+/// Every touch creates a cursor when it starts, updates it when it moves and removes it when it ends or is cancelled.
+/// The touch events must be handled between [Server::init_frame] and [Server::commit_frame]
+#[derive(Default)]
+pub struct TouchTracker {
+    session_ids: HashMap<u64, i32>,
+}
+
+impl TouchTracker {
+    /// Creates a [TouchTracker] without any active touch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates, updates or removes the [crate::Cursor] of a touch depending on its phase
+    ///
+    /// Returns the session ID of the cursor, or [None] if the touch is unknown because it started before the tracker
+    /// # Arguments
+    /// * `server` - the [Server] sending the cursors
+    /// * `touch` - the winit [Touch] event
+    /// * `window_size` - the inner size of the window, used to normalize the touch location
+    pub fn handle_touch(&mut self, server: &mut Server, touch: &Touch, window_size: PhysicalSize<u32>) -> Option<i32> {
+        let x = (touch.location.x / window_size.width.max(1) as f64) as f32;
+        let y = (touch.location.y / window_size.height.max(1) as f64) as f32;
+
+        match touch.phase {
+            TouchPhase::Started => {
+                if let Some(session_id) = self.session_ids.remove(&touch.id) {
+                    server.remove_cursor(session_id);
+                }
+
+                let session_id = server.create_cursor(x, y);
+                self.session_ids.insert(touch.id, session_id);
+                Some(session_id)
+            },
+            TouchPhase::Moved => {
+                let session_id = *self.session_ids.get(&touch.id)?;
+                server.update_cursor(session_id, x, y);
+                Some(session_id)
+            },
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                let session_id = self.session_ids.remove(&touch.id)?;
+                server.remove_cursor(session_id);
+                Some(session_id)
+            },
+        }
+    }
+
+    /// Returns the number of active touches
+    pub fn len(&self) -> usize {
+        self.session_ids.len()
+    }
+
+    /// Returns true if no touch is active
+    pub fn is_empty(&self) -> bool {
+        self.session_ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use winit::{dpi::PhysicalPosition, event::DeviceId};
+
+    use crate::{loopback::LoopbackTransport, Client};
+
+    use super::*;
+
+    fn touch(id: u64, phase: TouchPhase, x: f64, y: f64) -> Touch {
+        Touch {
+            device_id: DeviceId::dummy(),
+            phase,
+            location: PhysicalPosition { x, y },
+            force: None,
+            id,
+        }
+    }
+
+    #[test]
+    fn touch_lifecycle() {
+        let transport = LoopbackTransport::new();
+        let mut server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+        let mut tracker = TouchTracker::new();
+        let window_size = PhysicalSize { width: 800, height: 400 };
+
+        server.init_frame();
+        let session_id = tracker.handle_touch(&mut server, &touch(7, TouchPhase::Started, 200., 100.), window_size).unwrap();
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(client.cursors()[0].get_session_id(), session_id);
+        assert_eq!(client.cursors()[0].get_x_position(), 0.25);
+        assert_eq!(client.cursors()[0].get_y_position(), 0.25);
+
+        server.init_frame();
+        tracker.handle_touch(&mut server, &touch(7, TouchPhase::Moved, 400., 200.), window_size);
+        assert!(tracker.handle_touch(&mut server, &touch(8, TouchPhase::Moved, 0., 0.), window_size).is_none());
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert_eq!(client.cursors()[0].get_x_position(), 0.5);
+
+        server.init_frame();
+        tracker.handle_touch(&mut server, &touch(7, TouchPhase::Ended, 400., 200.), window_size);
+        server.commit_frame().unwrap();
+        client.refresh().unwrap();
+
+        assert!(tracker.is_empty());
+        assert!(client.cursors().is_empty());
+    }
+}
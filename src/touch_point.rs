@@ -0,0 +1,66 @@
+use crate::{cursor::{Position, Velocity}, Cursor, Object, Blob};
+
+/// A contact on the surface of any 2D profile, exposing the session ID, position and velocity shared by [Cursor]s, [Object]s and [Blob]s
+///
+/// Applications treating every contact alike can rely on the common getters, while the profile-specific fields stay reachable by matching the variant
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TouchPoint {
+    Cursor(Cursor),
+    Object(Object),
+    Blob(Blob),
+}
+
+impl TouchPoint {
+    /// Returns the session ID of the contact, which is only unique within its profile and source
+    pub fn get_session_id(&self) -> i32 {
+        match self {
+            TouchPoint::Cursor(cursor) => cursor.get_session_id(),
+            TouchPoint::Object(object) => object.get_session_id(),
+            TouchPoint::Blob(blob) => blob.get_session_id(),
+        }
+    }
+
+    pub fn get_position(&self) -> &Position {
+        match self {
+            TouchPoint::Cursor(cursor) => cursor.get_position(),
+            TouchPoint::Object(object) => object.get_position(),
+            TouchPoint::Blob(blob) => blob.get_position(),
+        }
+    }
+
+    pub fn get_velocity(&self) -> &Velocity {
+        match self {
+            TouchPoint::Cursor(cursor) => cursor.get_velocity(),
+            TouchPoint::Object(object) => object.get_velocity(),
+            TouchPoint::Blob(blob) => blob.get_velocity(),
+        }
+    }
+
+    /// Returns true if the contact first appeared in the last frame received from its source
+    pub fn is_new_this_frame(&self) -> bool {
+        match self {
+            TouchPoint::Cursor(cursor) => cursor.is_new_this_frame(),
+            TouchPoint::Object(object) => object.is_new_this_frame(),
+            TouchPoint::Blob(blob) => blob.is_new_this_frame(),
+        }
+    }
+}
+
+impl From<Cursor> for TouchPoint {
+    fn from(cursor: Cursor) -> Self {
+        TouchPoint::Cursor(cursor)
+    }
+}
+
+impl From<Object> for TouchPoint {
+    fn from(object: Object) -> Self {
+        TouchPoint::Object(object)
+    }
+}
+
+impl From<Blob> for TouchPoint {
+    fn from(blob: Blob) -> Self {
+        TouchPoint::Blob(blob)
+    }
+}
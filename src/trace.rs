@@ -0,0 +1,173 @@
+use crate::{client::Listener, Cursor, Object, Blob, Cursor3D, Cursor25D, Blob3D, Token, Pointer, Symbol, Bounds, Geometry, Control};
+
+/// A [Listener] emitting a `tracing` event for every added and removed input, notified by [crate::client::TuioEvents::dispatch_to] before the registered [Listener]s
+pub(crate) struct TracingListener;
+
+impl Listener for TracingListener {
+    fn cursor_added(&self, source_name: &str, cursor: &Cursor) {
+        tracing::debug!(source = source_name, session_id = cursor.get_session_id(), "cursor added");
+    }
+
+    fn cursor_removed(&self, source_name: &str, cursor: &Cursor) {
+        tracing::debug!(source = source_name, session_id = cursor.get_session_id(), "cursor removed");
+    }
+
+    fn object_added(&self, source_name: &str, object: &Object) {
+        tracing::debug!(source = source_name, session_id = object.get_session_id(), "object added");
+    }
+
+    fn object_removed(&self, source_name: &str, object: &Object) {
+        tracing::debug!(source = source_name, session_id = object.get_session_id(), "object removed");
+    }
+
+    fn blob_added(&self, source_name: &str, blob: &Blob) {
+        tracing::debug!(source = source_name, session_id = blob.get_session_id(), "blob added");
+    }
+
+    fn blob_removed(&self, source_name: &str, blob: &Blob) {
+        tracing::debug!(source = source_name, session_id = blob.get_session_id(), "blob removed");
+    }
+
+    fn cursor_3d_added(&self, source_name: &str, cursor: &Cursor3D) {
+        tracing::debug!(source = source_name, session_id = cursor.get_session_id(), "cursor 3D added");
+    }
+
+    fn cursor_3d_removed(&self, source_name: &str, cursor: &Cursor3D) {
+        tracing::debug!(source = source_name, session_id = cursor.get_session_id(), "cursor 3D removed");
+    }
+
+    fn cursor_25d_added(&self, source_name: &str, cursor: &Cursor25D) {
+        tracing::debug!(source = source_name, session_id = cursor.get_session_id(), "cursor 2.5D added");
+    }
+
+    fn cursor_25d_removed(&self, source_name: &str, cursor: &Cursor25D) {
+        tracing::debug!(source = source_name, session_id = cursor.get_session_id(), "cursor 2.5D removed");
+    }
+
+    fn blob_3d_added(&self, source_name: &str, blob: &Blob3D) {
+        tracing::debug!(source = source_name, session_id = blob.get_session_id(), "blob 3D added");
+    }
+
+    fn blob_3d_removed(&self, source_name: &str, blob: &Blob3D) {
+        tracing::debug!(source = source_name, session_id = blob.get_session_id(), "blob 3D removed");
+    }
+
+    fn token_added(&self, source_name: &str, token: &Token) {
+        tracing::debug!(source = source_name, session_id = token.get_session_id(), "token added");
+    }
+
+    fn token_removed(&self, source_name: &str, token: &Token) {
+        tracing::debug!(source = source_name, session_id = token.get_session_id(), "token removed");
+    }
+
+    fn pointer_added(&self, source_name: &str, pointer: &Pointer) {
+        tracing::debug!(source = source_name, session_id = pointer.get_session_id(), "pointer added");
+    }
+
+    fn pointer_removed(&self, source_name: &str, pointer: &Pointer) {
+        tracing::debug!(source = source_name, session_id = pointer.get_session_id(), "pointer removed");
+    }
+
+    fn symbol_added(&self, source_name: &str, symbol: &Symbol) {
+        tracing::debug!(source = source_name, session_id = symbol.get_session_id(), "symbol added");
+    }
+
+    fn symbol_removed(&self, source_name: &str, symbol: &Symbol) {
+        tracing::debug!(source = source_name, session_id = symbol.get_session_id(), "symbol removed");
+    }
+
+    fn bounds_added(&self, source_name: &str, bounds: &Bounds) {
+        tracing::debug!(source = source_name, session_id = bounds.get_session_id(), "bounds added");
+    }
+
+    fn bounds_removed(&self, source_name: &str, bounds: &Bounds) {
+        tracing::debug!(source = source_name, session_id = bounds.get_session_id(), "bounds removed");
+    }
+
+    fn geometry_added(&self, source_name: &str, geometry: &Geometry) {
+        tracing::debug!(source = source_name, session_id = geometry.get_session_id(), "geometry added");
+    }
+
+    fn geometry_removed(&self, source_name: &str, geometry: &Geometry) {
+        tracing::debug!(source = source_name, session_id = geometry.get_session_id(), "geometry removed");
+    }
+
+    fn control_added(&self, source_name: &str, control: &Control) {
+        tracing::debug!(source = source_name, session_id = control.get_session_id(), "control added");
+    }
+
+    fn control_removed(&self, source_name: &str, control: &Control) {
+        tracing::debug!(source = source_name, session_id = control.get_session_id(), "control removed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt, sync::{Arc, Mutex, atomic::{AtomicU64, Ordering}}};
+
+    use tracing::{field::{Field, Visit}, span, Event, Metadata, Subscriber};
+
+    use crate::{loopback::LoopbackTransport, cursor::Position, Client, Cursor, Server};
+
+    #[derive(Default)]
+    struct MessageCollector {
+        messages: Arc<Mutex<Vec<String>>>,
+        next_span_id: AtomicU64,
+    }
+
+    struct MessageVisitor<'a>(&'a mut Option<String>);
+
+    impl Visit for MessageVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                *self.0 = Some(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl Subscriber for MessageCollector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            self.messages.lock().unwrap().push(span.metadata().name().to_string());
+            span::Id::from_u64(self.next_span_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut message = None;
+            event.record(&mut MessageVisitor(&mut message));
+            self.messages.lock().unwrap().extend(message);
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn tracing_events() {
+        let transport = LoopbackTransport::new();
+        let server = Server::from_osc_sender(transport.clone());
+        let client = Client::from_loopback(&transport);
+
+        let collector = MessageCollector::default();
+        let messages = collector.messages.clone();
+
+        tracing::subscriber::with_default(collector, || {
+            server.send_frame(&[Cursor::new(0, Position {x: 0.5, y: 0.5})], &[], &[]).unwrap();
+            client.refresh().unwrap();
+        });
+
+        let messages = messages.lock().unwrap();
+        assert_eq!(messages.iter().filter(|message| *message == "frame assembled").count(), 3);
+        assert_eq!(messages.iter().filter(|message| *message == "cursor added").count(), 1);
+        assert!(messages.contains(&"decode".to_string()));
+        assert!(messages.contains(&"dispatch".to_string()));
+    }
+}
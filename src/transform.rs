@@ -0,0 +1,243 @@
+use std::f32::consts::TAU;
+
+use crate::{cursor::{Position, Velocity}, cursor_3d::{Position3D, Velocity3D}, osc_encode_decode::Set};
+
+/// A clockwise rotation around the center of the normalized space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// An affine transform applied to the coordinates of received TUIO inputs
+///
+/// Positions, velocities and 2D angles are transformed, and rotation speeds are mirrored along with the axes.
+/// Sizes and scalar accelerations are left untouched
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    matrix: [[f32; 2]; 2],
+    offset: [f32; 2],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform {
+    fn new(matrix: [[f32; 2]; 2], offset: [f32; 2]) -> Self {
+        Self { matrix, offset }
+    }
+
+    /// Returns the [Transform] which leaves coordinates unchanged
+    pub fn identity() -> Self {
+        Self::new([[1., 0.], [0., 1.]], [0., 0.])
+    }
+
+    /// Returns a [Transform] mirroring the X axis
+    pub fn flip_x() -> Self {
+        Self::new([[-1., 0.], [0., 1.]], [1., 0.])
+    }
+
+    /// Returns a [Transform] mirroring the Y axis, moving the origin to the bottom-left corner
+    pub fn flip_y() -> Self {
+        Self::new([[1., 0.], [0., -1.]], [0., 1.])
+    }
+
+    /// Returns a [Transform] swapping the X and Y axes
+    pub fn swap_xy() -> Self {
+        Self::new([[0., 1.], [1., 0.]], [0., 0.])
+    }
+
+    /// Returns a [Transform] rotating clockwise around the center of the normalized space
+    /// # Arguments
+    /// * `rotation` - the [Rotation] to apply
+    pub fn rotate(rotation: Rotation) -> Self {
+        match rotation {
+            Rotation::Deg90 => Self::new([[0., -1.], [1., 0.]], [1., 0.]),
+            Rotation::Deg180 => Self::new([[-1., 0.], [0., -1.]], [1., 1.]),
+            Rotation::Deg270 => Self::new([[0., 1.], [-1., 0.]], [0., 1.]),
+        }
+    }
+
+    /// Returns a [Transform] scaling the normalized space into a target rectangle
+    /// # Arguments
+    /// * `x` - the left edge of the rectangle
+    /// * `y` - the top edge of the rectangle
+    /// * `width` - the width of the rectangle
+    /// * `height` - the height of the rectangle
+    pub fn to_rect(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self::new([[width, 0.], [0., height]], [x, y])
+    }
+
+    /// Returns a [Transform] applying this one, then `next`
+    /// # Arguments
+    /// * `next` - the [Transform] to apply afterwards
+    pub fn then(self, next: Transform) -> Self {
+        let [[a, b], [c, d]] = next.matrix;
+        let [[e, f], [g, h]] = self.matrix;
+        let [ox, oy] = self.offset;
+
+        Self::new(
+            [[a * e + b * g, a * f + b * h], [c * e + d * g, c * f + d * h]],
+            [a * ox + b * oy + next.offset[0], c * ox + d * oy + next.offset[1]],
+        )
+    }
+
+    fn apply_xy(&self, x: f32, y: f32) -> (f32, f32) {
+        let [[a, b], [c, d]] = self.matrix;
+        (a * x + b * y, c * x + d * y)
+    }
+
+    /// Returns true if this [Transform] mirrors the space, reversing the direction of rotations
+    pub fn is_mirroring(&self) -> bool {
+        let [[a, b], [c, d]] = self.matrix;
+        a * d - b * c < 0.
+    }
+
+    /// Returns the transformed [Position]
+    pub fn apply_position(&self, position: &Position) -> Position {
+        let (x, y) = self.apply_xy(position.x, position.y);
+        Position { x: x + self.offset[0], y: y + self.offset[1] }
+    }
+
+    /// Returns the transformed [Velocity]
+    pub fn apply_velocity(&self, velocity: &Velocity) -> Velocity {
+        let (x, y) = self.apply_xy(velocity.x, velocity.y);
+        Velocity { x, y }
+    }
+
+    /// Returns the transformed angle in radians, between 0 and 2π
+    pub fn apply_angle(&self, angle: f32) -> f32 {
+        let (x, y) = self.apply_xy(angle.cos(), angle.sin());
+        y.atan2(x).rem_euclid(TAU)
+    }
+
+    fn apply_rotation_speed(&self, rotation_speed: f32) -> f32 {
+        if self.is_mirroring() { -rotation_speed } else { rotation_speed }
+    }
+
+    fn apply_position_3d(&self, position: &Position3D) -> Position3D {
+        let (x, y) = self.apply_xy(position.x, position.y);
+        Position3D { x: x + self.offset[0], y: y + self.offset[1], z: position.z }
+    }
+
+    fn apply_velocity_3d(&self, velocity: &Velocity3D) -> Velocity3D {
+        let (x, y) = self.apply_xy(velocity.x, velocity.y);
+        Velocity3D { x, y, z: velocity.z }
+    }
+
+    /// Transforms every input of a decoded [Set] in place. [crate::Symbol]s and [crate::Control]s have no coordinates and are left untouched
+    pub(crate) fn apply_set(&self, set: &mut Set) {
+        match set {
+            Set::Cursor(cursors) => for cursor in cursors {
+                cursor.position = self.apply_position(&cursor.position);
+                cursor.velocity = self.apply_velocity(&cursor.velocity);
+            },
+            Set::Object(objects) => for object in objects {
+                object.position = self.apply_position(&object.position);
+                object.velocity = self.apply_velocity(&object.velocity);
+                object.angle = self.apply_angle(object.angle);
+                object.rotation_speed = self.apply_rotation_speed(object.rotation_speed);
+                object.rotation_acceleration = self.apply_rotation_speed(object.rotation_acceleration);
+            },
+            Set::Blob(blobs) => for blob in blobs {
+                blob.position = self.apply_position(&blob.position);
+                blob.velocity = self.apply_velocity(&blob.velocity);
+                blob.angle = self.apply_angle(blob.angle);
+                blob.rotation_speed = self.apply_rotation_speed(blob.rotation_speed);
+                blob.rotation_acceleration = self.apply_rotation_speed(blob.rotation_acceleration);
+            },
+            Set::Cursor3D(cursors) => for cursor in cursors {
+                cursor.position = self.apply_position_3d(&cursor.position);
+                cursor.velocity = self.apply_velocity_3d(&cursor.velocity);
+            },
+            Set::Cursor25D(cursors) => for cursor in cursors {
+                cursor.position = self.apply_position_3d(&cursor.position);
+                cursor.velocity = self.apply_velocity_3d(&cursor.velocity);
+            },
+            Set::Blob3D(blobs) => for blob in blobs {
+                blob.position = self.apply_position_3d(&blob.position);
+                blob.velocity = self.apply_velocity_3d(&blob.velocity);
+            },
+            Set::Token(tokens) => for token in tokens {
+                token.position = self.apply_position(&token.position);
+                token.velocity = self.apply_velocity(&token.velocity);
+                token.angle = self.apply_angle(token.angle);
+                token.rotation_speed = self.apply_rotation_speed(token.rotation_speed);
+                token.rotation_acceleration = self.apply_rotation_speed(token.rotation_acceleration);
+            },
+            Set::Pointer(pointers) => for pointer in pointers {
+                pointer.position = self.apply_position(&pointer.position);
+                pointer.velocity = self.apply_velocity(&pointer.velocity);
+                pointer.angle = self.apply_angle(pointer.angle);
+            },
+            Set::Symbol(_) => (),
+            Set::Bounds(bounds) => for bounds in bounds {
+                bounds.position = self.apply_position(&bounds.position);
+                bounds.velocity = self.apply_velocity(&bounds.velocity);
+                bounds.angle = self.apply_angle(bounds.angle);
+                bounds.rotation_speed = self.apply_rotation_speed(bounds.rotation_speed);
+                bounds.rotation_acceleration = self.apply_rotation_speed(bounds.rotation_acceleration);
+            },
+            Set::Geometry(geometries) => for geometry in geometries {
+                for point in &mut geometry.contour {
+                    *point = self.apply_position(point);
+                }
+            },
+            Set::Control(_) => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use crate::cursor::{Position, Velocity};
+
+    use super::*;
+
+    fn assert_position(position: Position, x: f32, y: f32) {
+        assert!((position.x - x).abs() < 1e-6 && (position.y - y).abs() < 1e-6, "{:?} != ({}, {})", position, x, y);
+    }
+
+    #[test]
+    fn flips_and_rotations() {
+        let position = Position { x: 0.25, y: 0.1 };
+
+        assert_position(Transform::identity().apply_position(&position), 0.25, 0.1);
+        assert_position(Transform::flip_x().apply_position(&position), 0.75, 0.1);
+        assert_position(Transform::flip_y().apply_position(&position), 0.25, 0.9);
+        assert_position(Transform::swap_xy().apply_position(&position), 0.1, 0.25);
+        assert_position(Transform::rotate(Rotation::Deg90).apply_position(&Position { x: 0.5, y: 0. }), 1., 0.5);
+        assert_position(Transform::rotate(Rotation::Deg180).apply_position(&position), 0.75, 0.9);
+        assert_position(Transform::rotate(Rotation::Deg270).apply_position(&Position { x: 0.5, y: 0. }), 0., 0.5);
+        assert_position(Transform::to_rect(100., 50., 1920., 1080.).apply_position(&position), 580., 158.);
+
+        assert!(Transform::flip_y().is_mirroring());
+        assert!(!Transform::rotate(Rotation::Deg90).is_mirroring());
+    }
+
+    #[test]
+    fn composition() {
+        let transform = Transform::flip_y().then(Transform::to_rect(0., 0., 200., 100.));
+
+        assert_position(transform.apply_position(&Position { x: 0.25, y: 0.1 }), 50., 90.);
+
+        let velocity = transform.apply_velocity(&Velocity { x: 1., y: 1. });
+        assert_eq!(velocity, Velocity { x: 200., y: -100. });
+
+        let rotated = Transform::rotate(Rotation::Deg90).then(Transform::rotate(Rotation::Deg270));
+        assert_eq!(rotated, Transform::identity());
+    }
+
+    #[test]
+    fn angles() {
+        assert!((Transform::rotate(Rotation::Deg90).apply_angle(0.) - PI / 2.).abs() < 1e-6);
+        assert!((Transform::flip_y().apply_angle(PI / 2.) - 3. * PI / 2.).abs() < 1e-6);
+        assert!((Transform::flip_x().apply_angle(0.) - PI).abs() < 1e-6);
+    }
+}
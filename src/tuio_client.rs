@@ -0,0 +1,161 @@
+use std::{
+    net::ToSocketAddrs,
+    sync::{Arc, Mutex, atomic::{AtomicBool, Ordering}},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::{client::{Client, SharedListener}, errors::TuioError};
+
+/// The interval between two refreshes of the background [Client]
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+type ListenerList = Arc<Mutex<Vec<SharedListener>>>;
+
+/// A [Client] refreshed on a background thread which dispatches its events to every registered [Listener]
+///
+/// The thread is stopped and joined by [TuioClient::stop] or when the [TuioClient] is dropped
+pub struct TuioClient {
+    listeners: ListenerList,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TuioClient {
+    /// Connects a [Client] receiving TUIO over UDP on the provided address and starts dispatching its events
+    ///
+    /// # Arguments
+    /// * `address` - the local address to listen on, either IPv4 or IPv6, such as `"0.0.0.0:3333"` to receive from a tracker on another host
+    pub fn connect(address: impl ToSocketAddrs) -> Result<Self, TuioError> {
+        let address = address.to_socket_addrs()?.next().ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+        Self::spawn(Client::from_address(address)?)
+    }
+
+    /// Connects a [Client] receiving TUIO over UDP on a port of the loopback interface and starts dispatching its events
+    ///
+    /// # Arguments
+    /// * `port` - the local port to listen on, 3333 for most TUIO sources
+    pub fn connect_port(port: u16) -> Result<Self, TuioError> {
+        Self::spawn(Client::from_port(port)?)
+    }
+
+    /// Connects a configured [Client] and starts dispatching its events
+    ///
+    /// # Arguments
+    /// * `client` - the [Client] to run, such as one created with [Client::new_tcp] or [Client::from_player]
+    pub fn spawn(client: Client) -> Result<Self, TuioError> {
+        client.connect()?;
+
+        let listeners: ListenerList = Arc::default();
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread = {
+            let listeners = Arc::clone(&listeners);
+            let running = Arc::clone(&running);
+
+            thread::spawn(move || {
+                while running.load(Ordering::Relaxed) {
+                    if let Ok(Some(events)) = client.refresh() {
+                        events.dispatch_to(&listeners.lock().unwrap());
+                    }
+
+                    thread::sleep(POLL_INTERVAL);
+                }
+
+                client.disconnect();
+            })
+        };
+
+        Ok(Self { listeners, running, thread: Some(thread) })
+    }
+
+    /// Registers a [crate::client::Listener] notified of every following event
+    ///
+    /// Listeners are notified in their registration order, and a panicking listener does not prevent the others from being notified
+    /// # Arguments
+    /// * `listener` - the [crate::client::Listener] to add
+    pub fn add_listener(&self, listener: SharedListener) {
+        self.listeners.lock().unwrap().push(listener);
+    }
+
+    /// Returns true until [TuioClient::stop] is called
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    /// Stops the background thread and waits for it to finish
+    pub fn stop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for TuioClient {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::AtomicUsize, time::Instant};
+
+    use std::net::{Ipv6Addr, UdpSocket};
+
+    use crate::{client::Listener, cursor::Position, loopback::LoopbackTransport, Cursor, Server};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CursorCounter(AtomicUsize);
+
+    impl Listener for CursorCounter {
+        fn cursor_added(&self, _: &str, _: &Cursor) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn background_dispatch() {
+        let transport = LoopbackTransport::new();
+        let server = Server::from_osc_sender(transport.clone());
+        let counter = Arc::new(CursorCounter::default());
+
+        let mut client = TuioClient::spawn(Client::from_loopback(&transport)).unwrap();
+        client.add_listener(counter.clone());
+
+        server.send_frame(&[Cursor::new(0, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+
+        let start = Instant::now();
+        while counter.0.load(Ordering::SeqCst) == 0 && start.elapsed() < Duration::from_secs(1) {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+
+        client.stop();
+        assert!(!client.is_running());
+    }
+
+    #[test]
+    fn connect_address() {
+        let address = UdpSocket::bind((Ipv6Addr::LOCALHOST, 0)).unwrap().local_addr().unwrap();
+        let server = Server::from_socket_addr(address).unwrap();
+        let counter = Arc::new(CursorCounter::default());
+
+        let mut client = TuioClient::connect(format!("[::1]:{}", address.port())).unwrap();
+        client.add_listener(counter.clone());
+
+        let start = Instant::now();
+        while counter.0.load(Ordering::SeqCst) == 0 && start.elapsed() < Duration::from_secs(1) {
+            server.send_frame(&[Cursor::new(0, Position { x: 0.5, y: 0.5 })], &[], &[]).unwrap();
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        client.stop();
+    }
+}
@@ -0,0 +1,482 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Shutdown, TcpStream},
+    sync::Mutex,
+};
+
+use rosc::OscPacket;
+
+use crate::{errors::OscReceiverError, osc_receiver::{OscReceiver, RawTap, call_raw_tap, MAX_DATAGRAM_SIZE}};
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// The largest handshake response accepted, status line and headers included
+const MAX_HANDSHAKE_SIZE: u64 = 8192;
+
+/// The GUID appended to the handshake key to compute the `Sec-WebSocket-Accept` header
+const ACCEPT_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Returns 8 unpredictable bytes, used for the handshake key and the frame masks
+fn random_bytes() -> [u8; 8] {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_nanos());
+    hasher.finish().to_be_bytes()
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let triple = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8 | *chunk.get(2).unwrap_or(&0) as u32;
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(triple >> (18 - 6 * index) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Computes the SHA-1 digest of some bytes, only used to verify the handshake
+fn sha1(bytes: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    let mut message = bytes.to_vec();
+    message.push(0x80);
+
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+
+    message.extend((bytes.len() as u64 * 8).to_be_bytes());
+
+    for block in message.chunks(64) {
+        let mut words = [0u32; 80];
+
+        for (index, word) in block.chunks(4).enumerate() {
+            words[index] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+
+        for index in 16..80 {
+            words[index] = (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+
+        for (index, word) in words.iter().enumerate() {
+            let (f, k) = match index {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+
+            let temp = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (value, added) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(added);
+        }
+    }
+
+    let mut digest = [0u8; 20];
+
+    for (bytes, value) in digest.chunks_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+
+    digest
+}
+
+/// Returns the `Sec-WebSocket-Accept` header value a server must answer to a handshake key
+fn accept_key(key: &str) -> String {
+    base64_encode(&sha1(format!("{}{}", key, ACCEPT_GUID).as_bytes()))
+}
+
+/// Returns an [io::ErrorKind::InvalidData] error
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Splits a `ws://host[:port][/path]` URL into its `host:port` authority and its path
+fn parse_url(url: &str) -> Result<(String, String), io::Error> {
+    let rest = url.strip_prefix("ws://").ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("unsupported WebSocket URL: {}", url)))?;
+
+    let (authority, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+
+    if authority.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("missing host in WebSocket URL: {}", url)));
+    }
+
+    let authority = if authority.rsplit_once(':').is_some_and(|(_, port)| port.parse::<u16>().is_ok()) {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    Ok((authority, path.to_string()))
+}
+
+/// Receives OSC packets framed as binary WebSocket messages, as emitted by browser-based trackers.
+///
+/// Only plain `ws://` URLs are supported. Text messages, such as the TUIO-as-JSON variant, are ignored.
+/// A frame or a message larger than a UDP datagram closes the connection with an [io::ErrorKind::InvalidData] error
+pub struct WebSocketReceiver {
+    authority: String,
+    path: String,
+    stream: Mutex<Option<TcpStream>>,
+    reader: Mutex<Option<BufReader<TcpStream>>>,
+    raw_tap: Mutex<Option<RawTap>>,
+}
+
+impl WebSocketReceiver {
+    /// Creates a [WebSocketReceiver] which connects to the provided URL
+    ///
+    /// # Arguments
+    /// * `url` - a `ws://host:port/path` URL
+    pub fn new(url: &str) -> Result<Self, io::Error> {
+        let (authority, path) = parse_url(url)?;
+
+        Ok(Self {
+            authority,
+            path,
+            stream: Mutex::new(None),
+            reader: Mutex::new(None),
+            raw_tap: Mutex::new(None),
+        })
+    }
+
+    /// Upgrades a connection to the WebSocket protocol, checking the server's accept key
+    fn handshake(&self, reader: &mut BufReader<TcpStream>) -> Result<(), io::Error> {
+        let random = random_bytes();
+        let key = base64_encode(&[random, random_bytes()].concat());
+
+        write!(
+            reader.get_mut(),
+            "GET {} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n\r\n",
+            self.path, self.authority, key
+        )?;
+
+        let mut response = Vec::new();
+        let mut limited = reader.take(MAX_HANDSHAKE_SIZE);
+
+        while !response.ends_with(b"\r\n\r\n") {
+            if limited.read_until(b'\n', &mut response)? == 0 {
+                return Err(match limited.limit() {
+                    0 => invalid_data(format!("WebSocket handshake response exceeds {} bytes", MAX_HANDSHAKE_SIZE)),
+                    _ => io::Error::from(io::ErrorKind::UnexpectedEof),
+                });
+            }
+        }
+
+        let response = String::from_utf8_lossy(&response);
+        let mut lines = response.lines();
+
+        match lines.next() {
+            Some(status) if status.split_whitespace().nth(1) == Some("101") => (),
+            status => return Err(io::Error::new(io::ErrorKind::ConnectionRefused, format!("WebSocket upgrade refused: {}", status.unwrap_or_default()))),
+        }
+
+        let accept = lines
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("Sec-WebSocket-Accept"))
+            .map(|(_, value)| value.trim());
+
+        match accept {
+            Some(accept) if accept == accept_key(&key) => Ok(()),
+            _ => Err(io::Error::new(io::ErrorKind::ConnectionRefused, "WebSocket upgrade without a valid Sec-WebSocket-Accept header")),
+        }
+    }
+
+    /// Sends a masked frame, as required for every client to server frame
+    fn send_frame(stream: &mut TcpStream, opcode: u8, payload: &[u8]) -> Result<(), io::Error> {
+        let mut frame = vec![0x80 | opcode];
+
+        match payload.len() {
+            len if len < 126 => frame.push(0x80 | len as u8),
+            len if len <= u16::MAX as usize => {
+                frame.push(0x80 | 126);
+                frame.extend((len as u16).to_be_bytes());
+            }
+            len => {
+                frame.push(0x80 | 127);
+                frame.extend((len as u64).to_be_bytes());
+            }
+        }
+
+        let mask = random_bytes();
+        frame.extend(&mask[..4]);
+        frame.extend(payload.iter().enumerate().map(|(index, byte)| byte ^ mask[index % 4]));
+
+        stream.write_all(&frame)
+    }
+
+    /// Reads a single frame, returning its FIN flag, opcode and unmasked payload
+    fn read_frame(stream: &mut impl Read) -> Result<(bool, u8, Vec<u8>), io::Error> {
+        let mut header = [0u8; 2];
+        stream.read_exact(&mut header)?;
+
+        let fin = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0F;
+        let masked = header[1] & 0x80 != 0;
+
+        let len = match header[1] & 0x7F {
+            126 => {
+                let mut len = [0u8; 2];
+                stream.read_exact(&mut len)?;
+                u16::from_be_bytes(len) as usize
+            }
+            127 => {
+                let mut len = [0u8; 8];
+                stream.read_exact(&mut len)?;
+                u64::from_be_bytes(len) as usize
+            }
+            len => len as usize,
+        };
+
+        if len > MAX_DATAGRAM_SIZE {
+            return Err(invalid_data(format!("WebSocket frame of {} bytes exceeds {} bytes", len, MAX_DATAGRAM_SIZE)));
+        }
+
+        let mut mask = [0u8; 4];
+
+        if masked {
+            stream.read_exact(&mut mask)?;
+        }
+
+        let mut payload = vec![0u8; len];
+        stream.read_exact(&mut payload)?;
+
+        if masked {
+            payload.iter_mut().enumerate().for_each(|(index, byte)| *byte ^= mask[index % 4]);
+        }
+
+        Ok((fin, opcode, payload))
+    }
+
+    /// Reads frames until a complete binary message is assembled
+    fn read_message(reader: &mut BufReader<TcpStream>) -> Result<Vec<u8>, io::Error> {
+        let mut message = Vec::new();
+        let mut message_opcode = None;
+
+        loop {
+            let (fin, opcode, payload) = Self::read_frame(reader)?;
+
+            match opcode {
+                OPCODE_PING => Self::send_frame(reader.get_mut(), OPCODE_PONG, &payload)?,
+                OPCODE_CLOSE => {
+                    let _ = Self::send_frame(reader.get_mut(), OPCODE_CLOSE, &payload);
+                    return Err(io::Error::from(io::ErrorKind::ConnectionAborted));
+                }
+                OPCODE_TEXT | OPCODE_BINARY | OPCODE_CONTINUATION => {
+                    if opcode != OPCODE_CONTINUATION {
+                        message.clear();
+                        message_opcode = Some(opcode);
+                    }
+
+                    if message.len() + payload.len() > MAX_DATAGRAM_SIZE {
+                        return Err(invalid_data(format!("WebSocket message exceeds {} bytes", MAX_DATAGRAM_SIZE)));
+                    }
+
+                    message.extend(payload);
+
+                    if fin {
+                        if message_opcode == Some(OPCODE_BINARY) {
+                            return Ok(message);
+                        }
+
+                        message.clear();
+                        message_opcode = None;
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+impl OscReceiver<OscPacket, OscReceiverError> for WebSocketReceiver {
+    fn connect(&self) -> Result<(), io::Error> {
+        let mut stream = self.stream.lock().unwrap();
+
+        if stream.is_none() {
+            let new_stream = TcpStream::connect(&self.authority)?;
+            let mut reader = BufReader::new(new_stream.try_clone()?);
+            self.handshake(&mut reader)?;
+            *self.reader.lock().unwrap() = Some(reader);
+            *stream = Some(new_stream);
+        }
+
+        Ok(())
+    }
+
+    fn disconnect(&self) {
+        if let Some(mut stream) = self.stream.lock().unwrap().take() {
+            let _ = Self::send_frame(&mut stream, OPCODE_CLOSE, &[]);
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+
+        // Waits for a pending receive to fail on the shut down stream
+        *self.reader.lock().unwrap() = None;
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.lock().unwrap().is_some()
+    }
+
+    fn recv(&self) -> Result<OscPacket, OscReceiverError> {
+        let mut reader = self.reader.lock().unwrap();
+
+        let Some(reader) = reader.as_mut() else {
+            return Err(OscReceiverError::Receive(io::Error::from(io::ErrorKind::NotConnected)));
+        };
+
+        let message = Self::read_message(reader).map_err(OscReceiverError::Receive)?;
+
+        if let Ok(address) = reader.get_ref().peer_addr() {
+            call_raw_tap(&self.raw_tap, address, &message);
+        }
+
+        let (_, packet) = rosc::decoder::decode_udp(&message).map_err(OscReceiverError::Decode)?;
+
+        Ok(packet)
+    }
+
+    fn set_raw_tap(&self, raw_tap: RawTap) {
+        *self.raw_tap.lock().unwrap() = Some(raw_tap);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::{BufRead, BufReader, Read, Write}, net::{TcpListener, TcpStream}, thread};
+
+    use rosc::{encoder, OscMessage, OscPacket, OscType};
+
+    use super::*;
+
+    #[test]
+    fn url_parsing() {
+        assert_eq!(parse_url("ws://localhost:3000").unwrap(), ("localhost:3000".into(), "/".into()));
+        assert_eq!(parse_url("ws://localhost/tuio").unwrap(), ("localhost:80".into(), "/tuio".into()));
+        assert!(parse_url("wss://localhost:3000").is_err());
+        assert!(parse_url("ws://").is_err());
+    }
+
+    #[test]
+    fn base64() {
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn handshake_accept_key() {
+        assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+    }
+
+    /// Accepts a WebSocket connection, answering the handshake with an accept header computed by a function of the key
+    fn accept_connection(listener: &TcpListener, accept: fn(&str) -> String) -> TcpStream {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut key = String::new();
+        let mut line = String::new();
+
+        while line != "\r\n" {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+
+            if let Some(value) = line.strip_prefix("Sec-WebSocket-Key:") {
+                key = value.trim().to_string();
+            }
+        }
+
+        let mut stream = reader.into_inner();
+        write!(stream, "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n", accept(&key)).unwrap();
+        stream
+    }
+
+    #[test]
+    fn invalid_accept_key() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let receiver = WebSocketReceiver::new(&format!("ws://{}/", listener.local_addr().unwrap())).unwrap();
+
+        let server = thread::spawn(move || accept_connection(&listener, |_| accept_key("another key")));
+
+        assert!(matches!(receiver.connect(), Err(err) if err.kind() == io::ErrorKind::ConnectionRefused));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn oversized_frame() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let receiver = WebSocketReceiver::new(&format!("ws://{}/", listener.local_addr().unwrap())).unwrap();
+
+        let server = thread::spawn(move || {
+            let mut stream = accept_connection(&listener, accept_key);
+            stream.write_all(&[0x82, 127]).unwrap();
+            stream.write_all(&u64::MAX.to_be_bytes()).unwrap();
+            stream
+        });
+
+        receiver.connect().unwrap();
+
+        assert!(matches!(receiver.recv(), Err(OscReceiverError::Receive(err)) if err.kind() == io::ErrorKind::InvalidData));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn websocket_transport() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}/", listener.local_addr().unwrap());
+
+        let packet = OscPacket::Message(OscMessage {
+            addr: "/tuio/2Dcur".into(),
+            args: vec![OscType::String("alive".into()), OscType::Int(1)],
+        });
+        let bytes = encoder::encode(&packet).unwrap();
+
+        let server = thread::spawn(move || {
+            let mut stream = accept_connection(&listener, accept_key);
+
+            // A ping, a text message to ignore, then the OSC packet split into two fragments
+            stream.write_all(&[0x89, 0x00]).unwrap();
+            stream.write_all(&[0x81, 0x02, b'{', b'}']).unwrap();
+            stream.write_all(&[0x02, 4]).unwrap();
+            stream.write_all(&bytes[..4]).unwrap();
+            stream.write_all(&[0x80, (bytes.len() - 4) as u8]).unwrap();
+            stream.write_all(&bytes[4..]).unwrap();
+
+            let mut pong = [0u8; 6];
+            stream.read_exact(&mut pong).unwrap();
+            pong[0]
+        });
+
+        let receiver = WebSocketReceiver::new(&url).unwrap();
+        receiver.connect().unwrap();
+
+        assert_eq!(receiver.recv().unwrap(), packet);
+        assert_eq!(server.join().unwrap(), 0x8A);
+    }
+}